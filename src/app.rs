@@ -11,8 +11,13 @@ use layout_manager::*;
 pub use states::*;
 
 use crate::{
-    canvas, constants,
-    utils::error::{BottomError, Result},
+    canvas, constants, data_conversion,
+    snapshot::{export_graph_csv, Snapshot},
+    utils::{
+        clipboard::copy_to_clipboard,
+        error::{BottomError, Result},
+        gen_util::{get_plain_text_table, ByteFormat},
+    },
     Pid,
 };
 
@@ -21,19 +26,47 @@ pub mod data_harvester;
 pub mod layout_manager;
 mod process_killer;
 pub mod query;
+pub mod rate_ramp;
+pub mod state_file;
 pub mod states;
 
 const MAX_SEARCH_LENGTH: usize = 200;
 
+/// What it takes for the `q` key to quit.
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuitConfirmation {
+    /// Quit as soon as `q` is pressed.
+    Immediate,
+    /// Quit only if `q` is pressed twice in quick succession.
+    Double,
+    /// Ask for confirmation before quitting.
+    Prompt,
+}
+
+impl Default for QuitConfirmation {
+    fn default() -> Self {
+        QuitConfirmation::Immediate
+    }
+}
+
 /// AppConfigFields is meant to cover basic fields that would normally be set
 /// by config files or launch options.
 pub struct AppConfigFields {
     pub update_rate_in_milliseconds: u64,
     pub temperature_type: temperature::TemperatureType,
-    pub use_dot: bool,
+    pub hide_temperature_unit: bool,
+    pub graph_marker: canvas::GraphMarker,
+    /// Whether graphs plot every point, rather than only the lowest and highest points that fall
+    /// into each column of the graph.
+    pub raw_graphs: bool,
+    /// How many colours the terminal can show.
+    pub colour_depth: canvas::ColourDepth,
     pub left_legend: bool,
     pub show_average_cpu: bool,
     pub use_current_cpu_total: bool,
+    /// Whether process CPU% is relative to a single core, so a process keeping four cores busy
+    /// shows around 400%.  Takes precedence over `use_current_cpu_total`.
+    pub per_core_cpu: bool,
     pub use_basic_mode: bool,
     pub default_time_value: u64,
     pub time_interval: u64,
@@ -42,12 +75,77 @@ pub struct AppConfigFields {
     pub use_old_network_legend: bool,
     pub table_gap: u16,
     pub disable_click: bool,
+    pub mem_warn_threshold_percent: Option<f64>,
+    pub mem_warn_bell: bool,
+    /// How the memory pressure gauge weighs memory, swap, and swap activity.
+    pub mem_pressure_weights: data_conversion::MemPressureWeights,
+    pub disk_warn_threshold_percent: Option<f64>,
+    /// Per-mount disk warning thresholds, which override `disk_warn_threshold_percent`.
+    pub disk_mount_warn_threshold_percent: HashMap<String, f64>,
+    /// Which of the optional columns to show in the disk table.
+    pub disk_columns: DiskColumns,
+    pub command_display: data_conversion::CommandDisplay,
+    /// Which processes have their states counted in the process widget's title, if any.
+    pub process_state_counts: Option<data_conversion::ProcessStateCountScope>,
+    pub selection_style: canvas::SelectionStyle,
+    pub use_cpu_usage_gradient: bool,
+    /// Below this many columns or rows, only a note asking for a larger terminal is drawn.
+    pub min_terminal_width: u16,
+    pub min_terminal_height: u16,
+    pub quit_confirmation: QuitConfirmation,
+    /// Whether Alt-q quits from anywhere, including the search widget.
+    pub quit_from_search: bool,
+    /// Decimal places for percentages and rates.  If unset, each widget keeps its own default.
+    pub percent_precision: Option<usize>,
+    pub rate_precision: Option<usize>,
+    /// How byte values are scaled and rounded.
+    pub byte_format: ByteFormat,
+    /// How much of each new network and disk IO rate is blended into the shown current rate.
+    pub rate_smoothing_alpha: f64,
+    /// Whether to skip redrawing frames where nothing shown has changed.
+    pub pause_idle_redraws: bool,
+    /// Processes with an OOM score at or above this are highlighted.
+    pub oom_score_warn_threshold: Option<u64>,
+    /// More zombie processes than this shows a warning in the process widget's title.
+    pub zombie_warn_threshold: u64,
+    /// Shown in a process widget when no running process matches its search or filters.
+    pub process_empty_message: String,
+    /// Whether to show the CPU and memory usage of bottom itself.
+    pub show_self_usage: bool,
+    /// Whether to write a crash report file if bottom panics.
+    pub crash_report: bool,
+    /// Shown in the top border to tell several instances of bottom apart.
+    pub label: Option<String>,
+    /// Harvests new data and redraws right away when pressed.
+    pub refresh_key: char,
+    /// Whether scrolling past either end of a table goes to the other end.
+    pub scroll_wrap: bool,
+    /// Whether every other row of the process, disk, and temperature tables gets a different
+    /// background.
+    pub zebra_stripes: bool,
+    /// How many entries each tick of the mouse wheel scrolls a table by.
+    pub scroll_lines_per_tick: u64,
+    /// Breaks ties in the process widget's sort, before falling back to the process name.
+    pub secondary_sort: Option<data_harvester::processes::ProcessSorting>,
+    /// The most data points to keep for graphs, on top of only keeping recent data.
+    pub max_data_points: Option<usize>,
+    /// Whether to skip collecting each process' disk IO.
+    pub disable_process_io: bool,
+    /// Whether the CPU usage breakdown can't be shown, so `/proc/stat` is never read for it.
+    pub disable_cpu_breakdown: bool,
+    /// Whether to show context switches and interrupts per second in the CPU graph's title.
+    pub show_scheduler_activity: bool,
+    /// The strftime-style format of snapshot timestamps.  If unset, RFC 3339 is used.
+    pub time_format: Option<String>,
+    /// How long to run for before quitting on its own, if set.
+    pub run_duration_in_seconds: Option<u64>,
 }
 
 /// For filtering out information
 pub struct DataFilters {
     pub disk_filter: Option<Filter>,
     pub temp_filter: Option<Filter>,
+    pub hidden_disks: HiddenDisks,
 }
 
 #[derive(Debug)]
@@ -56,6 +154,23 @@ pub struct Filter {
     pub list: Vec<regex::Regex>,
 }
 
+/// Disks that are hidden from the disk table by their file system or mount point, unless
+/// all disks are being shown.
+#[derive(Debug, Default)]
+pub struct HiddenDisks {
+    pub file_systems: Vec<String>,
+    pub mounts: Vec<regex::Regex>,
+}
+
+impl HiddenDisks {
+    pub fn is_hidden(&self, file_system: &str, mount_point: &str) -> bool {
+        self.file_systems
+            .iter()
+            .any(|hidden_file_system| hidden_file_system.eq_ignore_ascii_case(file_system))
+            || self.mounts.iter().any(|mount| mount.is_match(mount_point))
+    }
+}
+
 #[derive(TypedBuilder)]
 pub struct App {
     #[builder(default = false, setter(skip))]
@@ -88,6 +203,9 @@ pub struct App {
     #[builder(default, setter(skip))]
     pub help_dialog_state: AppHelpDialogState,
 
+    #[builder(default, setter(skip))]
+    pub message_dialog_state: AppMessageDialogState,
+
     #[builder(default = false, setter(skip))]
     pub is_expanded: bool,
 
@@ -100,6 +218,47 @@ pub struct App {
     #[builder(default = false, setter(skip))]
     pub basic_mode_use_percent: bool,
 
+    #[builder(default = false, setter(skip))]
+    pub is_replaying: bool,
+
+    /// The environments of processes read for `env:` searches.
+    #[builder(default, setter(skip))]
+    pub environment_cache: processes::EnvironmentCache,
+
+    /// The host being monitored over SSH, if any.
+    #[builder(default, setter(skip))]
+    pub remote_host: Option<String>,
+
+    #[builder(default = false, setter(skip))]
+    pub is_remote_disconnected: bool,
+
+    /// The graph widget to copy as text the next time it is drawn.
+    #[builder(default, setter(skip))]
+    pub graph_capture_widget_id: Option<u64>,
+
+    /// Text for the main loop to show in the user's pager, after suspending the UI.
+    #[builder(default, setter(skip))]
+    pub pager_text: Option<String>,
+
+    /// When `q` was last pressed without quitting, if a double press is required to quit.
+    #[builder(default, setter(skip))]
+    pub quit_pressed_at: Option<Instant>,
+
+    #[builder(default = false, setter(skip))]
+    pub is_showing_quit_prompt: bool,
+
+    /// When the last harvested data was received.
+    #[builder(default, setter(skip))]
+    pub last_update_instant: Option<Instant>,
+
+    /// The optional process fields that the harvester was last asked to read.
+    #[builder(default, setter(skip))]
+    pub harvested_process_fields: processes::ProcessFields,
+
+    /// Whether the harvester was last asked to read the CPU usage breakdown.
+    #[builder(default = false, setter(skip))]
+    pub is_harvesting_cpu_breakdown: bool,
+
     pub cpu_state: CpuState,
     pub mem_state: MemState,
     pub net_state: NetState,
@@ -112,6 +271,8 @@ pub struct App {
     pub widget_map: HashMap<u64, BottomWidget>,
     pub current_widget: BottomWidget,
     pub used_widgets: UsedWidgets,
+    /// Slows down harvesting while the CPU is busy, if enabled.
+    pub rate_ramp: Option<rate_ramp::RateRamp>,
     pub filters: DataFilters,
 }
 
@@ -122,6 +283,7 @@ impl App {
 
         // Reset dialog state
         self.help_dialog_state.is_showing_help = false;
+        self.clear_help_search();
         self.delete_dialog_state.is_showing_dd = false;
 
         // Close all searches and reset it
@@ -153,6 +315,11 @@ impl App {
         self.is_force_redraw || self.is_determining_widget_boundary
     }
 
+    fn clear_help_search(&mut self) {
+        self.help_dialog_state.is_searching = false;
+        self.help_dialog_state.search_query.clear();
+    }
+
     fn close_dd(&mut self) {
         self.delete_dialog_state.is_showing_dd = false;
         self.delete_dialog_state.is_on_yes = false;
@@ -164,7 +331,13 @@ impl App {
         self.reset_multi_tap_keys();
         if self.is_in_dialog() {
             if self.help_dialog_state.is_showing_help {
-                self.help_dialog_state.is_showing_help = false;
+                if self.help_dialog_state.is_searching
+                    || !self.help_dialog_state.search_query.is_empty()
+                {
+                    self.clear_help_search();
+                } else {
+                    self.help_dialog_state.is_showing_help = false;
+                }
                 self.help_dialog_state.scroll_state.current_scroll_index = 0;
             } else {
                 self.close_dd();
@@ -178,6 +351,12 @@ impl App {
                         .proc_state
                         .get_mut_widget_state(self.current_widget.widget_id)
                     {
+                        if current_proc_state.expanded_pid.is_some() {
+                            current_proc_state.collapse_process_details();
+                            self.is_force_redraw = true;
+                            return;
+                        }
+
                         if current_proc_state.is_search_enabled() || current_proc_state.is_sort_open
                         {
                             current_proc_state
@@ -243,7 +422,7 @@ impl App {
         self.second_char = None;
     }
 
-    fn is_in_dialog(&self) -> bool {
+    pub fn is_in_dialog(&self) -> bool {
         self.help_dialog_state.is_showing_help || self.delete_dialog_state.is_showing_dd
     }
 
@@ -273,8 +452,10 @@ impl App {
 
                             // Forcefully switch off column if we were on it...
                             if (proc_widget_state.is_grouped
-                                && proc_widget_state.process_sorting_type
-                                    == data_harvester::processes::ProcessSorting::Pid)
+                                && (proc_widget_state.process_sorting_type
+                                    == data_harvester::processes::ProcessSorting::Pid
+                                    || proc_widget_state.process_sorting_type
+                                        == data_harvester::processes::ProcessSorting::ParentPid))
                                 || (!proc_widget_state.is_grouped
                                     && proc_widget_state.process_sorting_type
                                         == data_harvester::processes::ProcessSorting::Count)
@@ -290,6 +471,13 @@ impl App {
                                 .get_mut(&processes::ProcessSorting::State)
                                 .unwrap()
                                 .enabled = !(proc_widget_state.is_grouped);
+                            proc_widget_state
+                                .columns
+                                .column_mapping
+                                .get_mut(&processes::ProcessSorting::ParentPid)
+                                .unwrap()
+                                .enabled =
+                                !proc_widget_state.is_grouped && proc_widget_state.is_showing_ppid;
 
                             proc_widget_state
                                 .columns
@@ -373,6 +561,7 @@ impl App {
 
                 self.is_force_redraw = true;
             }
+            BottomWidgetType::Disk => self.toggle_disk_file_system_sort(),
             _ => {}
         }
     }
@@ -387,6 +576,7 @@ impl App {
                         _ => 0,
                     };
 
+                self.keep_process_selected(widget_id);
                 if let Some(proc_widget_state) = self.proc_state.get_mut_widget_state(widget_id) {
                     proc_widget_state.is_process_sort_descending =
                         !proc_widget_state.is_process_sort_descending;
@@ -394,15 +584,43 @@ impl App {
                     self.proc_state.force_update = Some(widget_id);
                 }
             }
+            BottomWidgetType::Temp => {
+                if let Some(temp_widget_state) = self
+                    .temp_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    temp_widget_state.is_sort_descending = !temp_widget_state.is_sort_descending;
+                }
+            }
             _ => {}
         }
     }
 
+    /// Sorts the selected temperature table by the given column, or reverses its order if it
+    /// is already sorted by it.
+    fn sort_temp_by(&mut self, sorting_type: TempSorting) {
+        if let Some(temp_widget_state) = self
+            .temp_state
+            .get_mut_widget_state(self.current_widget.widget_id)
+        {
+            temp_widget_state.sort_by(sorting_type);
+            self.skip_to_first();
+        }
+    }
+
     pub fn toggle_percentages(&mut self) {
         match &self.current_widget.widget_type {
             BottomWidgetType::BasicMem => {
                 self.basic_mode_use_percent = !self.basic_mode_use_percent; // Oh god this is so lazy.
             }
+            BottomWidgetType::Mem => {
+                if let Some(mem_widget_state) = self
+                    .mem_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    mem_widget_state.legend_display = mem_widget_state.legend_display.next();
+                }
+            }
             BottomWidgetType::Proc => {
                 if let Some(proc_widget_state) = self
                     .proc_state
@@ -487,27 +705,59 @@ impl App {
         }
     }
 
-    pub fn toggle_tree_mode(&mut self) {
+    pub fn toggle_search_invert(&mut self) {
+        let is_in_search_widget = self.is_in_search_widget();
         if let Some(proc_widget_state) = self
             .proc_state
             .widget_states
-            .get_mut(&(self.current_widget.widget_id))
+            .get_mut(&(self.current_widget.widget_id - 1))
         {
+            if is_in_search_widget && proc_widget_state.is_search_enabled() {
+                // Inverting doesn't change the query itself, so there's nothing to re-parse.
+                proc_widget_state
+                    .process_search_state
+                    .search_toggle_invert();
+                self.proc_state.force_update = Some(self.current_widget.widget_id - 1);
+            }
+        }
+    }
+
+    pub fn toggle_tree_mode(&mut self) {
+        // F5 also works from the search and sort widgets, so find their process widget.
+        let widget_id = match self.current_widget.widget_type {
+            BottomWidgetType::Proc => self.current_widget.widget_id,
+            BottomWidgetType::ProcSearch => self.current_widget.widget_id - 1,
+            BottomWidgetType::ProcSort => self.current_widget.widget_id - 2,
+            _ => return,
+        };
+
+        if let Some(proc_widget_state) = self.proc_state.get_mut_widget_state(widget_id) {
             proc_widget_state.is_tree_mode = !proc_widget_state.is_tree_mode;
 
             if proc_widget_state.is_tree_mode {
                 // We enabled... set PID sort type to ascending.
                 proc_widget_state.process_sorting_type = processes::ProcessSorting::Pid;
                 proc_widget_state.is_process_sort_descending = false;
+
+                // Keep the sort widget's selection on the new sort column.
+                if proc_widget_state.is_sort_open {
+                    proc_widget_state
+                        .columns
+                        .set_to_sorted_index(&proc_widget_state.process_sorting_type);
+                }
             }
 
-            self.proc_state.force_update = Some(self.current_widget.widget_id);
+            // Rebuild the list right away, rather than on the next harvest.
+            self.proc_state.force_update = Some(widget_id);
         }
     }
 
     /// One of two functions allowed to run while in a dialog...
     pub fn on_enter(&mut self) {
-        if self.delete_dialog_state.is_showing_dd {
+        if self.help_dialog_state.is_showing_help {
+            self.help_dialog_state.is_searching = false;
+            self.is_force_redraw = true;
+        } else if self.delete_dialog_state.is_showing_dd {
             if self.dd_err.is_some() {
                 self.close_dd();
             } else if self.delete_dialog_state.is_on_yes {
@@ -528,6 +778,7 @@ impl App {
                 self.delete_dialog_state.is_showing_dd = false;
             }
         } else if let BottomWidgetType::ProcSort = self.current_widget.widget_type {
+            self.keep_process_selected(self.current_widget.widget_id - 2);
             if let Some(proc_widget_state) = self
                 .proc_state
                 .widget_states
@@ -537,6 +788,89 @@ impl App {
                 proc_widget_state.update_sorting_with_columns();
                 self.toggle_sort();
             }
+        } else if let BottomWidgetType::Proc = self.current_widget.widget_type {
+            self.toggle_expanded_process(ExpandedProcessView::Details);
+        }
+    }
+
+    /// Sorts by the column matching the mnemonic pressed in the sort widget, then closes it.
+    /// Returns whether the key was a mnemonic for a shown column.
+    fn select_sort_by_mnemonic(&mut self, mnemonic: char) -> bool {
+        let widget_id = self.current_widget.widget_id - 2;
+        let is_selected = match self.proc_state.widget_states.get_mut(&widget_id) {
+            Some(proc_widget_state) => proc_widget_state.select_sort_mnemonic(mnemonic),
+            None => false,
+        };
+
+        if is_selected {
+            self.keep_process_selected(widget_id);
+            self.proc_state.force_update = Some(widget_id);
+            self.toggle_sort();
+        }
+
+        is_selected
+    }
+
+    /// Remembers the selected process of a process widget, so that it is selected again once
+    /// the list is re-sorted.
+    fn keep_process_selected(&mut self, widget_id: u64) {
+        if let Some(proc_widget_state) = self.proc_state.widget_states.get_mut(&widget_id) {
+            let position = proc_widget_state.scroll_state.current_scroll_position;
+            proc_widget_state.pid_to_reselect = self
+                .canvas_data
+                .finalized_process_data_map
+                .get(&widget_id)
+                .and_then(|processes| processes.get(position))
+                .map(|process| process.pid);
+        }
+    }
+
+    /// Shows or hides the given view of the selected process beneath its row, switching to it if
+    /// the process is showing another view.  The details are only collected for that one
+    /// process, at the time it is expanded.
+    fn toggle_expanded_process(&mut self, view: ExpandedProcessView) {
+        // Replayed processes aren't running on this system, so there is nothing to look up.
+        // Their commands are already known, though.
+        if let (ExpandedProcessView::Details, Some(data_source)) =
+            (view, self.get_foreign_data_source())
+        {
+            self.show_message(format!(
+                "Process details aren't available while {}.",
+                data_source
+            ));
+            return;
+        }
+
+        if let Some(proc_widget_state) = self
+            .proc_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            if proc_widget_state.expanded_pid.is_some() && proc_widget_state.expanded_view == view {
+                proc_widget_state.collapse_process_details();
+            } else if !proc_widget_state.is_grouped {
+                if let Some(process) = self
+                    .canvas_data
+                    .finalized_process_data_map
+                    .get(&self.current_widget.widget_id)
+                    .and_then(|processes| {
+                        processes.get(proc_widget_state.scroll_state.current_scroll_position)
+                    })
+                {
+                    if !process.is_disabled_entry && process.is_running() {
+                        proc_widget_state.expanded_pid = Some(process.pid);
+                        proc_widget_state.expanded_view = view;
+                        proc_widget_state.expanded_process_details = match view {
+                            ExpandedProcessView::Details => {
+                                Some(processes::get_process_details(process.pid))
+                            }
+                            ExpandedProcessView::Command => None,
+                        };
+                    }
+                }
+            }
+
+            self.is_force_redraw = true;
         }
     }
 
@@ -590,7 +924,14 @@ impl App {
     }
 
     pub fn on_backspace(&mut self) {
-        if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
+        if self.help_dialog_state.is_showing_help {
+            if self.help_dialog_state.is_searching
+                && self.help_dialog_state.search_query.pop().is_some()
+            {
+                self.help_dialog_state.scroll_state.current_scroll_index = 0;
+                self.is_force_redraw = true;
+            }
+        } else if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
             let is_in_search_widget = self.is_in_search_widget();
             if let Some(proc_widget_state) = self
                 .proc_state
@@ -724,6 +1065,9 @@ impl App {
                         }
                     }
                 }
+                BottomWidgetType::Cpu | BottomWidgetType::Mem | BottomWidgetType::Net => {
+                    self.pan_graph(true);
+                }
                 _ => {}
             }
         } else if self.delete_dialog_state.is_showing_dd && !self.delete_dialog_state.is_on_yes {
@@ -793,6 +1137,9 @@ impl App {
                         }
                     }
                 }
+                BottomWidgetType::Cpu | BottomWidgetType::Mem | BottomWidgetType::Net => {
+                    self.pan_graph(false);
+                }
                 _ => {}
             }
         } else if self.delete_dialog_state.is_showing_dd && self.delete_dialog_state.is_on_yes {
@@ -898,6 +1245,12 @@ impl App {
     pub fn start_dd(&mut self) {
         self.reset_multi_tap_keys();
 
+        // Replayed processes aren't running on this system, so they must not be killed.
+        if let Some(data_source) = self.get_foreign_data_source() {
+            self.show_message(format!("Processes can't be killed while {}.", data_source));
+            return;
+        }
+
         if let Some(proc_widget_state) = self
             .proc_state
             .widget_states
@@ -911,20 +1264,17 @@ impl App {
                 if proc_widget_state.scroll_state.current_scroll_position
                     < corresponding_filtered_process_list.len()
                 {
-                    let current_process: (String, Vec<Pid>);
-                    if self.is_grouped(self.current_widget.widget_id) {
-                        if let Some(process) = &corresponding_filtered_process_list
-                            .get(proc_widget_state.scroll_state.current_scroll_position)
-                        {
-                            current_process = (process.name.to_string(), process.group_pids.clone())
-                        } else {
-                            return;
-                        }
+                    let process = &corresponding_filtered_process_list
+                        [proc_widget_state.scroll_state.current_scroll_position];
+                    if !process.is_running() {
+                        // There's nothing to kill.
+                        return;
+                    }
+
+                    let current_process = if self.is_grouped(self.current_widget.widget_id) {
+                        (process.name.clone(), process.group_pids.clone())
                     } else {
-                        let process = corresponding_filtered_process_list
-                            [proc_widget_state.scroll_state.current_scroll_position]
-                            .clone();
-                        current_process = (process.name.clone(), vec![process.pid])
+                        (process.name.clone(), vec![process.pid])
                     };
 
                     self.to_delete_process_list = Some(current_process);
@@ -1007,12 +1357,35 @@ impl App {
                         return;
                     }
                 }
+            } else if let BottomWidgetType::ProcSort = self.current_widget.widget_type {
+                if self.select_sort_by_mnemonic(caught_char) {
+                    return;
+                }
             }
             self.handle_char(caught_char);
         } else if self.help_dialog_state.is_showing_help {
+            if self.help_dialog_state.is_searching {
+                self.help_dialog_state.search_query.push(caught_char);
+                self.help_dialog_state.scroll_state.current_scroll_index = 0;
+                self.is_force_redraw = true;
+                return;
+            }
+
             match caught_char {
-                '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' => {
-                    let potential_index = caught_char.to_digit(10);
+                '/' => {
+                    self.help_dialog_state.is_searching = true;
+                    self.help_dialog_state.scroll_state.current_scroll_index = 0;
+                    self.is_force_redraw = true;
+                }
+                // The section shortcuts don't line up with a filtered list.
+                '0' | '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9'
+                    if self.help_dialog_state.search_query.is_empty() =>
+                {
+                    // 0 is after 9, like on the keyboard.
+                    let potential_index = match caught_char.to_digit(10) {
+                        Some(0) => Some(10),
+                        potential_index => potential_index,
+                    };
                     if let Some(potential_index) = potential_index {
                         if (potential_index as usize) < self.help_dialog_state.index_shortcuts.len()
                         {
@@ -1179,10 +1552,14 @@ impl App {
 
                         self.proc_state.force_update = Some(self.current_widget.widget_id);
                     }
+                } else if let BottomWidgetType::Disk = self.current_widget.widget_type {
+                    self.toggle_disk_basenames();
                 }
             }
             'n' => {
-                if let BottomWidgetType::Proc = self.current_widget.widget_type {
+                if let BottomWidgetType::Temp = self.current_widget.widget_type {
+                    self.sort_temp_by(TempSorting::SensorName);
+                } else if let BottomWidgetType::Proc = self.current_widget.widget_type {
                     if let Some(proc_widget_state) = self
                         .proc_state
                         .get_mut_widget_state(self.current_widget.widget_id)
@@ -1216,7 +1593,33 @@ impl App {
             'L' | 'D' => self.move_widget_selection(&WidgetDirection::Right),
             'K' | 'W' => self.move_widget_selection(&WidgetDirection::Up),
             'J' | 'S' => self.move_widget_selection(&WidgetDirection::Down),
-            't' => self.toggle_tree_mode(),
+            'F' => self.toggle_pin_highlighted_process(),
+            'w' => self.toggle_watch_highlighted_process(),
+            'N' => self.toggle_search_highlighted_name(),
+            'B' => self.toggle_process_baseline(),
+            'u' => self.toggle_only_own_processes(),
+            'o' => {
+                if let BottomWidgetType::Proc = self.current_widget.widget_type {
+                    self.toggle_expanded_process(ExpandedProcessView::Command);
+                }
+            }
+            'r' => self.resume_highlighted_process(),
+            'b' => self.toggle_breakdown(),
+            'v' => self.toggle_cpu_heatmap(),
+            'T' => self.toggle_network_total(),
+            'x' => self.toggle_network_axes(),
+            '1' => self.toggle_network_series(true),
+            '2' => self.toggle_network_series(false),
+            ' ' => self.toggle_cpu_entry_visibility(),
+            'a' => self.toggle_hidden_disks(),
+            'y' => self.copy_snapshot_to_clipboard(),
+            'Y' => self.request_graph_capture(),
+            'E' => self.export_graph_csv(),
+            'V' => self.request_process_pager(),
+            't' => match self.current_widget.widget_type {
+                BottomWidgetType::Temp => self.sort_temp_by(TempSorting::Temperature),
+                _ => self.toggle_tree_mode(),
+            },
             '+' => self.zoom_in(),
             '-' => self.zoom_out(),
             '=' => self.reset_zoom(),
@@ -1234,6 +1637,532 @@ impl App {
         }
     }
 
+    /// Copies a text summary of the current system state to the clipboard.  If there is no
+    /// clipboard, such as over SSH, the summary is written to a file instead.
+    fn copy_snapshot_to_clipboard(&mut self) {
+        let summary = Snapshot::from_data_collection(
+            &self.data_collection,
+            self.app_config_fields.time_format.as_deref(),
+        )
+        .to_text(&self.app_config_fields.byte_format);
+        self.copy_or_save_text(
+            &summary,
+            "the summary",
+            constants::SNAPSHOT_SUMMARY_FILE_NAME,
+        );
+    }
+
+    /// Asks the canvas to capture the selected graph as text once it has been drawn.
+    fn request_graph_capture(&mut self) {
+        let widget_id = match self.current_widget.widget_type {
+            BottomWidgetType::Cpu | BottomWidgetType::Mem | BottomWidgetType::Net => {
+                self.current_widget.widget_id
+            }
+            BottomWidgetType::CpuLegend => self.current_widget.widget_id - 1,
+            _ => return,
+        };
+
+        self.graph_capture_widget_id = Some(widget_id);
+        self.is_force_redraw = true;
+    }
+
+    /// Exports the retained history of the selected graph as CSV to a file.
+    fn export_graph_csv(&mut self) {
+        let widget_type = match self.current_widget.widget_type {
+            BottomWidgetType::CpuLegend => BottomWidgetType::Cpu,
+            ref widget_type => widget_type.clone(),
+        };
+
+        if let Some(csv) = export_graph_csv(
+            &self.data_collection,
+            &widget_type,
+            self.app_config_fields.time_format.as_deref(),
+        ) {
+            let path = std::env::temp_dir().join(constants::GRAPH_CSV_FILE_NAME);
+            let message = match std::fs::write(&path, csv) {
+                Ok(_) => format!("Exported the chart data to {}.", path.display()),
+                Err(err) => format!("Unable to export the chart data: {}", err),
+            };
+            self.show_message(message);
+        }
+    }
+
+    /// Asks the main loop to show the selected process table as plain text in the user's pager.
+    fn request_process_pager(&mut self) {
+        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+            if let Some(proc_widget_state) = self
+                .proc_state
+                .get_widget_state(self.current_widget.widget_id)
+            {
+                self.pager_text = Some(get_plain_text_table(
+                    &proc_widget_state.columns.get_plain_column_headers(),
+                    &proc_widget_state.cached_process_rows,
+                ));
+            }
+        }
+    }
+
+    /// Copies a graph captured by the canvas to the clipboard, or to a file if there is
+    /// no clipboard.
+    pub fn copy_graph_capture(&mut self, captured_graph: &str) {
+        self.copy_or_save_text(
+            captured_graph,
+            "the chart",
+            constants::GRAPH_CAPTURE_FILE_NAME,
+        );
+    }
+
+    fn copy_or_save_text(&mut self, text: &str, description: &str, file_name: &str) {
+        let message = match copy_to_clipboard(text) {
+            Ok(_) => format!("Copied {} to the clipboard.", description),
+            Err(copy_err) => {
+                // The log is only kept by debug builds, so the text is also saved to a file.
+                warn!("Unable to copy {}: {}\n{}", description, copy_err, text);
+
+                let path = std::env::temp_dir().join(file_name);
+                match std::fs::write(&path, text) {
+                    Ok(_) => format!(
+                        "No clipboard is available, so {} was written to {}.",
+                        description,
+                        path.display()
+                    ),
+                    Err(err) => format!("Unable to copy or save {}: {}", description, err),
+                }
+            }
+        };
+
+        self.show_message(message);
+    }
+
+    /// Describes where the shown data comes from if it isn't this system, in which case its
+    /// processes can't be acted on.
+    pub fn get_foreign_data_source(&self) -> Option<&'static str> {
+        if self.is_replaying {
+            Some("replaying a recording")
+        } else if self.remote_host.is_some() {
+            Some("monitoring a remote host")
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether no data has been received for long enough that the harvester is
+    /// likely lagging behind the update rate.
+    pub fn is_data_delayed(&self) -> bool {
+        if let Some(last_update_instant) = self.last_update_instant {
+            let update_rate_in_milliseconds = match &self.rate_ramp {
+                Some(rate_ramp) => rate_ramp.get_current_rate(),
+                None => self.app_config_fields.update_rate_in_milliseconds,
+            };
+            Instant::now()
+                .duration_since(last_update_instant)
+                .as_millis() as u64
+                > update_rate_in_milliseconds * constants::DATA_DELAYED_RATE_MULTIPLIER
+        } else {
+            false
+        }
+    }
+
+    /// Returns whether anything is shown that changes or goes away by itself after a while,
+    /// such as a message or a temporarily shown time axis.
+    pub fn is_showing_timed_elements(&self) -> bool {
+        self.message_dialog_state.shown_at.is_some()
+            || self
+                .cpu_state
+                .widget_states
+                .values()
+                .any(|state| state.autohide_timer.is_some())
+            || self
+                .mem_state
+                .widget_states
+                .values()
+                .any(|state| state.autohide_timer.is_some())
+            || self
+                .net_state
+                .widget_states
+                .values()
+                .any(|state| state.autohide_timer.is_some())
+    }
+
+    /// Shows a message to the user for a brief moment.
+    pub fn show_message(&mut self, message: String) {
+        self.message_dialog_state.message = Some(message);
+        self.message_dialog_state.shown_at = Some(Instant::now());
+    }
+
+    /// Toggles whether the CPU widget shows the usage breakdown of the selected entry
+    /// rather than its total usage, or whether the memory widget shows buffers and cached
+    /// memory on top of used memory.
+    fn toggle_breakdown(&mut self) {
+        match self.current_widget.widget_type {
+            BottomWidgetType::Cpu | BottomWidgetType::CpuLegend => {
+                let cpu_widget_id = match self.current_widget.widget_type {
+                    BottomWidgetType::CpuLegend => self.current_widget.widget_id - 1,
+                    _ => self.current_widget.widget_id,
+                };
+
+                if self.app_config_fields.disable_cpu_breakdown {
+                    self.show_message(
+                        "The CPU usage breakdown is turned off by disable_cpu_breakdown."
+                            .to_string(),
+                    );
+                } else if let Some(cpu_widget_state) =
+                    self.cpu_state.get_mut_widget_state(cpu_widget_id)
+                {
+                    cpu_widget_state.is_showing_breakdown = !cpu_widget_state.is_showing_breakdown;
+                }
+            }
+            BottomWidgetType::Mem => {
+                if let Some(mem_widget_state) = self
+                    .mem_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    mem_widget_state.is_showing_breakdown = !mem_widget_state.is_showing_breakdown;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Toggles whether the CPU widget draws a heatmap of each core's usage over time
+    /// rather than a line per entry.
+    fn toggle_cpu_heatmap(&mut self) {
+        let cpu_widget_id = match self.current_widget.widget_type {
+            BottomWidgetType::Cpu => self.current_widget.widget_id,
+            BottomWidgetType::CpuLegend => self.current_widget.widget_id - 1,
+            _ => return,
+        };
+
+        if let Some(cpu_widget_state) = self.cpu_state.get_mut_widget_state(cpu_widget_id) {
+            cpu_widget_state.is_showing_heatmap = !cpu_widget_state.is_showing_heatmap;
+        }
+    }
+
+    /// Toggles whether the network widgets show the totals since bottom started rather than
+    /// the interface totals since boot.
+    fn toggle_network_total(&mut self) {
+        if let BottomWidgetType::Net | BottomWidgetType::BasicNet = self.current_widget.widget_type
+        {
+            self.net_state.is_showing_session_total = !self.net_state.is_showing_session_total;
+            self.net_state.force_update = Some(self.current_widget.widget_id);
+        }
+    }
+
+    /// Toggles whether the network graph draws RX and TX on separate y-axes.
+    fn toggle_network_axes(&mut self) {
+        if let BottomWidgetType::Net = self.current_widget.widget_type {
+            self.net_state.is_showing_split_axes = !self.net_state.is_showing_split_axes;
+        }
+    }
+
+    /// Toggles whether the RX or TX series is drawn in the selected network graph.
+    fn toggle_network_series(&mut self, is_rx: bool) {
+        if let BottomWidgetType::Net = self.current_widget.widget_type {
+            if let Some(net_widget_state) = self
+                .net_state
+                .get_mut_widget_state(self.current_widget.widget_id)
+            {
+                if is_rx {
+                    net_widget_state.is_rx_hidden = !net_widget_state.is_rx_hidden;
+                } else {
+                    net_widget_state.is_tx_hidden = !net_widget_state.is_tx_hidden;
+                }
+            }
+        }
+    }
+
+    /// Toggles whether the selected CPU legend entry is drawn when all entries are shown.
+    /// Toggling the "All" entry shows every entry again.
+    fn toggle_cpu_entry_visibility(&mut self) {
+        if let BottomWidgetType::CpuLegend = self.current_widget.widget_type {
+            if let Some(cpu_widget_state) = self
+                .cpu_state
+                .get_mut_widget_state(self.current_widget.widget_id - 1)
+            {
+                // The "All" entry is always first.
+                let position = cpu_widget_state.scroll_state.current_scroll_position;
+                if position == 0 {
+                    cpu_widget_state.hidden_entries.clear();
+                } else if !cpu_widget_state.hidden_entries.remove(&position) {
+                    cpu_widget_state.hidden_entries.insert(position);
+                }
+            }
+        }
+    }
+
+    /// Returns the disks to hide from the disk table, or `None` if all disks are being shown.
+    pub fn get_hidden_disks(&self) -> Option<&HiddenDisks> {
+        if self.disk_state.is_showing_hidden {
+            None
+        } else {
+            Some(&self.filters.hidden_disks)
+        }
+    }
+
+    fn toggle_hidden_disks(&mut self) {
+        if let BottomWidgetType::Disk = self.current_widget.widget_type {
+            self.disk_state.is_showing_hidden = !self.disk_state.is_showing_hidden;
+            self.disk_state.force_update = Some(self.current_widget.widget_id);
+            self.show_message(if self.disk_state.is_showing_hidden {
+                "Showing all disks".to_string()
+            } else {
+                "Hiding disks by file system and mount".to_string()
+            });
+        }
+    }
+
+    fn toggle_disk_file_system_sort(&mut self) {
+        self.disk_state.is_sorted_by_file_system = !self.disk_state.is_sorted_by_file_system;
+        self.disk_state.force_update = Some(self.current_widget.widget_id);
+        self.show_message(if self.disk_state.is_sorted_by_file_system {
+            "Grouping disks by file system".to_string()
+        } else {
+            "Sorting disks by name".to_string()
+        });
+    }
+
+    fn toggle_disk_basenames(&mut self) {
+        if let Some(disk_widget_state) = self
+            .disk_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            disk_widget_state.is_showing_basenames = !disk_widget_state.is_showing_basenames;
+            let is_showing_basenames = disk_widget_state.is_showing_basenames;
+            self.show_message(if is_showing_basenames {
+                "Showing disk and mount names".to_string()
+            } else {
+                "Showing full disk and mount paths".to_string()
+            });
+        }
+    }
+
+    /// Pins the highlighted process to the top of the process widgets, or unpins it if it's
+    /// already pinned.
+    fn toggle_pin_highlighted_process(&mut self) {
+        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+            let widget_id = self.current_widget.widget_id;
+            if let Some(proc_widget_state) = self.proc_state.get_widget_state(widget_id) {
+                let highlighted_name = self
+                    .canvas_data
+                    .finalized_process_data_map
+                    .get(&widget_id)
+                    .and_then(|process_list| {
+                        process_list.get(proc_widget_state.scroll_state.current_scroll_position)
+                    })
+                    .map(|process| process.name.clone());
+
+                if let Some(name) = highlighted_name {
+                    self.proc_state.toggle_pinned(&name);
+                }
+            }
+        }
+    }
+
+    /// Searches for every process with the highlighted process' name, or clears that search if
+    /// it is already the current one.
+    fn toggle_search_highlighted_name(&mut self) {
+        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+            let widget_id = self.current_widget.widget_id;
+            if let Some(proc_widget_state) = self.proc_state.get_mut_widget_state(widget_id) {
+                let highlighted_name = self
+                    .canvas_data
+                    .finalized_process_data_map
+                    .get(&widget_id)
+                    .and_then(|process_list| {
+                        process_list.get(proc_widget_state.scroll_state.current_scroll_position)
+                    })
+                    .filter(|process| !process.is_disabled_entry)
+                    .map(|process| process.name.clone());
+
+                if let Some(name) = highlighted_name {
+                    // Quoting treats the whole name as one search term, even with spaces.
+                    let name_query = format!("\"{}\"", name.replace('"', ""));
+                    if *proc_widget_state.get_current_search_query() == name_query {
+                        proc_widget_state.clear_search();
+                        proc_widget_state
+                            .process_search_state
+                            .search_state
+                            .is_enabled = false;
+                    } else {
+                        proc_widget_state.set_search_query(name_query);
+                        proc_widget_state
+                            .process_search_state
+                            .search_state
+                            .is_enabled = true;
+                    }
+
+                    self.proc_state.force_update = Some(widget_id);
+                    self.is_force_redraw = true;
+                }
+            }
+        }
+    }
+
+    /// Watches the highlighted process, or unwatches it if it's already watched.  The watched
+    /// processes are saved so they're kept between runs.
+    fn toggle_watch_highlighted_process(&mut self) {
+        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+            let widget_id = self.current_widget.widget_id;
+            if let Some(proc_widget_state) = self.proc_state.get_widget_state(widget_id) {
+                let highlighted_name = self
+                    .canvas_data
+                    .finalized_process_data_map
+                    .get(&widget_id)
+                    .and_then(|process_list| {
+                        process_list.get(proc_widget_state.scroll_state.current_scroll_position)
+                    })
+                    .filter(|process| !process.is_disabled_entry)
+                    .map(|process| process.name.clone());
+
+                if let Some(name) = highlighted_name {
+                    self.proc_state.toggle_watched(&name);
+
+                    let state_file = state_file::StateFile {
+                        watched: self.proc_state.watched_names.clone(),
+                    };
+                    if let Err(err) = state_file.write() {
+                        self.show_message(format!("Couldn't save watched processes: {}", err));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Sends SIGCONT to the highlighted process if it's stopped, or to every process in its group
+    /// if processes are grouped.
+    fn resume_highlighted_process(&mut self) {
+        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+            let widget_id = self.current_widget.widget_id;
+            let highlighted_process =
+                if let Some(proc_widget_state) = self.proc_state.get_widget_state(widget_id) {
+                    self.canvas_data
+                        .finalized_process_data_map
+                        .get(&widget_id)
+                        .and_then(|process_list| {
+                            process_list.get(proc_widget_state.scroll_state.current_scroll_position)
+                        })
+                        .filter(|process| !process.is_disabled_entry && process.is_running())
+                        .map(|process| {
+                            let pids = if proc_widget_state.is_grouped {
+                                process.group_pids.clone()
+                            } else {
+                                vec![process.pid]
+                            };
+                            (process.name.clone(), process.is_stopped(), pids)
+                        })
+                } else {
+                    None
+                };
+
+            if let Some((name, is_stopped, pids)) = highlighted_process {
+                if let Some(data_source) = self.get_foreign_data_source() {
+                    self.show_message(format!("Processes can't be resumed while {}.", data_source));
+                } else if !is_stopped {
+                    self.show_message(format!("{} is not stopped", name));
+                } else {
+                    let result = pids
+                        .into_iter()
+                        .try_for_each(process_killer::resume_process_given_pid);
+
+                    self.show_message(match result {
+                        Ok(()) => format!("Resumed {}", name),
+                        Err(err) => format!("Couldn't resume {}: {}", name, err),
+                    });
+                }
+            }
+        }
+    }
+
+    /// Marks the current processes as the baseline that process widgets show changes against,
+    /// or clears the baseline if one is already marked.
+    fn toggle_process_baseline(&mut self) {
+        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+            if self.canvas_data.process_diff_baseline.is_some() {
+                self.canvas_data.process_diff_baseline = None;
+                self.show_message("Cleared the process baseline".to_string());
+            } else {
+                self.canvas_data.process_diff_baseline =
+                    Some(self.canvas_data.single_process_data.clone());
+                self.show_message("Marked the current processes as the baseline".to_string());
+            }
+            self.canvas_data.process_exit_times.clear();
+            self.proc_state.force_update_all = true;
+        }
+    }
+
+    /// Returns which of the optional process fields need to be harvested for the process
+    /// widgets as they are currently shown.
+    pub fn get_process_fields(&self) -> processes::ProcessFields {
+        let is_column_shown = |column: &processes::ProcessSorting| {
+            self.proc_state
+                .widget_states
+                .values()
+                .any(|proc_widget_state| proc_widget_state.columns.is_enabled(column))
+        };
+
+        processes::ProcessFields {
+            swap: is_column_shown(&processes::ProcessSorting::Swap),
+            // Processes are highlighted by their OOM score even while the column is hidden.
+            oom_score: is_column_shown(&processes::ProcessSorting::OomScore)
+                || self.app_config_fields.oom_score_warn_threshold.is_some(),
+            cpu_affinity: is_column_shown(&processes::ProcessSorting::CpuAffinity),
+            uid: self
+                .proc_state
+                .widget_states
+                .values()
+                .any(|proc_widget_state| proc_widget_state.is_showing_only_own),
+        }
+    }
+
+    /// Returns whether any CPU widget is showing the usage breakdown, which is only harvested
+    /// while it is.
+    pub fn is_showing_cpu_breakdown(&self) -> bool {
+        self.cpu_state
+            .widget_states
+            .values()
+            .any(|cpu_widget_state| cpu_widget_state.is_showing_breakdown)
+    }
+
+    /// Shows only the processes owned by the user running bottom in the selected process
+    /// widget, or shows every process again.
+    fn toggle_only_own_processes(&mut self) {
+        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+            if processes::get_current_uid().is_none() {
+                self.show_message(
+                    "Filtering processes by owner isn't available on this platform.".to_string(),
+                );
+                return;
+            }
+
+            if let Some(proc_widget_state) = self
+                .proc_state
+                .get_mut_widget_state(self.current_widget.widget_id)
+            {
+                proc_widget_state.is_showing_only_own = !proc_widget_state.is_showing_only_own;
+                self.proc_state.force_update = Some(self.current_widget.widget_id);
+            }
+        }
+    }
+
+    /// Updates whether memory usage is over the configured warning threshold, based on the
+    /// latest converted memory data.  Returns true only when usage has just crossed the threshold,
+    /// so callers can alert once rather than on every update.
+    pub fn update_mem_warning(&mut self) -> bool {
+        if let Some(threshold) = self.app_config_fields.mem_warn_threshold_percent {
+            let is_over_threshold = match self.canvas_data.mem_data.last() {
+                Some((_time, mem_percent)) => *mem_percent >= threshold,
+                None => false,
+            };
+            let has_crossed_threshold = is_over_threshold && !self.mem_state.is_over_warn_threshold;
+            self.mem_state.is_over_warn_threshold = is_over_threshold;
+
+            has_crossed_threshold
+        } else {
+            false
+        }
+    }
+
     pub fn kill_highlighted_process(&mut self) -> Result<()> {
         if let BottomWidgetType::Proc = self.current_widget.widget_type {
             if let Some(current_selected_processes) = &self.to_delete_process_list {
@@ -1852,6 +2781,9 @@ impl App {
                         }
                     }
                 }
+                BottomWidgetType::Cpu | BottomWidgetType::Mem | BottomWidgetType::Net => {
+                    self.jump_graph_to_live();
+                }
                 _ => {}
             }
             self.reset_multi_tap_keys();
@@ -1865,26 +2797,23 @@ impl App {
     }
 
     pub fn decrement_position_count(&mut self) {
-        if !self.is_in_dialog() {
-            match self.current_widget.widget_type {
-                BottomWidgetType::Proc => self.increment_process_position(-1),
-                BottomWidgetType::ProcSort => self.increment_process_sort_position(-1),
-                BottomWidgetType::Temp => self.increment_temp_position(-1),
-                BottomWidgetType::Disk => self.increment_disk_position(-1),
-                BottomWidgetType::CpuLegend => self.increment_cpu_legend_position(-1),
-                _ => {}
-            }
-        }
+        self.change_position_count(-1);
     }
 
     pub fn increment_position_count(&mut self) {
+        self.change_position_count(1);
+    }
+
+    fn change_position_count(&mut self, num_to_change_by: i64) {
         if !self.is_in_dialog() {
             match self.current_widget.widget_type {
-                BottomWidgetType::Proc => self.increment_process_position(1),
-                BottomWidgetType::ProcSort => self.increment_process_sort_position(1),
-                BottomWidgetType::Temp => self.increment_temp_position(1),
-                BottomWidgetType::Disk => self.increment_disk_position(1),
-                BottomWidgetType::CpuLegend => self.increment_cpu_legend_position(1),
+                BottomWidgetType::Proc => self.increment_process_position(num_to_change_by),
+                BottomWidgetType::ProcSort => {
+                    self.increment_process_sort_position(num_to_change_by)
+                }
+                BottomWidgetType::Temp => self.increment_temp_position(num_to_change_by),
+                BottomWidgetType::Disk => self.increment_disk_position(num_to_change_by),
+                BottomWidgetType::CpuLegend => self.increment_cpu_legend_position(num_to_change_by),
                 _ => {}
             }
         }
@@ -1895,21 +2824,16 @@ impl App {
             .proc_state
             .get_mut_widget_state(self.current_widget.widget_id - 2)
         {
-            let current_posn = proc_widget_state.columns.current_scroll_position;
             let num_columns = proc_widget_state.columns.get_enabled_columns_len();
-
-            if current_posn as i64 + num_to_change_by >= 0
-                && current_posn as i64 + num_to_change_by < num_columns as i64
-            {
-                proc_widget_state.columns.current_scroll_position =
-                    (current_posn as i64 + num_to_change_by) as usize;
-            }
-
-            if num_to_change_by < 0 {
-                proc_widget_state.columns.scroll_direction = ScrollDirection::Up;
-            } else {
-                proc_widget_state.columns.scroll_direction = ScrollDirection::Down;
-            }
+            let columns = &mut proc_widget_state.columns;
+
+            scroll_table_position(
+                &mut columns.current_scroll_position,
+                &mut columns.scroll_direction,
+                num_to_change_by,
+                num_columns,
+                self.app_config_fields.scroll_wrap,
+            );
         }
     }
 
@@ -1919,21 +2843,15 @@ impl App {
             .widget_states
             .get_mut(&(self.current_widget.widget_id - 1))
         {
-            let current_posn = cpu_widget_state.scroll_state.current_scroll_position;
-
-            let cap = self.canvas_data.cpu_data.len();
-            if current_posn as i64 + num_to_change_by >= 0
-                && current_posn as i64 + num_to_change_by < cap as i64
-            {
-                cpu_widget_state.scroll_state.current_scroll_position =
-                    (current_posn as i64 + num_to_change_by) as usize;
-            }
-
-            if num_to_change_by < 0 {
-                cpu_widget_state.scroll_state.scroll_direction = ScrollDirection::Up;
-            } else {
-                cpu_widget_state.scroll_state.scroll_direction = ScrollDirection::Down;
-            }
+            let scroll_state = &mut cpu_widget_state.scroll_state;
+
+            scroll_table_position(
+                &mut scroll_state.current_scroll_position,
+                &mut scroll_state.scroll_direction,
+                num_to_change_by,
+                self.canvas_data.cpu_data.len(),
+                self.app_config_fields.scroll_wrap,
+            );
         }
     }
 
@@ -1942,25 +2860,21 @@ impl App {
             .proc_state
             .get_mut_widget_state(self.current_widget.widget_id)
         {
-            let current_posn = proc_widget_state.scroll_state.current_scroll_position;
-            if let Some(finalized_process_data) = self
+            let num_processes = self
                 .canvas_data
                 .finalized_process_data_map
                 .get(&self.current_widget.widget_id)
-            {
-                if current_posn as i64 + num_to_change_by >= 0
-                    && current_posn as i64 + num_to_change_by < finalized_process_data.len() as i64
-                {
-                    proc_widget_state.scroll_state.current_scroll_position =
-                        (current_posn as i64 + num_to_change_by) as usize;
-                }
-            }
-
-            if num_to_change_by < 0 {
-                proc_widget_state.scroll_state.scroll_direction = ScrollDirection::Up;
-            } else {
-                proc_widget_state.scroll_state.scroll_direction = ScrollDirection::Down;
-            }
+                .map(|finalized_process_data| finalized_process_data.len())
+                .unwrap_or(0);
+            let scroll_state = &mut proc_widget_state.scroll_state;
+
+            scroll_table_position(
+                &mut scroll_state.current_scroll_position,
+                &mut scroll_state.scroll_direction,
+                num_to_change_by,
+                num_processes,
+                self.app_config_fields.scroll_wrap,
+            );
         }
     }
 
@@ -1970,21 +2884,15 @@ impl App {
             .widget_states
             .get_mut(&self.current_widget.widget_id)
         {
-            let current_posn = temp_widget_state.scroll_state.current_scroll_position;
-
-            if current_posn as i64 + num_to_change_by >= 0
-                && current_posn as i64 + num_to_change_by
-                    < self.canvas_data.temp_sensor_data.len() as i64
-            {
-                temp_widget_state.scroll_state.current_scroll_position =
-                    (current_posn as i64 + num_to_change_by) as usize;
-            }
-
-            if num_to_change_by < 0 {
-                temp_widget_state.scroll_state.scroll_direction = ScrollDirection::Up;
-            } else {
-                temp_widget_state.scroll_state.scroll_direction = ScrollDirection::Down;
-            }
+            let scroll_state = &mut temp_widget_state.scroll_state;
+
+            scroll_table_position(
+                &mut scroll_state.current_scroll_position,
+                &mut scroll_state.scroll_direction,
+                num_to_change_by,
+                self.canvas_data.temp_sensor_data.len(),
+                self.app_config_fields.scroll_wrap,
+            );
         }
     }
 
@@ -1994,20 +2902,15 @@ impl App {
             .widget_states
             .get_mut(&self.current_widget.widget_id)
         {
-            let current_posn = disk_widget_state.scroll_state.current_scroll_position;
-
-            if current_posn as i64 + num_to_change_by >= 0
-                && current_posn as i64 + num_to_change_by < self.canvas_data.disk_data.len() as i64
-            {
-                disk_widget_state.scroll_state.current_scroll_position =
-                    (current_posn as i64 + num_to_change_by) as usize;
-            }
-
-            if num_to_change_by < 0 {
-                disk_widget_state.scroll_state.scroll_direction = ScrollDirection::Up;
-            } else {
-                disk_widget_state.scroll_state.scroll_direction = ScrollDirection::Down;
-            }
+            let scroll_state = &mut disk_widget_state.scroll_state;
+
+            scroll_table_position(
+                &mut scroll_state.current_scroll_position,
+                &mut scroll_state.scroll_direction,
+                num_to_change_by,
+                self.canvas_data.disk_data.len(),
+                self.app_config_fields.scroll_wrap,
+            );
         }
     }
 
@@ -2035,22 +2938,28 @@ impl App {
     }
 
     pub fn handle_scroll_up(&mut self) {
+        let scroll_lines = self.app_config_fields.scroll_lines_per_tick;
         if self.help_dialog_state.is_showing_help {
-            self.help_scroll_up();
+            for _ in 0..scroll_lines {
+                self.help_scroll_up();
+            }
         } else if self.current_widget.widget_type.is_widget_graph() {
             self.zoom_in();
         } else if self.current_widget.widget_type.is_widget_table() {
-            self.decrement_position_count();
+            self.change_position_count(-(scroll_lines as i64));
         }
     }
 
     pub fn handle_scroll_down(&mut self) {
+        let scroll_lines = self.app_config_fields.scroll_lines_per_tick;
         if self.help_dialog_state.is_showing_help {
-            self.help_scroll_down();
+            for _ in 0..scroll_lines {
+                self.help_scroll_down();
+            }
         } else if self.current_widget.widget_type.is_widget_graph() {
             self.zoom_out();
         } else if self.current_widget.widget_type.is_widget_table() {
-            self.increment_position_count();
+            self.change_position_count(scroll_lines as i64);
         }
     }
 
@@ -2258,6 +3167,129 @@ impl App {
         }
     }
 
+    /// Returns how many milliseconds of data are stored up to the currently displayed time.
+    fn get_stored_history_millis(&self) -> u64 {
+        let current_time = if self.is_frozen {
+            self.data_collection
+                .frozen_instant
+                .unwrap_or(self.data_collection.current_instant)
+        } else {
+            self.data_collection.current_instant
+        };
+
+        if let Some((oldest_time, _)) = self.data_collection.timed_data_vec.first() {
+            current_time.duration_since(*oldest_time).as_millis() as u64
+        } else {
+            0
+        }
+    }
+
+    /// Shifts the currently selected graph's view back (left) or forward (right) in time by the
+    /// zoom interval.  The offset is clamped so that the view never goes past the stored history.
+    fn pan_graph(&mut self, is_panning_back: bool) {
+        let max_offset = self
+            .get_stored_history_millis()
+            .saturating_sub(self.get_current_display_time());
+        let pan_step = self.app_config_fields.time_interval;
+
+        let get_new_offset = |pan_offset: u64| {
+            if is_panning_back {
+                std::cmp::min(pan_offset + pan_step, max_offset)
+            } else {
+                pan_offset.saturating_sub(pan_step)
+            }
+        };
+
+        match self.current_widget.widget_type {
+            BottomWidgetType::Cpu => {
+                if let Some(cpu_widget_state) = self
+                    .cpu_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    cpu_widget_state.pan_offset = get_new_offset(cpu_widget_state.pan_offset);
+                }
+            }
+            BottomWidgetType::Mem => {
+                if let Some(mem_widget_state) = self
+                    .mem_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    mem_widget_state.pan_offset = get_new_offset(mem_widget_state.pan_offset);
+                }
+            }
+            BottomWidgetType::Net => {
+                if let Some(net_widget_state) = self
+                    .net_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    net_widget_state.pan_offset = get_new_offset(net_widget_state.pan_offset);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Resets the currently selected graph's view to show the latest data.
+    fn jump_graph_to_live(&mut self) {
+        match self.current_widget.widget_type {
+            BottomWidgetType::Cpu => {
+                if let Some(cpu_widget_state) = self
+                    .cpu_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    cpu_widget_state.pan_offset = 0;
+                }
+            }
+            BottomWidgetType::Mem => {
+                if let Some(mem_widget_state) = self
+                    .mem_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    mem_widget_state.pan_offset = 0;
+                }
+            }
+            BottomWidgetType::Net => {
+                if let Some(net_widget_state) = self
+                    .net_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    net_widget_state.pan_offset = 0;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the display time of the currently selected graph.
+    fn get_current_display_time(&self) -> u64 {
+        let widget_id = &self.current_widget.widget_id;
+        match self.current_widget.widget_type {
+            BottomWidgetType::Cpu => self
+                .cpu_state
+                .widget_states
+                .get(widget_id)
+                .map(|state| state.current_display_time),
+            BottomWidgetType::Mem => self
+                .mem_state
+                .widget_states
+                .get(widget_id)
+                .map(|state| state.current_display_time),
+            BottomWidgetType::Net => self
+                .net_state
+                .widget_states
+                .get(widget_id)
+                .map(|state| state.current_display_time),
+            _ => None,
+        }
+        .unwrap_or(self.app_config_fields.default_time_value)
+    }
+
     fn reset_zoom(&mut self) {
         match self.current_widget.widget_type {
             BottomWidgetType::Cpu => self.reset_cpu_zoom(),
@@ -2270,6 +3302,8 @@ impl App {
     /// Moves the mouse to the widget that was clicked on, then propagates the click down to be
     /// handled by the widget specifically.
     pub fn left_mouse_click_movement(&mut self, x: u16, y: u16) {
+        let previous_widget_id = self.current_widget.widget_id;
+
         // Pretty dead simple - iterate through the widget map and go to the widget where the click
         // is within.
         if let Some(bt) = &mut self.basic_table_widget_state {
@@ -2425,9 +3459,17 @@ impl App {
                                     if let Some(visual_index) =
                                         cpu_widget_state.scroll_state.table_state.selected()
                                     {
-                                        self.increment_cpu_legend_position(
-                                            offset_clicked_entry as i64 - visual_index as i64,
-                                        );
+                                        // Clicking the selected entry again toggles whether
+                                        // it is drawn.
+                                        if previous_widget_id == self.current_widget.widget_id
+                                            && usize::from(offset_clicked_entry) == visual_index
+                                        {
+                                            self.toggle_cpu_entry_visibility();
+                                        } else {
+                                            self.increment_cpu_legend_position(
+                                                offset_clicked_entry as i64 - visual_index as i64,
+                                            );
+                                        }
                                     }
                                 }
                             }
@@ -2482,3 +3524,38 @@ impl App {
         }
     }
 }
+
+/// Moves a table's selection by the given number of entries, if it stays within the table.  If
+/// `scroll_wrap` is set, moving a single entry past either end goes to the other end instead,
+/// while moving several entries past either end stops at that end.
+fn scroll_table_position(
+    current_scroll_position: &mut usize, scroll_direction: &mut ScrollDirection,
+    num_to_change_by: i64, num_entries: usize, scroll_wrap: bool,
+) {
+    let new_position = *current_scroll_position as i64 + num_to_change_by;
+    if new_position >= 0 && new_position < num_entries as i64 {
+        *current_scroll_position = new_position as usize;
+    } else if num_to_change_by.abs() > 1 && num_entries > 0 {
+        *current_scroll_position = if num_to_change_by < 0 {
+            0
+        } else {
+            num_entries - 1
+        };
+    } else if scroll_wrap && num_to_change_by.abs() == 1 && num_entries > 0 {
+        // The direction follows where the selection went, so the table scrolls to show it.
+        if num_to_change_by < 0 {
+            *current_scroll_position = num_entries - 1;
+            *scroll_direction = ScrollDirection::Down;
+        } else {
+            *current_scroll_position = 0;
+            *scroll_direction = ScrollDirection::Up;
+        }
+        return;
+    }
+
+    if num_to_change_by < 0 {
+        *scroll_direction = ScrollDirection::Up;
+    } else {
+        *scroll_direction = ScrollDirection::Down;
+    }
+}
@@ -13,11 +13,15 @@ use lazy_static::lazy_static;
 /// call the purging function.  Failure to do so *will* result in a growing
 /// memory usage and higher CPU usage - you will be trying to process more and
 /// more points as this is used!
-use std::{time::Instant, vec::Vec};
+use std::{collections::VecDeque, time::Instant, vec::Vec};
 
 use crate::{
-    data_harvester::{battery_harvester, cpu, disks, mem, network, processes, temperature, Data},
-    utils::gen_util::get_simple_byte_values,
+    constants::{BATTERY_ESTIMATE_MIN_SPAN_IN_SECONDS, BATTERY_ESTIMATE_WINDOW_IN_SECONDS},
+    data_harvester::{
+        battery_harvester, cpu, disks, fan, mem, network, pressure, processes, system_info,
+        temperature, Data,
+    },
+    utils::gen_util::{get_simple_byte_values, ByteFormat},
 };
 use regex::Regex;
 
@@ -29,10 +33,47 @@ pub struct TimedData {
     pub rx_data: Value,
     pub tx_data: Value,
     pub cpu_data: Vec<Value>,
+    pub cpu_breakdown_data: Vec<Option<cpu::CpuBreakdown>>,
     pub mem_data: Value,
+    /// Buffers and cached memory as a percentage of total memory, if available.
+    pub mem_buffers_data: Option<Value>,
+    pub mem_cached_data: Option<Value>,
     pub swap_data: Value,
 }
 
+/// The recent charge readings of a battery since it last changed state, used to estimate how
+/// long it will take to fill or empty when the OS doesn't report it.
+#[derive(Debug, Default)]
+pub struct BatteryChargeHistory {
+    pub state: battery_harvester::BatteryState,
+    pub readings: VecDeque<(Instant, f64)>,
+}
+
+impl BatteryChargeHistory {
+    /// Returns the estimated seconds until the battery is full while charging, or empty while
+    /// discharging, based on how fast the charge changed over the readings.
+    pub fn estimate_secs_remaining(&self) -> Option<i64> {
+        let (first_time, first_charge) = self.readings.front()?;
+        let (last_time, last_charge) = self.readings.back()?;
+        let span = last_time.duration_since(*first_time).as_secs_f64();
+        if span < BATTERY_ESTIMATE_MIN_SPAN_IN_SECONDS as f64 {
+            return None;
+        }
+
+        let charge_per_sec = (last_charge - first_charge) / span;
+        let secs_remaining = match self.state {
+            battery_harvester::BatteryState::Charging if charge_per_sec > 0.0 => {
+                (100.0 - last_charge) / charge_per_sec
+            }
+            battery_harvester::BatteryState::Discharging if charge_per_sec < 0.0 => {
+                last_charge / -charge_per_sec
+            }
+            _ => return None,
+        };
+        Some(secs_remaining as i64)
+    }
+}
+
 /// AppCollection represents the pooled data stored within the main app
 /// thread.  Basically stores a (occasionally cleaned) record of the data
 /// collected, and what is needed to convert into a displayable form.
@@ -54,10 +95,26 @@ pub struct DataCollection {
     pub process_harvest: Vec<processes::ProcessHarvest>,
     pub disk_harvest: Vec<disks::DiskHarvest>,
     pub io_harvest: disks::IOHarvest,
-    pub io_labels_and_prev: Vec<((u64, u64), (u64, u64))>,
+    /// The smoothed read and write rates of each disk, followed by its previous byte counts.
+    pub io_labels_and_prev: Vec<((f64, f64), (u64, u64))>,
     pub io_labels: Vec<(String, String)>,
     pub temp_harvest: Vec<temperature::TempHarvest>,
+    pub fan_harvest: Vec<fan::FanHarvest>,
     pub battery_harvest: Vec<battery_harvester::BatteryHarvest>,
+    /// The charge history of each battery, in the same order as `battery_harvest`.
+    pub battery_charge_histories: Vec<BatteryChargeHistory>,
+    pub pressure_harvest: Option<pressure::PressureHarvest>,
+    /// Only replaced when the system info is harvested again, which is far less often than
+    /// everything else.
+    pub system_info_harvest: Option<system_info::SystemInfoHarvest>,
+    /// The interface counters when the first network data was harvested, used to get the
+    /// totals since bottom started rather than since boot.
+    pub network_session_baseline: Option<(u64, u64)>,
+    /// The smoothed receive and transmit rates shown in the network legend.
+    pub network_rate_averages: (f64, f64),
+    /// The pages swapped in and out per second since the last harvest, if available.
+    pub swap_activity_rate: Option<f64>,
+    pub scheduler_activity: Option<cpu::SchedulerActivity>,
 }
 
 impl Default for DataCollection {
@@ -76,7 +133,15 @@ impl Default for DataCollection {
             io_labels_and_prev: Vec::default(),
             io_labels: Vec::default(),
             temp_harvest: Vec::default(),
+            fan_harvest: Vec::default(),
             battery_harvest: Vec::default(),
+            battery_charge_histories: Vec::default(),
+            pressure_harvest: None,
+            system_info_harvest: None,
+            network_session_baseline: None,
+            network_rate_averages: (0.0, 0.0),
+            swap_activity_rate: None,
+            scheduler_activity: None,
         }
     }
 }
@@ -85,22 +150,32 @@ impl DataCollection {
     pub fn reset(&mut self) {
         self.timed_data_vec = Vec::default();
         self.network_harvest = network::NetworkHarvest::default();
+        self.network_session_baseline = None;
+        self.network_rate_averages = (0.0, 0.0);
         self.memory_harvest = mem::MemHarvest::default();
         self.swap_harvest = mem::MemHarvest::default();
+        self.swap_activity_rate = None;
         self.cpu_harvest = cpu::CpuHarvest::default();
+        self.scheduler_activity = None;
         self.process_harvest = Vec::default();
         self.disk_harvest = Vec::default();
         self.io_harvest = disks::IOHarvest::default();
         self.io_labels_and_prev = Vec::default();
         self.temp_harvest = Vec::default();
+        self.fan_harvest = Vec::default();
         self.battery_harvest = Vec::default();
+        self.battery_charge_histories = Vec::default();
+        self.pressure_harvest = None;
+        // The system info is kept, as it would otherwise be missing until it is next harvested.
     }
 
     pub fn set_frozen_time(&mut self) {
         self.frozen_instant = Some(self.current_instant);
     }
 
-    pub fn clean_data(&mut self, max_time_millis: u64) {
+    /// Removes data older than `max_time_millis`, and the oldest data beyond `max_data_points`
+    /// entries if it is set, whichever removes more.
+    pub fn clean_data(&mut self, max_time_millis: u64, max_data_points: Option<usize>) {
         let current_time = Instant::now();
 
         let mut remove_index = 0;
@@ -112,22 +187,34 @@ impl DataCollection {
             }
         }
 
+        if let Some(max_data_points) = max_data_points {
+            remove_index = std::cmp::max(
+                remove_index,
+                self.timed_data_vec.len().saturating_sub(max_data_points),
+            );
+        }
+
         self.timed_data_vec.drain(0..remove_index);
     }
 
-    pub fn eat_data(&mut self, harvested_data: &Data) {
+    /// Adds newly harvested data.  The network and disk IO rates shown as current values are
+    /// smoothed with an exponentially weighted moving average using `rate_smoothing_alpha`,
+    /// where an alpha of 1 shows the latest rates as-is.
+    pub fn eat_data(
+        &mut self, harvested_data: &Data, rate_smoothing_alpha: f64, byte_format: &ByteFormat,
+    ) {
         let harvested_time = harvested_data.last_collection_time;
         let mut new_entry = TimedData::default();
 
         // Network
         if let Some(network) = &harvested_data.network {
-            self.eat_network(network, &mut new_entry);
+            self.eat_network(network, &mut new_entry, rate_smoothing_alpha);
         }
 
         // Memory and Swap
         if let Some(memory) = &harvested_data.memory {
             if let Some(swap) = &harvested_data.swap {
-                self.eat_memory_and_swap(memory, swap, &mut new_entry);
+                self.eat_memory_and_swap(memory, swap, &mut new_entry, harvested_time);
             }
         }
 
@@ -135,16 +222,20 @@ impl DataCollection {
         if let Some(cpu) = &harvested_data.cpu {
             self.eat_cpu(cpu, &mut new_entry);
         }
+        self.scheduler_activity = harvested_data.scheduler_activity;
 
         // Temp
         if let Some(temperature_sensors) = &harvested_data.temperature_sensors {
             self.eat_temp(temperature_sensors);
         }
 
+        // Fans
+        self.fan_harvest = harvested_data.fans.clone().unwrap_or_default();
+
         // Disks
         if let Some(disks) = &harvested_data.disks {
             if let Some(io) = &harvested_data.io {
-                self.eat_disks(disks, io, harvested_time);
+                self.eat_disks(disks, io, harvested_time, rate_smoothing_alpha, byte_format);
             }
         }
 
@@ -155,7 +246,15 @@ impl DataCollection {
 
         // Battery
         if let Some(list_of_batteries) = &harvested_data.list_of_batteries {
-            self.eat_battery(list_of_batteries);
+            self.eat_battery(list_of_batteries, harvested_time);
+        }
+
+        // Pressure
+        self.pressure_harvest = harvested_data.pressure.clone();
+
+        // System info
+        if let Some(system_info) = &harvested_data.system_info {
+            self.system_info_harvest = Some(system_info.clone());
         }
 
         // And we're done eating.  Update time and push the new entry!
@@ -165,6 +264,7 @@ impl DataCollection {
 
     fn eat_memory_and_swap(
         &mut self, memory: &mem::MemHarvest, swap: &mem::MemHarvest, new_entry: &mut TimedData,
+        harvested_time: Instant,
     ) {
         // Memory
         let mem_percent = match memory.mem_total_in_mb {
@@ -172,6 +272,15 @@ impl DataCollection {
             total => (memory.mem_used_in_mb as f64) / (total as f64) * 100.0,
         };
         new_entry.mem_data = mem_percent;
+        if memory.mem_total_in_mb > 0 {
+            let total = memory.mem_total_in_mb as f64;
+            new_entry.mem_buffers_data = memory
+                .mem_buffers_in_mb
+                .map(|buffers| buffers as f64 / total * 100.0);
+            new_entry.mem_cached_data = memory
+                .mem_cached_in_mb
+                .map(|cached| cached as f64 / total * 100.0);
+        }
 
         // Swap
         if swap.mem_total_in_mb > 0 {
@@ -182,12 +291,35 @@ impl DataCollection {
             new_entry.swap_data = swap_percent;
         }
 
+        // Swap activity, which needs the counts from the previous harvest
+        let time_since_last_harvest = harvested_time
+            .duration_since(self.current_instant)
+            .as_secs_f64();
+        self.swap_activity_rate = match (
+            swap.swap_in_pages,
+            swap.swap_out_pages,
+            self.swap_harvest.swap_in_pages,
+            self.swap_harvest.swap_out_pages,
+        ) {
+            (Some(swap_in), Some(swap_out), Some(prev_swap_in), Some(prev_swap_out))
+                if time_since_last_harvest > 0.0 =>
+            {
+                let pages =
+                    swap_in.saturating_sub(prev_swap_in) + swap_out.saturating_sub(prev_swap_out);
+                Some(pages as f64 / time_since_last_harvest)
+            }
+            _ => None,
+        };
+
         // In addition copy over latest data for easy reference
         self.memory_harvest = memory.clone();
         self.swap_harvest = swap.clone();
     }
 
-    fn eat_network(&mut self, network: &network::NetworkHarvest, new_entry: &mut TimedData) {
+    fn eat_network(
+        &mut self, network: &network::NetworkHarvest, new_entry: &mut TimedData,
+        rate_smoothing_alpha: f64,
+    ) {
         // FIXME [NETWORKING]: Support bits, support switching between decimal and binary units (move the log part to conversion and switch on the fly)
         // RX
         new_entry.rx_data = if network.rx > 0 {
@@ -203,16 +335,44 @@ impl DataCollection {
             0.0
         };
 
+        self.network_rate_averages = (
+            smooth_rate(
+                self.network_rate_averages.0,
+                network.rx as f64,
+                rate_smoothing_alpha,
+            ),
+            smooth_rate(
+                self.network_rate_averages.1,
+                network.tx as f64,
+                rate_smoothing_alpha,
+            ),
+        );
+
+        if self.network_session_baseline.is_none() {
+            self.network_session_baseline = Some((network.total_rx, network.total_tx));
+        }
+
         // In addition copy over latest data for easy reference
         self.network_harvest = network.clone();
     }
 
+    /// Returns the bytes received and transmitted since bottom started (or was last reset).
+    pub fn get_network_session_totals(&self) -> (u64, u64) {
+        let (baseline_rx, baseline_tx) = self.network_session_baseline.unwrap_or_default();
+        (
+            self.network_harvest.total_rx.saturating_sub(baseline_rx),
+            self.network_harvest.total_tx.saturating_sub(baseline_tx),
+        )
+    }
+
     fn eat_cpu(&mut self, cpu: &[cpu::CpuData], new_entry: &mut TimedData) {
         // Note this only pre-calculates the data points - the names will be
         // within the local copy of cpu_harvest.  Since it's all sequential
         // it probably doesn't matter anyways.
-        cpu.iter()
-            .for_each(|cpu| new_entry.cpu_data.push(cpu.cpu_usage));
+        cpu.iter().for_each(|cpu| {
+            new_entry.cpu_data.push(cpu.cpu_usage);
+            new_entry.cpu_breakdown_data.push(cpu.breakdown);
+        });
 
         self.cpu_harvest = cpu.to_vec();
     }
@@ -224,6 +384,7 @@ impl DataCollection {
 
     fn eat_disks(
         &mut self, disks: &[disks::DiskHarvest], io: &disks::IOHarvest, harvested_time: Instant,
+        rate_smoothing_alpha: f64, byte_format: &ByteFormat,
     ) {
         // TODO: [PO] To implement
 
@@ -259,7 +420,8 @@ impl DataCollection {
                     }
 
                     if self.io_labels_and_prev.len() <= itx {
-                        self.io_labels_and_prev.push(((0, 0), (io_r_pt, io_w_pt)));
+                        self.io_labels_and_prev
+                            .push(((0.0, 0.0), (io_r_pt, io_w_pt)));
                     }
 
                     if let Some((io_curr, io_prev)) = self.io_labels_and_prev.get_mut(itx) {
@@ -270,12 +432,23 @@ impl DataCollection {
                             / time_since_last_harvest)
                             .round() as u64;
 
-                        *io_curr = (r_rate, w_rate);
+                        *io_curr = (
+                            smooth_rate(io_curr.0, r_rate as f64, rate_smoothing_alpha),
+                            smooth_rate(io_curr.1, w_rate as f64, rate_smoothing_alpha),
+                        );
                         *io_prev = (io_r_pt, io_w_pt);
 
                         if let Some(io_labels) = self.io_labels.get_mut(itx) {
-                            let converted_read = get_simple_byte_values(r_rate, false);
-                            let converted_write = get_simple_byte_values(w_rate, false);
+                            let converted_read = get_simple_byte_values(
+                                io_curr.0.round() as u64,
+                                false,
+                                byte_format,
+                            );
+                            let converted_write = get_simple_byte_values(
+                                io_curr.1.round() as u64,
+                                false,
+                                byte_format,
+                            );
                             *io_labels = (
                                 format!("{:.*}{}/s", 0, converted_read.0, converted_read.1),
                                 format!("{:.*}{}/s", 0, converted_write.0, converted_write.1),
@@ -302,7 +475,39 @@ impl DataCollection {
         self.process_harvest = list_of_processes.to_vec();
     }
 
-    fn eat_battery(&mut self, list_of_batteries: &[battery_harvester::BatteryHarvest]) {
+    fn eat_battery(
+        &mut self, list_of_batteries: &[battery_harvester::BatteryHarvest], harvested_time: Instant,
+    ) {
+        self.battery_charge_histories
+            .resize_with(list_of_batteries.len(), BatteryChargeHistory::default);
+        for (battery, history) in list_of_batteries
+            .iter()
+            .zip(self.battery_charge_histories.iter_mut())
+        {
+            // Readings from before charging started or stopped don't say anything about now.
+            if history.state != battery.state {
+                history.state = battery.state;
+                history.readings.clear();
+            }
+            history
+                .readings
+                .push_back((harvested_time, battery.charge_percent));
+            while let Some(&(time, _charge)) = history.readings.front() {
+                if harvested_time.duration_since(time).as_secs()
+                    > BATTERY_ESTIMATE_WINDOW_IN_SECONDS
+                {
+                    history.readings.pop_front();
+                } else {
+                    break;
+                }
+            }
+        }
+
         self.battery_harvest = list_of_batteries.to_vec();
     }
 }
+
+/// Blends a new rate into an exponentially weighted moving average of the previous rates.
+fn smooth_rate(average: f64, rate: f64, alpha: f64) -> f64 {
+    alpha * rate + (1.0 - alpha) * average
+}
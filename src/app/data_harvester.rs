@@ -1,10 +1,11 @@
 //! This is the main file to house data collection functions.
 
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 #[cfg(target_os = "linux")]
 use std::collections::HashMap;
 
+use serde::{Deserialize, Serialize};
 use sysinfo::{System, SystemExt};
 
 use battery::{Battery, Manager};
@@ -16,23 +17,41 @@ use futures::join;
 pub mod battery_harvester;
 pub mod cpu;
 pub mod disks;
+pub mod fan;
+pub mod gpu;
 pub mod mem;
 pub mod network;
+pub mod pressure;
 pub mod processes;
+pub mod recording;
+pub mod system_info;
 pub mod temperature;
 
-#[derive(Clone, Debug)]
+/// How often the system info is read again, as it rarely changes.
+const SYSTEM_INFO_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Data {
+    #[serde(skip, default = "Instant::now")]
     pub last_collection_time: Instant,
     pub cpu: Option<cpu::CpuHarvest>,
+    #[serde(default)]
+    pub scheduler_activity: Option<cpu::SchedulerActivity>,
     pub memory: Option<mem::MemHarvest>,
     pub swap: Option<mem::MemHarvest>,
     pub temperature_sensors: Option<Vec<temperature::TempHarvest>>,
+    #[serde(default)]
+    pub fans: Option<Vec<fan::FanHarvest>>,
     pub network: Option<network::NetworkHarvest>,
     pub list_of_processes: Option<Vec<processes::ProcessHarvest>>,
     pub disks: Option<Vec<disks::DiskHarvest>>,
     pub io: Option<disks::IOHarvest>,
     pub list_of_batteries: Option<Vec<battery_harvester::BatteryHarvest>>,
+    #[serde(default)]
+    pub pressure: Option<pressure::PressureHarvest>,
+    /// Only set when the system info is refreshed, which is far less often than everything else.
+    #[serde(default)]
+    pub system_info: Option<system_info::SystemInfoHarvest>,
 }
 
 impl Default for Data {
@@ -40,14 +59,18 @@ impl Default for Data {
         Data {
             last_collection_time: Instant::now(),
             cpu: None,
+            scheduler_activity: None,
             memory: None,
             swap: None,
             temperature_sensors: None,
+            fans: None,
             list_of_processes: None,
             disks: None,
             io: None,
             network: None,
             list_of_batteries: None,
+            pressure: None,
+            system_info: None,
         }
     }
 }
@@ -56,11 +79,13 @@ impl Data {
     pub fn first_run_cleanup(&mut self) {
         self.io = None;
         self.temperature_sensors = None;
+        self.fans = None;
         self.list_of_processes = None;
         self.disks = None;
         self.memory = None;
         self.swap = None;
         self.cpu = None;
+        self.scheduler_activity = None;
 
         if let Some(network) = &mut self.network {
             network.first_run_cleanup();
@@ -84,9 +109,16 @@ pub struct DataCollector {
     total_rx: u64,
     total_tx: u64,
     show_average_cpu: bool,
+    disable_process_io: bool,
+    process_fields: processes::ProcessFields,
+    show_cpu_breakdown: bool,
+    prev_cpu_times: cpu::CpuTimesMap,
+    show_scheduler_activity: bool,
+    prev_scheduler_counters: Option<(Instant, cpu::SchedulerCounters)>,
     widgets_to_harvest: UsedWidgets,
     battery_manager: Option<Manager>,
     battery_list: Option<Vec<Battery>>,
+    prev_system_info_time: Option<Instant>,
     #[cfg(target_os = "linux")]
     page_file_size_kb: u64,
 }
@@ -109,9 +141,16 @@ impl Default for DataCollector {
             total_rx: 0,
             total_tx: 0,
             show_average_cpu: false,
+            disable_process_io: false,
+            process_fields: processes::ProcessFields::default(),
+            show_cpu_breakdown: false,
+            prev_cpu_times: cpu::CpuTimesMap::new(),
+            show_scheduler_activity: false,
+            prev_scheduler_counters: None,
             widgets_to_harvest: UsedWidgets::default(),
             battery_manager: None,
             battery_list: None,
+            prev_system_info_time: None,
             #[cfg(target_os = "linux")]
             page_file_size_kb: unsafe { libc::sysconf(libc::_SC_PAGESIZE) as u64 / 1024 },
         }
@@ -155,6 +194,22 @@ impl DataCollector {
         self.show_average_cpu = show_average_cpu;
     }
 
+    pub fn set_show_cpu_breakdown(&mut self, show_cpu_breakdown: bool) {
+        self.show_cpu_breakdown = show_cpu_breakdown;
+    }
+
+    pub fn set_disable_process_io(&mut self, disable_process_io: bool) {
+        self.disable_process_io = disable_process_io;
+    }
+
+    pub fn set_process_fields(&mut self, process_fields: processes::ProcessFields) {
+        self.process_fields = process_fields;
+    }
+
+    pub fn set_show_scheduler_activity(&mut self, show_scheduler_activity: bool) {
+        self.show_scheduler_activity = show_scheduler_activity;
+    }
+
     pub async fn update_data(&mut self) {
         if self.widgets_to_harvest.use_cpu {
             self.sys.refresh_cpu();
@@ -192,9 +247,39 @@ impl DataCollector {
 
         // CPU
         if self.widgets_to_harvest.use_cpu {
-            self.data.cpu = Some(cpu::get_cpu_data_list(&self.sys, self.show_average_cpu));
+            self.data.cpu = Some(cpu::get_cpu_data_list(
+                &self.sys,
+                self.show_average_cpu,
+                self.show_cpu_breakdown,
+                &mut self.prev_cpu_times,
+            ));
+
+            if self.show_scheduler_activity {
+                self.data.scheduler_activity =
+                    cpu::get_scheduler_activity(&mut self.prev_scheduler_counters, current_instant);
+            }
         }
 
+        // Pressure
+        self.data.pressure = pressure::get_pressure_data(self.widgets_to_harvest.use_pressure);
+
+        // System info, which rarely changes, so it's only sent when it's refreshed
+        self.data.system_info = if self.widgets_to_harvest.use_system_info
+            && self
+                .prev_system_info_time
+                .map_or(true, |prev_system_info_time| {
+                    current_instant.duration_since(prev_system_info_time)
+                        >= SYSTEM_INFO_REFRESH_INTERVAL
+                }) {
+            self.prev_system_info_time = Some(current_instant);
+            Some(system_info::get_system_info(&self.sys, self.mem_total_kb))
+        } else {
+            None
+        };
+
+        // Fans, which are shown alongside temperatures
+        self.data.fans = fan::get_fan_data(self.widgets_to_harvest.use_temp);
+
         // Batteries
         if let Some(battery_manager) = &self.battery_manager {
             if let Some(battery_list) = &mut self.battery_list {
@@ -222,6 +307,8 @@ impl DataCollector {
                             .as_secs(),
                         self.mem_total_kb,
                         self.page_file_size_kb,
+                        self.disable_process_io,
+                        self.process_fields,
                     )
                 }
                 #[cfg(not(target_os = "linux"))]
@@ -371,6 +458,9 @@ impl DataCollector {
         if let Ok(temp) = temp_res {
             self.data.temperature_sensors = temp;
         }
+        if let Some(temperature_sensors) = &mut self.data.temperature_sensors {
+            gpu::add_gpu_temperatures(temperature_sensors, &self.temperature_type);
+        }
 
         // Update time
         self.data.last_collection_time = current_instant;
@@ -1,10 +1,47 @@
+use serde::{Deserialize, Serialize};
+
 use battery::{
     units::{power::watt, ratio::percent, time::second, Time},
-    Battery, Manager,
+    Battery, Manager, State,
 };
 
-#[derive(Debug, Clone)]
+/// Whether a battery is charging, discharging, or neither.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BatteryState {
+    Charging,
+    Discharging,
+    Empty,
+    Full,
+    Unknown,
+}
+
+impl Default for BatteryState {
+    fn default() -> Self {
+        BatteryState::Unknown
+    }
+}
+
+impl std::fmt::Display for BatteryState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use BatteryState::*;
+        write!(
+            f,
+            "{}",
+            match &self {
+                Charging => "Charging",
+                Discharging => "Discharging",
+                Empty => "Empty",
+                Full => "Full",
+                Unknown => "Unknown",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BatteryHarvest {
+    pub name: String,
+    pub state: BatteryState,
     pub charge_percent: f64,
     pub secs_until_full: Option<i64>,
     pub secs_until_empty: Option<i64>,
@@ -20,12 +57,34 @@ fn convert_optional_time_to_optional_seconds(optional_time: Option<Time>) -> Opt
     }
 }
 
+/// Returns the kernel-style `BAT<n>` name of the battery, followed by its reported model if it
+/// has one.  Batteries of the same model are still told apart by their names.
+fn get_battery_name(battery: &Battery, index: usize) -> String {
+    match battery.model() {
+        Some(model) if !model.trim().is_empty() => format!("BAT{} ({})", index, model.trim()),
+        _ => format!("BAT{}", index),
+    }
+}
+
+fn get_battery_state(battery: &Battery) -> BatteryState {
+    match battery.state() {
+        State::Charging => BatteryState::Charging,
+        State::Discharging => BatteryState::Discharging,
+        State::Empty => BatteryState::Empty,
+        State::Full => BatteryState::Full,
+        _ => BatteryState::Unknown,
+    }
+}
+
 pub fn refresh_batteries(manager: &Manager, batteries: &mut [Battery]) -> Vec<BatteryHarvest> {
     batteries
         .iter_mut()
-        .filter_map(|battery| {
+        .enumerate()
+        .filter_map(|(itx, battery)| {
             if manager.refresh(battery).is_ok() {
                 Some(BatteryHarvest {
+                    name: get_battery_name(battery, itx),
+                    state: get_battery_state(battery),
                     secs_until_full: convert_optional_time_to_optional_seconds(
                         battery.time_to_full(),
                     ),
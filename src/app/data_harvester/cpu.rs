@@ -1,31 +1,249 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Instant;
 use sysinfo::{ProcessorExt, System, SystemExt};
 
-#[derive(Default, Debug, Clone)]
+/// The percentage of time a CPU spent in each category since the last harvest.
+#[derive(Default, Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct CpuBreakdown {
+    pub user: f64,
+    pub system: f64,
+    pub iowait: f64,
+    pub irq: f64,
+    pub softirq: f64,
+    pub steal: f64,
+}
+
+impl CpuBreakdown {
+    /// Returns the categories in the order they are stacked when drawn.
+    pub fn to_array(&self) -> [f64; 6] {
+        [
+            self.user,
+            self.system,
+            self.iowait,
+            self.irq,
+            self.softirq,
+            self.steal,
+        ]
+    }
+}
+
+/// The raw time counters of a single CPU line in `/proc/stat`.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct CpuTimes {
+    user: u64,
+    nice: u64,
+    system: u64,
+    idle: u64,
+    iowait: u64,
+    irq: u64,
+    softirq: u64,
+    steal: u64,
+}
+
+impl CpuTimes {
+    fn total(&self) -> u64 {
+        self.user
+            + self.nice
+            + self.system
+            + self.idle
+            + self.iowait
+            + self.irq
+            + self.softirq
+            + self.steal
+    }
+
+    fn get_breakdown(&self, prev: &CpuTimes) -> Option<CpuBreakdown> {
+        let total_delta = self.total().saturating_sub(prev.total());
+        if total_delta == 0 {
+            return None;
+        }
+
+        let percent = |curr: u64, prev: u64| -> f64 {
+            curr.saturating_sub(prev) as f64 / total_delta as f64 * 100.0
+        };
+
+        Some(CpuBreakdown {
+            user: percent(self.user + self.nice, prev.user + prev.nice),
+            system: percent(self.system, prev.system),
+            iowait: percent(self.iowait, prev.iowait),
+            irq: percent(self.irq, prev.irq),
+            softirq: percent(self.softirq, prev.softirq),
+            steal: percent(self.steal, prev.steal),
+        })
+    }
+}
+
+/// How often the scheduler switched tasks and interrupts were handled since the last harvest.
+#[derive(Default, Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SchedulerActivity {
+    pub context_switches_per_sec: f64,
+    pub interrupts_per_sec: f64,
+}
+
+/// The total context switches and interrupts since boot, from `/proc/stat`.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct SchedulerCounters {
+    context_switches: u64,
+    interrupts: u64,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct CpuData {
     pub cpu_name: String,
     pub cpu_usage: f64,
+    /// Only available on Linux.
+    pub breakdown: Option<CpuBreakdown>,
 }
 
 pub type CpuHarvest = Vec<CpuData>;
 
-pub fn get_cpu_data_list(sys: &System, show_average_cpu: bool) -> CpuHarvest {
+/// The raw time counters of each CPU line in `/proc/stat`, by the ID the line starts with, such
+/// as `cpu` for the aggregate line or `cpu3` for a single core.
+pub type CpuTimesMap = HashMap<String, CpuTimes>;
+
+/// The ID of the aggregate line in `/proc/stat`.
+const AGGREGATE_CPU_ID: &str = "cpu";
+
+pub fn get_cpu_data_list(
+    sys: &System, show_average_cpu: bool, show_breakdown: bool, prev_cpu_times: &mut CpuTimesMap,
+) -> CpuHarvest {
     let cpu_data = sys.get_processors();
     let avg_cpu_usage = sys.get_global_processor_info().get_cpu_usage();
     let mut cpu_vec = vec![];
 
+    // The breakdown needs `/proc/stat`, so it is only read while a breakdown is shown.  The old
+    // counters are dropped otherwise, so the first breakdown after showing it again isn't taken
+    // over the whole time it was hidden.
+    let breakdowns: HashMap<String, CpuBreakdown> = if show_breakdown {
+        let cpu_times = get_cpu_times();
+        let breakdowns = cpu_times
+            .iter()
+            .filter_map(|(id, times)| {
+                prev_cpu_times
+                    .get(id)
+                    .and_then(|prev_times| times.get_breakdown(prev_times))
+                    .map(|breakdown| (id.clone(), breakdown))
+            })
+            .collect();
+        *prev_cpu_times = cpu_times;
+        breakdowns
+    } else {
+        prev_cpu_times.clear();
+        HashMap::new()
+    };
+
     if show_average_cpu {
         cpu_vec.push(CpuData {
             cpu_name: "AVG".to_string(),
             cpu_usage: avg_cpu_usage as f64,
+            breakdown: breakdowns.get(AGGREGATE_CPU_ID).cloned(),
         });
     }
 
+    // On Linux, each processor is named after its line in `/proc/stat`, which isn't always in
+    // the same position, such as while a core is offline.
     for (itx, cpu) in cpu_data.iter().enumerate() {
         cpu_vec.push(CpuData {
             cpu_name: format!("CPU{}", itx),
             cpu_usage: f64::from(cpu.get_cpu_usage()),
+            breakdown: breakdowns.get(cpu.get_name()).cloned(),
         });
     }
 
     cpu_vec
 }
+
+/// Returns the rates since the previous counters, and replaces them with the current ones.
+/// Returns [`None`] on the first harvest, or if the counters are unavailable, such as on
+/// platforms other than Linux.
+pub fn get_scheduler_activity(
+    prev_counters: &mut Option<(Instant, SchedulerCounters)>, current_instant: Instant,
+) -> Option<SchedulerActivity> {
+    let counters = get_scheduler_counters();
+    let activity = match (&prev_counters, counters) {
+        (Some((prev_instant, prev)), Some(counters)) => {
+            let elapsed_secs = current_instant.duration_since(*prev_instant).as_secs_f64();
+            if elapsed_secs > 0.0 {
+                Some(SchedulerActivity {
+                    context_switches_per_sec: counters
+                        .context_switches
+                        .saturating_sub(prev.context_switches)
+                        as f64
+                        / elapsed_secs,
+                    interrupts_per_sec: counters.interrupts.saturating_sub(prev.interrupts) as f64
+                        / elapsed_secs,
+                })
+            } else {
+                None
+            }
+        }
+        _ => None,
+    };
+
+    *prev_counters = counters.map(|counters| (current_instant, counters));
+    activity
+}
+
+#[cfg(target_os = "linux")]
+fn get_scheduler_counters() -> Option<SchedulerCounters> {
+    let stat_results = std::fs::read_to_string("/proc/stat").ok()?;
+    let get_counter = |prefix: &str| -> Option<u64> {
+        // The `intr` line lists each interrupt after the total, so only the first value is used.
+        stat_results
+            .lines()
+            .find(|line| line.starts_with(prefix))
+            .and_then(|line| line.split_whitespace().nth(1))
+            .and_then(|value| value.parse::<u64>().ok())
+    };
+
+    Some(SchedulerCounters {
+        context_switches: get_counter("ctxt ")?,
+        interrupts: get_counter("intr ")?,
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn get_scheduler_counters() -> Option<SchedulerCounters> {
+    None
+}
+
+#[cfg(target_os = "linux")]
+fn get_cpu_times() -> CpuTimesMap {
+    if let Ok(stat_results) = std::fs::read_to_string("/proc/stat") {
+        stat_results
+            .lines()
+            .filter(|line| line.starts_with("cpu"))
+            .filter_map(|line| {
+                let val = line.split_whitespace().collect::<Vec<&str>>();
+                let id = val.first()?.to_string();
+                let get_val = |index: usize| -> u64 {
+                    val.get(index)
+                        .and_then(|value| value.parse::<u64>().ok())
+                        .unwrap_or(0)
+                };
+
+                Some((
+                    id,
+                    CpuTimes {
+                        user: get_val(1),
+                        nice: get_val(2),
+                        system: get_val(3),
+                        idle: get_val(4),
+                        iowait: get_val(5),
+                        irq: get_val(6),
+                        softirq: get_val(7),
+                        steal: get_val(8),
+                    },
+                ))
+            })
+            .collect()
+    } else {
+        HashMap::new()
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn get_cpu_times() -> CpuTimesMap {
+    HashMap::new()
+}
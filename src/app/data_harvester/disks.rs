@@ -1,13 +1,21 @@
-#[derive(Debug, Clone, Default)]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DiskHarvest {
     pub name: String,
     pub mount_point: String,
+    #[serde(default)]
+    pub file_system: String,
+    /// The key mount options, such as `ro,noatime`, or [`None`] if they couldn't be read.
+    #[serde(default)]
+    pub mount_options: Option<String>,
     pub free_space: u64,
     pub used_space: u64,
     pub total_space: u64,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct IOData {
     pub read_bytes: u64,
     pub write_bytes: u64,
@@ -15,6 +23,52 @@ pub struct IOData {
 
 pub type IOHarvest = std::collections::HashMap<String, Option<IOData>>;
 
+/// The mount options worth showing besides whether a mount is read-only, in the order shown.
+#[cfg(target_os = "linux")]
+const KEY_MOUNT_OPTIONS: [&str; 6] = ["noatime", "nodiratime", "nosuid", "nodev", "noexec", "sync"];
+
+/// Returns the key mount options of each mount point, read from `/proc/mounts`.  Every entry
+/// starts with `ro` or `rw`, followed by any of [`KEY_MOUNT_OPTIONS`] that are set.
+#[cfg(target_os = "linux")]
+fn get_mount_options() -> HashMap<String, String> {
+    let mounts = match std::fs::read_to_string("/proc/mounts") {
+        Ok(mounts) => mounts,
+        Err(_) => return HashMap::new(),
+    };
+
+    // Each line is the device, mount point, file system, and options, with whitespace in the
+    // mount point escaped as octal.  Later mounts over the same point are the visible ones, so
+    // they replace earlier entries.
+    mounts
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let mount_point = fields.nth(1)?;
+            let options = fields.nth(1)?.split(',').collect::<Vec<_>>();
+
+            let mut key_options = vec![if options.contains(&"ro") { "ro" } else { "rw" }];
+            key_options.extend(
+                KEY_MOUNT_OPTIONS
+                    .iter()
+                    .filter(|option| options.contains(*option))
+                    .cloned(),
+            );
+
+            let mount_point = mount_point
+                .replace("\\040", " ")
+                .replace("\\011", "\t")
+                .replace("\\012", "\n")
+                .replace("\\134", "\\");
+            Some((mount_point, key_options.join(",")))
+        })
+        .collect()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn get_mount_options() -> HashMap<String, String> {
+    HashMap::new()
+}
+
 /// Meant for ARM use.
 #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
 pub async fn get_sysinfo_io_usage_list(
@@ -39,12 +93,17 @@ pub async fn get_sysinfo_disk_usage_list(
         return Ok(None);
     }
 
+    let mount_options = get_mount_options();
     let mut vec_disks = sys
         .get_disks()
         .iter()
         .map(|disk| DiskHarvest {
             name: disk.get_name().to_string_lossy().into(),
             mount_point: disk.get_mount_point().to_string_lossy().into(),
+            file_system: String::from_utf8_lossy(disk.get_file_system()).into(),
+            mount_options: mount_options
+                .get(disk.get_mount_point().to_string_lossy().as_ref())
+                .cloned(),
             free_space: disk.get_available_space(),
             used_space: disk
                 .get_total_space()
@@ -112,6 +171,7 @@ pub async fn get_heim_disk_usage_list(
         return Ok(None);
     }
 
+    let mount_options = get_mount_options();
     let mut vec_disks: Vec<DiskHarvest> = Vec::new();
     let mut partitions_stream = heim::disk::partitions_physical();
 
@@ -119,16 +179,19 @@ pub async fn get_heim_disk_usage_list(
         if let Ok(part) = part {
             let partition = part;
             let usage = heim::disk::usage(partition.mount_point().to_path_buf()).await?;
+            let mount_point = partition
+                .mount_point()
+                .to_str()
+                .unwrap_or("Name Unavailable")
+                .to_string();
 
             vec_disks.push(DiskHarvest {
                 free_space: usage.free().get::<heim::units::information::byte>(),
                 used_space: usage.used().get::<heim::units::information::byte>(),
                 total_space: usage.total().get::<heim::units::information::byte>(),
-                mount_point: (partition
-                    .mount_point()
-                    .to_str()
-                    .unwrap_or("Name Unavailable"))
-                .to_string(),
+                mount_options: mount_options.get(&mount_point).cloned(),
+                mount_point,
+                file_system: partition.file_system().as_str().to_string(),
                 name: (partition
                     .device()
                     .unwrap_or_else(|| std::ffi::OsStr::new("Name Unavailable"))
@@ -0,0 +1,84 @@
+//! Fan speeds, which are read from the hwmon sensors that also report temperatures.  Only
+//! available on Linux.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct FanHarvest {
+    /// The name of the chip the fan is attached to, such as `thinkpad` or `nct6775`.
+    pub component_name: Option<String>,
+    pub fan_label: String,
+    pub rpm: u64,
+}
+
+/// Returns [`None`] if there are no fan sensors, such as on other platforms.
+pub fn get_fan_data(actually_get: bool) -> Option<Vec<FanHarvest>> {
+    if !actually_get {
+        return None;
+    }
+
+    let mut fan_vec = get_hwmon_fans();
+    if fan_vec.is_empty() {
+        None
+    } else {
+        fan_vec.sort_by(|a, b| {
+            a.component_name
+                .cmp(&b.component_name)
+                .then_with(|| a.fan_label.cmp(&b.fan_label))
+        });
+        Some(fan_vec)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn get_hwmon_fans() -> Vec<FanHarvest> {
+    use std::fs;
+
+    let read_trimmed = |path: &std::path::Path| -> Option<String> {
+        fs::read_to_string(path)
+            .ok()
+            .map(|contents| contents.trim().to_string())
+    };
+
+    let mut fan_vec = Vec::new();
+    let hwmon_dirs = match fs::read_dir("/sys/class/hwmon") {
+        Ok(hwmon_dirs) => hwmon_dirs,
+        Err(_) => return fan_vec,
+    };
+
+    for hwmon_dir in hwmon_dirs.filter_map(|entry| entry.ok()) {
+        let hwmon_path = hwmon_dir.path();
+        let component_name = read_trimmed(&hwmon_path.join("name"));
+
+        let entries = match fs::read_dir(&hwmon_path) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        // Each fan has a file like `fan1_input` holding its speed in RPM, and may also have
+        // a `fan1_label` holding a friendlier name.
+        for entry in entries.filter_map(|entry| entry.ok()) {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if !(file_name.starts_with("fan") && file_name.ends_with("_input")) {
+                continue;
+            }
+            let fan_id = &file_name["fan".len()..file_name.len() - "_input".len()];
+
+            if let Some(rpm) = read_trimmed(&entry.path()).and_then(|rpm| rpm.parse::<u64>().ok()) {
+                fan_vec.push(FanHarvest {
+                    component_name: component_name.clone(),
+                    fan_label: read_trimmed(&hwmon_path.join(format!("fan{}_label", fan_id)))
+                        .unwrap_or_else(|| format!("Fan {}", fan_id)),
+                    rpm,
+                });
+            }
+        }
+    }
+
+    fan_vec
+}
+
+#[cfg(not(target_os = "linux"))]
+fn get_hwmon_fans() -> Vec<FanHarvest> {
+    Vec::new()
+}
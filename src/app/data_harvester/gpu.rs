@@ -0,0 +1,86 @@
+//! GPU temperatures, which are read from the hwmon sensors of DRM cards so they can be listed in
+//! the temperature widget as "GPU 0", "GPU 1", and so on.  Only available on Linux, and only for
+//! drivers that expose a hwmon sensor (such as amdgpu, radeon, nouveau, and i915).
+
+use super::temperature::{TempHarvest, TemperatureType};
+
+/// Adds the temperature of each GPU to `temperature_vec`.  The same sensors are also found
+/// when reading all temperatures, listed under their driver's name, so those entries are removed
+/// to avoid showing each GPU twice.  Nothing is added if there are no GPU sensors.
+pub fn add_gpu_temperatures(temperature_vec: &mut Vec<TempHarvest>, temp_type: &TemperatureType) {
+    let gpu_sensors = get_drm_gpu_sensors();
+    if gpu_sensors.is_empty() {
+        return;
+    }
+
+    temperature_vec.retain(|temp_harvest| {
+        !gpu_sensors.iter().any(|(_, chip_name, _)| {
+            chip_name.is_some() && temp_harvest.component_name == *chip_name
+        })
+    });
+    temperature_vec.extend(
+        gpu_sensors
+            .into_iter()
+            .map(|(gpu_index, _, celsius)| TempHarvest {
+                component_name: Some(format!("GPU {}", gpu_index)),
+                component_label: None,
+                temperature: temp_type.convert_from_celsius(celsius),
+            }),
+    );
+}
+
+/// Returns the index, hwmon chip name, and temperature in Celsius of each GPU, sorted by index.
+#[cfg(target_os = "linux")]
+fn get_drm_gpu_sensors() -> Vec<(usize, Option<String>, f32)> {
+    use std::fs;
+
+    let read_trimmed = |path: &std::path::Path| -> Option<String> {
+        fs::read_to_string(path)
+            .ok()
+            .map(|contents| contents.trim().to_string())
+    };
+
+    let mut gpu_sensors = Vec::new();
+    let cards = match fs::read_dir("/sys/class/drm") {
+        Ok(cards) => cards,
+        Err(_) => return gpu_sensors,
+    };
+
+    for card in cards.filter_map(|entry| entry.ok()) {
+        // Connectors like `card0-DP-1` are listed alongside the cards themselves.
+        let file_name = card.file_name().to_string_lossy().to_string();
+        if !file_name.starts_with("card") {
+            continue;
+        }
+        let gpu_index = match file_name["card".len()..].parse::<usize>() {
+            Ok(gpu_index) => gpu_index,
+            Err(_) => continue,
+        };
+
+        let hwmon_dirs = match fs::read_dir(card.path().join("device/hwmon")) {
+            Ok(hwmon_dirs) => hwmon_dirs,
+            Err(_) => continue,
+        };
+        for hwmon_dir in hwmon_dirs.filter_map(|entry| entry.ok()) {
+            let hwmon_path = hwmon_dir.path();
+            if let Some(millidegrees) = read_trimmed(&hwmon_path.join("temp1_input"))
+                .and_then(|millidegrees| millidegrees.parse::<f32>().ok())
+            {
+                gpu_sensors.push((
+                    gpu_index,
+                    read_trimmed(&hwmon_path.join("name")),
+                    millidegrees / 1000.0,
+                ));
+                break;
+            }
+        }
+    }
+
+    gpu_sensors.sort_by_key(|(gpu_index, _, _)| *gpu_index);
+    gpu_sensors
+}
+
+#[cfg(not(target_os = "linux"))]
+fn get_drm_gpu_sensors() -> Vec<(usize, Option<String>, f32)> {
+    Vec::new()
+}
@@ -1,7 +1,21 @@
-#[derive(Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MemHarvest {
     pub mem_total_in_mb: u64,
     pub mem_used_in_mb: u64,
+    /// The following are only available for memory (not swap) on Linux.
+    #[serde(default)]
+    pub mem_available_in_mb: Option<u64>,
+    #[serde(default)]
+    pub mem_cached_in_mb: Option<u64>,
+    #[serde(default)]
+    pub mem_buffers_in_mb: Option<u64>,
+    /// The total pages swapped in and out since boot, only available for swap on Linux.
+    #[serde(default)]
+    pub swap_in_pages: Option<u64>,
+    #[serde(default)]
+    pub swap_out_pages: Option<u64>,
 }
 
 impl Default for MemHarvest {
@@ -9,10 +23,65 @@ impl Default for MemHarvest {
         MemHarvest {
             mem_total_in_mb: 0,
             mem_used_in_mb: 0,
+            mem_available_in_mb: None,
+            mem_cached_in_mb: None,
+            mem_buffers_in_mb: None,
+            swap_in_pages: None,
+            swap_out_pages: None,
         }
     }
 }
 
+/// Fills in the available, cached, and buffers values from `/proc/meminfo`.
+#[cfg(target_os = "linux")]
+fn get_linux_mem_breakdown(mem_harvest: &mut MemHarvest) {
+    if let Ok(meminfo) = std::fs::read_to_string("/proc/meminfo") {
+        // Values are listed in kB, like "Cached:          123456 kB".
+        let get_val = |field: &str| -> Option<u64> {
+            meminfo
+                .lines()
+                .find(|line| line.starts_with(field))
+                .and_then(|line| line.split_whitespace().nth(1))
+                .and_then(|value| value.parse::<u64>().ok())
+                .map(|value_in_kb| value_in_kb / 1024)
+        };
+
+        mem_harvest.mem_available_in_mb = get_val("MemAvailable:");
+        mem_harvest.mem_cached_in_mb = get_val("Cached:");
+        mem_harvest.mem_buffers_in_mb = get_val("Buffers:");
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn get_linux_mem_breakdown(_mem_harvest: &mut MemHarvest) {}
+
+/// Fills in the pages swapped in and out from `/proc/vmstat`.
+#[cfg(target_os = "linux")]
+fn get_linux_swap_activity(swap_harvest: &mut MemHarvest) {
+    if let Ok(vmstat) = std::fs::read_to_string("/proc/vmstat") {
+        // Values are listed as page counts, like "pswpin 1234".
+        let get_val = |field: &str| -> Option<u64> {
+            vmstat
+                .lines()
+                .find_map(|line| {
+                    let mut split = line.split_whitespace();
+                    if split.next() == Some(field) {
+                        split.next()
+                    } else {
+                        None
+                    }
+                })
+                .and_then(|value| value.parse::<u64>().ok())
+        };
+
+        swap_harvest.swap_in_pages = get_val("pswpin");
+        swap_harvest.swap_out_pages = get_val("pswpout");
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn get_linux_swap_activity(_swap_harvest: &mut MemHarvest) {}
+
 /// Meant for ARM use.
 #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
 pub async fn get_sysinfo_mem_data_list(
@@ -23,10 +92,14 @@ pub async fn get_sysinfo_mem_data_list(
         return Ok(None);
     }
 
-    Ok(Some(MemHarvest {
+    let mut mem_harvest = MemHarvest {
         mem_total_in_mb: sys.get_total_memory() / 1024,
         mem_used_in_mb: sys.get_used_memory() / 1024,
-    }))
+        ..MemHarvest::default()
+    };
+    get_linux_mem_breakdown(&mut mem_harvest);
+
+    Ok(Some(mem_harvest))
 }
 
 /// Meant for ARM use.
@@ -39,10 +112,14 @@ pub async fn get_sysinfo_swap_data_list(
         return Ok(None);
     }
 
-    Ok(Some(MemHarvest {
+    let mut swap_harvest = MemHarvest {
         mem_total_in_mb: sys.get_total_swap() / 1024,
         mem_used_in_mb: sys.get_used_swap() / 1024,
-    }))
+        ..MemHarvest::default()
+    };
+    get_linux_swap_activity(&mut swap_harvest);
+
+    Ok(Some(swap_harvest))
 }
 
 #[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
@@ -55,13 +132,17 @@ pub async fn get_heim_mem_data_list(
 
     let memory = heim::memory::memory().await?;
 
-    Ok(Some(MemHarvest {
+    let mut mem_harvest = MemHarvest {
         mem_total_in_mb: memory.total().get::<heim::units::information::megabyte>(),
         mem_used_in_mb: memory.total().get::<heim::units::information::megabyte>()
             - memory
                 .available()
                 .get::<heim::units::information::megabyte>(),
-    }))
+        ..MemHarvest::default()
+    };
+    get_linux_mem_breakdown(&mut mem_harvest);
+
+    Ok(Some(mem_harvest))
 }
 
 #[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
@@ -74,8 +155,12 @@ pub async fn get_heim_swap_data_list(
 
     let memory = heim::memory::swap().await?;
 
-    Ok(Some(MemHarvest {
+    let mut swap_harvest = MemHarvest {
         mem_total_in_mb: memory.total().get::<heim::units::information::megabyte>(),
         mem_used_in_mb: memory.used().get::<heim::units::information::megabyte>(),
-    }))
+        ..MemHarvest::default()
+    };
+    get_linux_swap_activity(&mut swap_harvest);
+
+    Ok(Some(swap_harvest))
 }
@@ -1,6 +1,8 @@
 use std::time::Instant;
 
-#[derive(Default, Clone, Debug)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct NetworkHarvest {
     pub rx: u64,
     pub tx: u64,
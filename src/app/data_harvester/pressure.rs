@@ -0,0 +1,78 @@
+//! Pressure stall information (PSI), which is only available on Linux 4.20 and newer with
+//! PSI enabled.
+
+use serde::{Deserialize, Serialize};
+
+/// The share of time, as a percentage, that some or all tasks were stalled on a resource,
+/// averaged over the last 10 and 60 seconds.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct PressureStats {
+    pub some_avg10: f64,
+    pub some_avg60: f64,
+    /// Not reported for the CPU on older kernels.
+    pub full_avg10: Option<f64>,
+    pub full_avg60: Option<f64>,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct PressureHarvest {
+    pub cpu: Option<PressureStats>,
+    pub memory: Option<PressureStats>,
+    pub io: Option<PressureStats>,
+}
+
+/// Returns [`None`] if PSI is not available, such as on other platforms or older kernels.
+pub fn get_pressure_data(actually_get: bool) -> Option<PressureHarvest> {
+    if !actually_get {
+        return None;
+    }
+
+    let pressure_harvest = PressureHarvest {
+        cpu: get_pressure_stats("cpu"),
+        memory: get_pressure_stats("memory"),
+        io: get_pressure_stats("io"),
+    };
+
+    if pressure_harvest.cpu.is_none()
+        && pressure_harvest.memory.is_none()
+        && pressure_harvest.io.is_none()
+    {
+        None
+    } else {
+        Some(pressure_harvest)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn get_pressure_stats(resource: &str) -> Option<PressureStats> {
+    // Each file looks like:
+    // some avg10=0.00 avg60=0.00 avg300=0.00 total=0
+    // full avg10=0.00 avg60=0.00 avg300=0.00 total=0
+    let contents = std::fs::read_to_string(format!("/proc/pressure/{}", resource)).ok()?;
+
+    let get_averages = |kind: &str| -> Option<(f64, f64)> {
+        let line = contents.lines().find(|line| line.starts_with(kind))?;
+        let get_avg = |key: &str| -> Option<f64> {
+            line.split_whitespace()
+                .find(|field| field.starts_with(key))
+                .and_then(|field| field[key.len()..].parse::<f64>().ok())
+        };
+
+        Some((get_avg("avg10=")?, get_avg("avg60=")?))
+    };
+
+    let (some_avg10, some_avg60) = get_averages("some")?;
+    let full = get_averages("full");
+
+    Some(PressureStats {
+        some_avg10,
+        some_avg60,
+        full_avg10: full.map(|(avg10, _)| avg10),
+        full_avg60: full.map(|(_, avg60)| avg60),
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn get_pressure_stats(_resource: &str) -> Option<PressureStats> {
+    None
+}
@@ -1,4 +1,6 @@
 use crate::Pid;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 use sysinfo::ProcessStatus;
 
@@ -6,7 +8,7 @@ use sysinfo::ProcessStatus;
 use crate::utils::error::{self, BottomError};
 
 #[cfg(target_os = "linux")]
-use std::collections::{hash_map::RandomState, HashMap};
+use std::collections::hash_map::RandomState;
 
 #[cfg(not(target_os = "linux"))]
 use sysinfo::{ProcessExt, ProcessorExt, System, SystemExt};
@@ -17,7 +19,9 @@ pub enum ProcessSorting {
     CpuPercent,
     Mem,
     MemPercent,
+    Swap,
     Pid,
+    ParentPid,
     ProcessName,
     Command,
     ReadPerSecond,
@@ -26,6 +30,9 @@ pub enum ProcessSorting {
     TotalWrite,
     State,
     Count,
+    Threads,
+    OomScore,
+    CpuAffinity,
 }
 
 impl std::fmt::Display for ProcessSorting {
@@ -38,6 +45,7 @@ impl std::fmt::Display for ProcessSorting {
                 CpuPercent => "CPU%",
                 MemPercent => "Mem%",
                 Mem => "Mem",
+                Swap => "Swap",
                 ReadPerSecond => "R/s",
                 WritePerSecond => "W/s",
                 TotalRead => "T.Read",
@@ -46,7 +54,11 @@ impl std::fmt::Display for ProcessSorting {
                 ProcessName => "Name",
                 Command => "Command",
                 Pid => "PID",
+                ParentPid => "PPID",
                 Count => "Count",
+                Threads => "Threads",
+                OomScore => "OOM",
+                CpuAffinity => "Affinity",
             }
         )
     }
@@ -58,13 +70,17 @@ impl Default for ProcessSorting {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ProcessHarvest {
     pub pid: Pid,
     pub parent_pid: Option<Pid>, // Remember, parent_pid 0 is root...
     pub cpu_usage_percent: f64,
     pub mem_usage_percent: f64,
     pub mem_usage_bytes: u64,
+    /// How much of this process has been swapped out.  Only available on Linux, and not for
+    /// kernel threads.
+    #[serde(default)]
+    pub swap_bytes: Option<u64>,
     // pub rss_kb: u64,
     // pub virt_kb: u64,
     pub name: String,
@@ -75,18 +91,89 @@ pub struct ProcessHarvest {
     pub total_write_bytes: u64,
     pub process_state: String,
     pub process_state_char: char,
+    /// Not available on all platforms.
+    #[serde(default)]
+    pub thread_count: Option<u64>,
+    /// How likely the kernel is to kill this process when out of memory.  Only available on Linux.
+    #[serde(default)]
+    pub oom_score: Option<u64>,
+    /// The CPUs this process may run on, in ascending order.  Only available on Linux.
+    #[serde(default)]
+    pub cpu_affinity: Option<Vec<usize>>,
+    /// The user ID that owns this process.  Not available on Windows.
+    #[serde(default)]
+    pub uid: Option<u32>,
+    /// When the process started, which tells a process apart from an earlier one that had the
+    /// same PID.  In clock ticks since boot on Linux, and seconds since the epoch elsewhere.
+    #[serde(default)]
+    pub start_time: Option<u64>,
+}
+
+/// Which of the costlier fields of each process to read when harvesting, so that nothing is
+/// read for columns that aren't shown.  Only used on Linux, as the other platforms get every
+/// field at once.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProcessFields {
+    /// Read from `VmSwap` in `/proc/<pid>/status`.
+    pub swap: bool,
+    /// Read from `/proc/<pid>/oom_score`.
+    pub oom_score: bool,
+    /// Read with `sched_getaffinity`.
+    pub cpu_affinity: bool,
+    /// Read from `Uid:` in `/proc/<pid>/status`, which is only needed to show only the processes
+    /// of the current user.
+    pub uid: bool,
+}
+
+impl ProcessFields {
+    /// Returns fields that read everything, for when the shown columns aren't known.
+    pub fn all() -> Self {
+        ProcessFields {
+            swap: true,
+            oom_score: true,
+            cpu_affinity: true,
+            uid: true,
+        }
+    }
+}
+
+/// Details about a single process that are too costly to gather for every process,
+/// so they are only collected on demand.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessDetails {
+    pub command: String,
+    pub cwd: String,
+    pub exe: String,
+    pub thread_count: Option<u64>,
+    pub user: Option<String>,
+    /// The adjustment added to the OOM score.  Only available on Linux.
+    pub oom_score_adj: Option<i64>,
+    /// The CPUs the process may run on, in ascending order.  Only available on Linux.
+    pub cpu_affinity: Option<Vec<usize>>,
+    /// The names and resident sizes in bytes of the largest memory regions, such as the heap,
+    /// the stack, or a mapped file, largest first.  Only available on Linux, and empty if the
+    /// regions can't be read, such as for processes owned by other users.
+    pub memory_regions: Vec<(String, u64)>,
 }
 
+/// How many of a process' largest memory regions are kept in its [`ProcessDetails`].
+pub const MEMORY_REGION_COUNT: usize = 5;
+
 #[derive(Debug, Default, Clone)]
 pub struct PrevProcDetails {
     pub total_read_bytes: u64,
     pub total_write_bytes: u64,
     pub cpu_time: f64,
+    /// When the process started, in clock ticks since boot, which tells a process apart from
+    /// an earlier one that had the same PID.
+    pub start_time: u64,
     pub proc_stat_path: PathBuf,
     // pub proc_statm_path: PathBuf,
     pub proc_exe_path: PathBuf,
     pub proc_io_path: PathBuf,
     pub proc_cmdline_path: PathBuf,
+    pub proc_oom_score_path: PathBuf,
+    pub proc_status_path: PathBuf,
     pub just_read: bool,
 }
 
@@ -98,6 +185,8 @@ impl PrevProcDetails {
             proc_stat_path: PathBuf::from(format!("/proc/{}/stat", pid)),
             // proc_statm_path: PathBuf::from(format!("/proc/{}/statm", pid)),
             proc_cmdline_path: PathBuf::from(format!("/proc/{}/cmdline", pid)),
+            proc_oom_score_path: PathBuf::from(format!("/proc/{}/oom_score", pid)),
+            proc_status_path: PathBuf::from(format!("/proc/{}/status", pid)),
             ..PrevProcDetails::default()
         }
     }
@@ -195,6 +284,86 @@ fn get_linux_process_vsize_rss(stat: &[&str]) -> (u64, u64) {
     )
 }
 
+#[cfg(target_os = "linux")]
+fn get_linux_process_start_time(stat: &[&str]) -> u64 {
+    // Represents starttime, in clock ticks since boot.  Like the thread count, this is offset
+    // by 3 from its usual index of 22.
+    stat.get(19)
+        .and_then(|start_time| start_time.parse::<u64>().ok())
+        .unwrap_or(0)
+}
+
+#[cfg(target_os = "linux")]
+fn get_linux_process_thread_count(stat: &[&str]) -> Option<u64> {
+    // The -3 offset is because of us cutting off name + pid, normally it's 20
+    stat.get(17)
+        .and_then(|thread_count| thread_count.parse::<u64>().ok())
+}
+
+/// Returns how many bytes of a process are swapped out, from the `VmSwap` line of its status.
+/// Kernel threads have no such line.
+#[cfg(target_os = "linux")]
+fn get_linux_process_swap_bytes(status: &str) -> Option<u64> {
+    // The line looks like `VmSwap:     1024 kB`.
+    status
+        .lines()
+        .find(|line| line.starts_with("VmSwap:"))
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|swap_kb| swap_kb.parse::<u64>().ok())
+        .map(|swap_kb| swap_kb * 1024)
+}
+
+/// Returns the user ID of the owner of a process, from the `Uid:` line of its status.
+#[cfg(target_os = "linux")]
+fn get_linux_process_uid(status: &str) -> Option<u32> {
+    // The line looks like `Uid:    1000    1000    1000    1000`, starting with the real ID.
+    status
+        .lines()
+        .find(|line| line.starts_with("Uid:"))
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|uid| uid.parse::<u32>().ok())
+}
+
+/// Returns the user ID of the user running bottom, or [`None`] on platforms without one.
+pub fn get_current_uid() -> Option<u32> {
+    #[cfg(unix)]
+    {
+        // getuid always succeeds.
+        Some(unsafe { libc::getuid() })
+    }
+    #[cfg(not(unix))]
+    {
+        None
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn get_linux_process_cpu_affinity(pid: Pid) -> Option<Vec<usize>> {
+    // Enough room for 1024 CPUs, the same as glibc's `cpu_set_t`.
+    let mut mask = [0u64; 16];
+    let result = unsafe {
+        libc::sched_getaffinity(
+            pid,
+            std::mem::size_of_val(&mask),
+            mask.as_mut_ptr() as *mut libc::cpu_set_t,
+        )
+    };
+    if result != 0 {
+        return None;
+    }
+
+    Some(
+        mask.iter()
+            .enumerate()
+            .flat_map(|(word_index, word)| {
+                (0..64)
+                    .filter(move |bit| word & (1 << bit) != 0)
+                    .map(move |bit| word_index * 64 + bit)
+            })
+            .collect(),
+    )
+}
+
 #[cfg(target_os = "linux")]
 fn read_path_contents(path: &PathBuf) -> std::io::Result<String> {
     Ok(std::fs::read_to_string(path)?)
@@ -245,7 +414,8 @@ fn get_linux_cpu_usage(
 fn read_proc<S: core::hash::BuildHasher>(
     pid: Pid, cpu_usage: f64, cpu_fraction: f64,
     pid_mapping: &mut HashMap<Pid, PrevProcDetails, S>, use_current_cpu_total: bool,
-    time_difference_in_secs: u64, mem_total_kb: u64, page_file_kb: u64,
+    time_difference_in_secs: u64, mem_total_kb: u64, page_file_kb: u64, disable_io: bool,
+    fields: ProcessFields,
 ) -> error::Result<ProcessHarvest> {
     let pid_stat = pid_mapping
         .entry(pid)
@@ -276,6 +446,17 @@ fn read_proc<S: core::hash::BuildHasher>(
         .ok_or(BottomError::MinorError)?
         .split_whitespace()
         .collect::<Vec<&str>>();
+
+    // If the PID was reused by a new process since the last harvest, the old process' CPU time
+    // and IO totals would be subtracted from the new one's, so start over as if it were new.
+    let start_time = get_linux_process_start_time(&stat);
+    if pid_stat.start_time != start_time {
+        *pid_stat = PrevProcDetails {
+            start_time,
+            ..PrevProcDetails::new(pid)
+        };
+    }
+
     let (process_state_char, process_state) = get_linux_process_state(&stat);
     let cpu_usage_percent = get_linux_cpu_usage(
         &stat,
@@ -285,14 +466,45 @@ fn read_proc<S: core::hash::BuildHasher>(
         use_current_cpu_total,
     )?;
     let parent_pid = stat[1].parse::<Pid>().ok();
+    let thread_count = get_linux_process_thread_count(&stat);
+    let oom_score = if fields.oom_score {
+        read_path_contents(&pid_stat.proc_oom_score_path)
+            .ok()
+            .and_then(|oom_score| oom_score.trim().parse::<u64>().ok())
+    } else {
+        None
+    };
+    let cpu_affinity = if fields.cpu_affinity {
+        get_linux_process_cpu_affinity(pid)
+    } else {
+        None
+    };
     let (_vsize, rss) = get_linux_process_vsize_rss(&stat);
     let mem_usage_kb = rss * page_file_kb;
     let mem_usage_percent = mem_usage_kb as f64 / mem_total_kb as f64 * 100.0;
     let mem_usage_bytes = mem_usage_kb * 1024;
+    // The swap usage and the owner are both in the status, so it's read at most once for both.
+    let status = if fields.swap || fields.uid {
+        read_path_contents(&pid_stat.proc_status_path).ok()
+    } else {
+        None
+    };
+    let swap_bytes = if fields.swap {
+        status.as_deref().and_then(get_linux_process_swap_bytes)
+    } else {
+        None
+    };
+    let uid = if fields.uid {
+        status.as_deref().and_then(get_linux_process_uid)
+    } else {
+        None
+    };
 
     // This can fail if permission is denied!
     let (total_read_bytes, total_write_bytes, read_bytes_per_sec, write_bytes_per_sec) =
-        if let Ok(io_results) = get_process_io(&pid_stat.proc_io_path) {
+        if disable_io {
+            (0, 0, 0, 0)
+        } else if let Ok(io_results) = get_process_io(&pid_stat.proc_io_path) {
             let io_stats = io_results.split_whitespace().collect::<Vec<&str>>();
 
             let (total_read_bytes, total_write_bytes) = get_linux_process_io_usage(&io_stats);
@@ -328,6 +540,7 @@ fn read_proc<S: core::hash::BuildHasher>(
         command,
         mem_usage_percent,
         mem_usage_bytes,
+        swap_bytes,
         cpu_usage_percent,
         total_read_bytes,
         total_write_bytes,
@@ -335,14 +548,21 @@ fn read_proc<S: core::hash::BuildHasher>(
         write_bytes_per_sec,
         process_state,
         process_state_char,
+        thread_count,
+        oom_score,
+        cpu_affinity,
+        uid,
+        start_time: Some(start_time),
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 #[cfg(target_os = "linux")]
 pub fn linux_get_processes_list(
     prev_idle: &mut f64, prev_non_idle: &mut f64,
     pid_mapping: &mut HashMap<Pid, PrevProcDetails, RandomState>, use_current_cpu_total: bool,
-    time_difference_in_secs: u64, mem_total_kb: u64, page_file_kb: u64,
+    time_difference_in_secs: u64, mem_total_kb: u64, page_file_kb: u64, disable_io: bool,
+    fields: ProcessFields,
 ) -> crate::utils::error::Result<Vec<ProcessHarvest>> {
     // TODO: [PROC THREADS] Add threads
 
@@ -362,6 +582,8 @@ pub fn linux_get_processes_list(
                             time_difference_in_secs,
                             mem_total_kb,
                             page_file_kb,
+                            disable_io,
+                            fields,
                         ) {
                             return Some(process_object);
                         }
@@ -378,6 +600,250 @@ pub fn linux_get_processes_list(
     }
 }
 
+#[cfg(target_os = "linux")]
+pub fn get_process_details(pid: Pid) -> ProcessDetails {
+    let read_link = |file: &str| -> String {
+        std::fs::read_link(format!("/proc/{}/{}", pid, file))
+            .map(|path| path.to_string_lossy().to_string())
+            .unwrap_or_default()
+    };
+
+    let command = read_path_contents(&PathBuf::from(format!("/proc/{}/cmdline", pid)))
+        .map(|cmd| cmd.replace('\0', " ").trim().to_string())
+        .unwrap_or_default();
+
+    let (thread_count, uid) =
+        if let Ok(status) = read_path_contents(&PathBuf::from(format!("/proc/{}/status", pid))) {
+            let get_field = |field: &str| -> Option<&str> {
+                status
+                    .lines()
+                    .find(|line| line.starts_with(field))
+                    .and_then(|line| line.split_whitespace().nth(1))
+            };
+
+            (
+                get_field("Threads:").and_then(|threads| threads.parse::<u64>().ok()),
+                get_field("Uid:").map(|uid| uid.to_string()),
+            )
+        } else {
+            (None, None)
+        };
+
+    // Map the uid back to a user name if possible, otherwise just show the uid.
+    let user = uid.map(|uid| {
+        uid.parse::<u32>()
+            .ok()
+            .and_then(get_user_name)
+            .unwrap_or(uid)
+    });
+
+    let oom_score_adj = read_path_contents(&PathBuf::from(format!("/proc/{}/oom_score_adj", pid)))
+        .ok()
+        .and_then(|oom_score_adj| oom_score_adj.trim().parse::<i64>().ok());
+
+    ProcessDetails {
+        command,
+        cwd: read_link("cwd"),
+        exe: read_link("exe"),
+        thread_count,
+        user,
+        oom_score_adj,
+        cpu_affinity: get_linux_process_cpu_affinity(pid),
+        memory_regions: get_memory_regions(pid),
+    }
+}
+
+#[cfg(target_os = "linux")]
+lazy_static::lazy_static! {
+    /// The names of the users that have been looked up, by user ID.  A lookup may have to ask a
+    /// directory service rather than read `/etc/passwd`, so each user is only looked up once.
+    static ref USER_NAMES: std::sync::Mutex<HashMap<u32, Option<String>>> =
+        std::sync::Mutex::new(HashMap::new());
+}
+
+/// Returns the name of the user with the given ID, or [`None`] if there is no such user.
+#[cfg(target_os = "linux")]
+fn get_user_name(uid: u32) -> Option<String> {
+    let mut user_names = USER_NAMES.lock().ok()?;
+    user_names
+        .entry(uid)
+        .or_insert_with(|| look_up_user_name(uid))
+        .clone()
+}
+
+#[cfg(target_os = "linux")]
+fn look_up_user_name(uid: u32) -> Option<String> {
+    let mut passwd: libc::passwd = unsafe { std::mem::zeroed() };
+    let mut result: *mut libc::passwd = std::ptr::null_mut();
+    let mut buffer: Vec<libc::c_char> = vec![0; 1024];
+    loop {
+        let error = unsafe {
+            libc::getpwuid_r(
+                uid,
+                &mut passwd,
+                buffer.as_mut_ptr(),
+                buffer.len(),
+                &mut result,
+            )
+        };
+        // The buffer holds the strings of the entry, so try again with more room if they didn't
+        // fit, up to a sane limit.
+        if error == libc::ERANGE && buffer.len() < 1 << 20 {
+            buffer.resize(buffer.len() * 2, 0);
+        } else {
+            break;
+        }
+    }
+
+    if result.is_null() {
+        None
+    } else {
+        // The name points into the buffer, which is still alive here.
+        let name = unsafe { std::ffi::CStr::from_ptr(passwd.pw_name) };
+        Some(name.to_string_lossy().into_owned())
+    }
+}
+
+/// Adds up the resident size of every mapping in `/proc/<pid>/smaps` by what it maps, so that
+/// all of the mappings of one file count as one region.  Anonymous mappings other than the heap
+/// and stack are counted together.
+#[cfg(target_os = "linux")]
+fn get_memory_regions(pid: Pid) -> Vec<(String, u64)> {
+    let smaps = match read_path_contents(&PathBuf::from(format!("/proc/{}/smaps", pid))) {
+        Ok(smaps) => smaps,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut region_sizes: HashMap<String, u64> = HashMap::new();
+    let mut current_region = String::new();
+    for line in smaps.lines() {
+        let mut fields = line.split_whitespace();
+        match fields.next() {
+            // Each mapping starts with a line like `7f3c...-7f3d... r-xp 00000000 08:01 1234
+            // /usr/lib/libc.so.6`, followed by lines like `Rss:  1024 kB`.
+            Some("Rss:") => {
+                if let Some(rss_kb) = fields.next().and_then(|rss| rss.parse::<u64>().ok()) {
+                    *region_sizes.entry(current_region.clone()).or_insert(0) += rss_kb * 1024;
+                }
+            }
+            Some(field) if !field.ends_with(':') => {
+                let path = fields.skip(4).collect::<Vec<_>>().join(" ");
+                current_region = if path.is_empty() {
+                    "[anonymous]".to_string()
+                } else {
+                    path
+                };
+            }
+            _ => {}
+        }
+    }
+
+    let mut memory_regions = region_sizes
+        .into_iter()
+        .filter(|(_region, size)| *size > 0)
+        .collect::<Vec<_>>();
+    memory_regions.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    memory_regions.truncate(MEMORY_REGION_COUNT);
+    memory_regions
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn get_process_details(pid: Pid) -> ProcessDetails {
+    let mut sys = System::new();
+    sys.refresh_process(pid);
+
+    if let Some(process) = sys.get_process(pid) {
+        ProcessDetails {
+            command: process.cmd().join(" "),
+            cwd: process.cwd().to_string_lossy().to_string(),
+            exe: process.exe().to_string_lossy().to_string(),
+            thread_count: None,
+            user: None,
+            oom_score_adj: None,
+            cpu_affinity: None,
+            memory_regions: Vec::new(),
+        }
+    } else {
+        ProcessDetails::default()
+    }
+}
+
+/// The environments of processes that have been searched by, so each process' environment is
+/// only read once rather than on every search.  A process is told apart from an earlier one
+/// with the same PID by its start time.
+pub struct EnvironmentCache {
+    environments: HashMap<Pid, (Option<u64>, Option<Vec<String>>)>,
+    #[cfg(not(target_os = "linux"))]
+    sys: System,
+}
+
+impl Default for EnvironmentCache {
+    fn default() -> Self {
+        EnvironmentCache {
+            environments: HashMap::new(),
+            #[cfg(not(target_os = "linux"))]
+            sys: System::new(),
+        }
+    }
+}
+
+impl EnvironmentCache {
+    /// Returns the environment variables of a process as `KEY=value` strings, or [`None`] if
+    /// they can't be read, such as for processes owned by other users.
+    pub fn get(&mut self, pid: Pid, start_time: Option<u64>) -> Option<&[String]> {
+        let is_stale = self
+            .environments
+            .get(&pid)
+            .map_or(true, |(cached_start_time, _)| {
+                *cached_start_time != start_time
+            });
+        if is_stale {
+            #[cfg(target_os = "linux")]
+            let environ = get_process_environment(pid);
+            #[cfg(not(target_os = "linux"))]
+            let environ = get_process_environment(&mut self.sys, pid);
+
+            self.environments.insert(pid, (start_time, environ));
+        }
+
+        self.environments
+            .get(&pid)
+            .and_then(|(_, environ)| environ.as_deref())
+    }
+
+    /// Forgets the environments of processes for which `is_running` is false.
+    pub fn retain_running(&mut self, is_running: impl Fn(Pid, Option<u64>) -> bool) {
+        self.environments
+            .retain(|pid, (start_time, _)| is_running(*pid, *start_time));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.environments.is_empty()
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn get_process_environment(pid: Pid) -> Option<Vec<String>> {
+    let environ = std::fs::read(format!("/proc/{}/environ", pid)).ok()?;
+
+    Some(
+        environ
+            .split(|byte| *byte == 0)
+            .filter(|variable| !variable.is_empty())
+            .map(|variable| String::from_utf8_lossy(variable).to_string())
+            .collect(),
+    )
+}
+
+#[cfg(not(target_os = "linux"))]
+fn get_process_environment(sys: &mut System, pid: Pid) -> Option<Vec<String>> {
+    sys.refresh_process(pid);
+
+    sys.get_process(pid)
+        .map(|process| process.environ().to_vec())
+        .filter(|environ| !environ.is_empty())
+}
+
 #[cfg(not(target_os = "linux"))]
 pub fn windows_macos_get_processes_list(
     sys: &System, use_current_cpu_total: bool, mem_total_kb: u64,
@@ -440,6 +906,7 @@ pub fn windows_macos_get_processes_list(
                 0.0
             },
             mem_usage_bytes: process_val.memory() * 1024,
+            swap_bytes: None,
             cpu_usage_percent: process_cpu_usage,
             read_bytes_per_sec: disk_usage.read_bytes,
             write_bytes_per_sec: disk_usage.written_bytes,
@@ -447,6 +914,14 @@ pub fn windows_macos_get_processes_list(
             total_write_bytes: disk_usage.total_written_bytes,
             process_state: process_val.status().to_string().to_string(),
             process_state_char: convert_process_status_to_char(process_val.status()),
+            thread_count: None,
+            oom_score: None,
+            cpu_affinity: None,
+            #[cfg(target_os = "macos")]
+            uid: Some(process_val.uid),
+            #[cfg(not(target_os = "macos"))]
+            uid: None,
+            start_time: Some(process_val.start_time()),
         });
     }
 
@@ -463,6 +938,7 @@ fn convert_process_status_to_char(status: ProcessStatus) -> char {
                 ProcessStatus::Run => 'R',
                 ProcessStatus::Sleep => 'S',
                 ProcessStatus::Idle => 'D',
+                ProcessStatus::Stop => 'T',
                 ProcessStatus::Zombie => 'Z',
                 _ => '?',
             }
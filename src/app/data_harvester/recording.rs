@@ -0,0 +1,92 @@
+//! Recording harvested data to a file, and reading it back so it can be replayed
+//! in place of live data collection.  Recordings store one JSON frame per line, which is
+//! also how frames are sent from a remote host.
+
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    time::Instant,
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::Data;
+use crate::utils::error::{self, BottomError};
+
+#[derive(Serialize, Deserialize)]
+pub struct RecordedFrame {
+    /// How many milliseconds after the start of the recording this frame was harvested.
+    pub elapsed_ms: u64,
+    pub data: Data,
+}
+
+pub struct Recorder {
+    writer: Box<dyn Write + Send>,
+    start_time: Instant,
+}
+
+impl Recorder {
+    pub fn new(path: &str) -> error::Result<Self> {
+        Ok(Recorder {
+            writer: Box::new(BufWriter::new(File::create(path)?)),
+            start_time: Instant::now(),
+        })
+    }
+
+    /// Creates a recorder that writes frames to stdout, such as for `--collect`.
+    pub fn stdout() -> Self {
+        Recorder {
+            writer: Box::new(io::stdout()),
+            start_time: Instant::now(),
+        }
+    }
+
+    /// Appends a frame to the recording.  Each frame is flushed so that a recording
+    /// is still usable if the program is stopped abruptly.
+    pub fn record(&mut self, data: &Data) -> error::Result<()> {
+        let frame = RecordedFrame {
+            elapsed_ms: Instant::now().duration_since(self.start_time).as_millis() as u64,
+            data: data.clone(),
+        };
+        let line = serde_json::to_string(&frame)
+            .map_err(|err| BottomError::ConversionError(err.to_string()))?;
+
+        writeln!(self.writer, "{}", line)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Reads all frames from a recording made with a [`Recorder`].
+pub fn read_recording(path: &str) -> error::Result<Vec<RecordedFrame>> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut frames = Vec::new();
+
+    for (line_number, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        frames.push(parse_frame(&line).map_err(|err| {
+            BottomError::ConversionError(format!(
+                "line {} of the recording is invalid: {}",
+                line_number + 1,
+                err
+            ))
+        })?);
+    }
+
+    if frames.is_empty() {
+        return Err(BottomError::ConversionError(
+            "the recording has no frames.".to_string(),
+        ));
+    }
+
+    Ok(frames)
+}
+
+/// Parses a single line written by a [`Recorder`].
+pub fn parse_frame(line: &str) -> serde_json::Result<RecordedFrame> {
+    serde_json::from_str(line)
+}
@@ -0,0 +1,72 @@
+//! Details about the system that rarely change, such as the kernel version and the VM tunables
+//! under `/proc/sys/vm`.  Everything but the core count and total memory is only available on
+//! Linux.
+
+use serde::{Deserialize, Serialize};
+use sysinfo::{System, SystemExt};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct SystemInfoHarvest {
+    pub hostname: Option<String>,
+    pub kernel_version: Option<String>,
+    pub cpu_model: Option<String>,
+    pub core_count: usize,
+    pub total_memory_bytes: u64,
+    pub swappiness: Option<u64>,
+    /// The heuristic used when overcommitting memory: 0 is heuristic, 1 is always, and 2 is never.
+    pub overcommit_memory: Option<u64>,
+    /// The percentage of memory that can be dirty before writers have to write it out.
+    pub dirty_ratio: Option<u64>,
+}
+
+pub fn get_system_info(sys: &System, mem_total_kb: u64) -> SystemInfoHarvest {
+    SystemInfoHarvest {
+        hostname: read_proc_string("/proc/sys/kernel/hostname"),
+        kernel_version: read_proc_string("/proc/sys/kernel/osrelease"),
+        cpu_model: get_cpu_model(),
+        core_count: sys.get_processors().len(),
+        total_memory_bytes: mem_total_kb * 1024,
+        swappiness: read_proc_u64("/proc/sys/vm/swappiness"),
+        overcommit_memory: read_proc_u64("/proc/sys/vm/overcommit_memory"),
+        dirty_ratio: read_proc_u64("/proc/sys/vm/dirty_ratio"),
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn read_proc_string(path: &str) -> Option<String> {
+    std::fs::read_to_string(path)
+        .ok()
+        .map(|contents| contents.trim().to_string())
+        .filter(|contents| !contents.is_empty())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_proc_string(_path: &str) -> Option<String> {
+    None
+}
+
+fn read_proc_u64(path: &str) -> Option<u64> {
+    read_proc_string(path).and_then(|contents| contents.parse::<u64>().ok())
+}
+
+#[cfg(target_os = "linux")]
+fn get_cpu_model() -> Option<String> {
+    // x86 lists a `model name` for each processor, while ARM often only has a `Model` line for
+    // the whole board.
+    let cpuinfo = std::fs::read_to_string("/proc/cpuinfo").ok()?;
+    ["model name", "Model"].iter().find_map(|key| {
+        cpuinfo.lines().find_map(|line| {
+            let (line_key, value) = line.split_at(line.find(':')?);
+            if line_key.trim() == *key {
+                Some(value[1..].trim().to_string())
+            } else {
+                None
+            }
+        })
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn get_cpu_model() -> Option<String> {
+    None
+}
@@ -1,6 +1,8 @@
 use std::cmp::Ordering;
 
-#[derive(Default, Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct TempHarvest {
     pub component_name: Option<String>,
     pub component_label: Option<String>,
@@ -12,6 +14,30 @@ pub enum TemperatureType {
     Celsius,
     Kelvin,
     Fahrenheit,
+    /// The sensor's raw reading, in millidegrees Celsius.
+    Raw,
+}
+
+impl TemperatureType {
+    /// The symbol shown after temperatures of this type.
+    pub fn unit_symbol(&self) -> &'static str {
+        match self {
+            TemperatureType::Celsius => "C",
+            TemperatureType::Kelvin => "K",
+            TemperatureType::Fahrenheit => "F",
+            TemperatureType::Raw => "mC",
+        }
+    }
+
+    /// Converts a temperature in degrees Celsius to this type.
+    pub fn convert_from_celsius(&self, celsius: f32) -> f32 {
+        match self {
+            TemperatureType::Celsius => celsius,
+            TemperatureType::Kelvin => celsius + 273.15,
+            TemperatureType::Fahrenheit => (celsius * (9.0 / 5.0)) + 32.0,
+            TemperatureType::Raw => celsius * 1000.0,
+        }
+    }
 }
 
 impl Default for TemperatureType {
@@ -27,14 +53,6 @@ pub async fn get_sysinfo_temperature_data(
 ) -> crate::utils::error::Result<Option<Vec<TempHarvest>>> {
     use sysinfo::{ComponentExt, SystemExt};
 
-    fn convert_celsius_to_kelvin(celsius: f32) -> f32 {
-        celsius + 273.15
-    }
-
-    fn convert_celsius_to_fahrenheit(celsius: f32) -> f32 {
-        (celsius * (9.0 / 5.0)) + 32.0
-    }
-
     if !actually_get {
         return Ok(None);
     }
@@ -46,13 +64,7 @@ pub async fn get_sysinfo_temperature_data(
         temperature_vec.push(TempHarvest {
             component_name: None,
             component_label: Some(component.get_label().to_string()),
-            temperature: match temp_type {
-                TemperatureType::Celsius => component.get_temperature(),
-                TemperatureType::Kelvin => convert_celsius_to_kelvin(component.get_temperature()),
-                TemperatureType::Fahrenheit => {
-                    convert_celsius_to_fahrenheit(component.get_temperature())
-                }
-            },
+            temperature: temp_type.convert_from_celsius(component.get_temperature()),
         });
     }
 
@@ -94,6 +106,12 @@ pub async fn get_heim_temperature_data(
                         .current()
                         .get::<thermodynamic_temperature::degree_fahrenheit>(
                     ),
+                    TemperatureType::Raw => {
+                        sensor
+                            .current()
+                            .get::<thermodynamic_temperature::degree_celsius>()
+                            * 1000.0
+                    }
                 },
             });
         }
@@ -104,8 +122,8 @@ pub async fn get_heim_temperature_data(
 }
 
 fn temp_vec_sort(temperature_vec: &mut Vec<TempHarvest>) {
-    // By default, sort temperature, then by alphabetically!
-    // TODO: [TEMPS] Allow users to control this.
+    // By default, sort temperature, then by alphabetically!  The temperature table is sorted
+    // again when drawn, based on each widget's sort.
 
     // Note we sort in reverse here; we want greater temps to be higher priority.
     temperature_vec.sort_by(|a, b| match a.temperature.partial_cmp(&b.temperature) {
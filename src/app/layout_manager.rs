@@ -875,6 +875,48 @@ pub struct BottomWidget {
     /// Bottom right corner when drawn, for mouse click detection
     #[builder(default = None)]
     pub bottom_right_corner: Option<(u16, u16)>,
+
+    /// Whether a process widget starts grouped, if set in the layout.
+    #[builder(default = None)]
+    pub is_grouped: Option<bool>,
+
+    /// How a memory widget draws its usage, if set in the layout.
+    #[builder(default = MemStyle::Graph)]
+    pub mem_style: MemStyle,
+
+    /// Whether a graph widget's graph fills from the top, if set in the layout.
+    #[builder(default = false)]
+    pub is_y_inverted: bool,
+}
+
+/// How a memory widget draws RAM and swap usage.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum MemStyle {
+    /// A graph of usage over time.
+    Graph,
+    /// A bar showing only the current usage.
+    Gauge,
+}
+
+impl Default for MemStyle {
+    fn default() -> Self {
+        MemStyle::Graph
+    }
+}
+
+impl std::str::FromStr for MemStyle {
+    type Err = BottomError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "graph" => Ok(MemStyle::Graph),
+            "gauge" => Ok(MemStyle::Gauge),
+            _ => Err(BottomError::ConfigError(format!(
+                "\"{}\" is an invalid memory style, use \"<graph|gauge>\".",
+                s
+            ))),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
@@ -894,6 +936,8 @@ pub enum BottomWidgetType {
     BasicNet,
     BasicTables,
     Battery,
+    Pressure,
+    SystemInfo,
 }
 
 impl BottomWidgetType {
@@ -917,6 +961,8 @@ impl BottomWidgetType {
             Temp => "Temperature",
             Disk => "Disks",
             Battery => "Battery",
+            Pressure => "Pressure",
+            SystemInfo => "System Info",
             _ => "",
         }
     }
@@ -942,6 +988,8 @@ impl std::str::FromStr for BottomWidgetType {
             "disk" => Ok(BottomWidgetType::Disk),
             "empty" => Ok(BottomWidgetType::Empty),
             "battery" | "batt" => Ok(BottomWidgetType::Battery),
+            "pressure" | "psi" => Ok(BottomWidgetType::Pressure),
+            "sysinfo" | "system_info" => Ok(BottomWidgetType::SystemInfo),
             _ => Err(BottomError::ConfigError(format!(
                 "\"{}\" is an invalid widget name.
 
@@ -960,6 +1008,10 @@ Supported widget names:
 |           disk           |
 +--------------------------+
 |       batt, battery      |
++--------------------------+
+|       pressure, psi      |
++--------------------------+
+|   sysinfo, system_info   |
 +--------------------------+
                 ",
                 s
@@ -977,4 +1029,6 @@ pub struct UsedWidgets {
     pub use_disk: bool,
     pub use_temp: bool,
     pub use_battery: bool,
+    pub use_pressure: bool,
+    pub use_system_info: bool,
 }
@@ -31,34 +31,55 @@ impl Process {
     }
 }
 
+/// Sends a signal to a process, given a PID.
+#[cfg(target_family = "unix")]
+fn send_signal(pid: Pid, signal: libc::c_int) -> crate::utils::error::Result<()> {
+    let output = unsafe { libc::kill(pid as i32, signal) };
+    if output != 0 {
+        // We had an error...
+        let err_code = std::io::Error::last_os_error().raw_os_error();
+        let err = match err_code {
+            Some(libc::ESRCH) => "the target process did not exist.",
+            Some(libc::EPERM) => "the calling process does not have the permissions to signal the target process(es).",
+            Some(libc::EINVAL) => "an invalid signal was specified.",
+            _ => "Unknown error occurred."
+        };
+
+        return if let Some(err_code) = err_code {
+            Err(BottomError::GenericError(format!(
+                "Error code {} - {}",
+                err_code, err,
+            )))
+        } else {
+            Err(BottomError::GenericError(format!(
+                "Error code ??? - {}",
+                err,
+            )))
+        };
+    }
+
+    Ok(())
+}
+
+/// Resumes a stopped process, given a PID.
+#[cfg(target_family = "unix")]
+pub fn resume_process_given_pid(pid: Pid) -> crate::utils::error::Result<()> {
+    send_signal(pid, libc::SIGCONT)
+}
+
+#[cfg(not(target_family = "unix"))]
+pub fn resume_process_given_pid(_pid: Pid) -> crate::utils::error::Result<()> {
+    Err(BottomError::GenericError(
+        "Sorry, resuming processes is only supported on Unix-like systems.".to_string(),
+    ))
+}
+
 /// Kills a process, given a PID.
 pub fn kill_process_given_pid(pid: Pid) -> crate::utils::error::Result<()> {
     if cfg!(target_family = "unix") {
         #[cfg(any(target_family = "unix"))]
         {
-            let output = unsafe { libc::kill(pid as i32, libc::SIGTERM) };
-            if output != 0 {
-                // We had an error...
-                let err_code = std::io::Error::last_os_error().raw_os_error();
-                let err = match err_code {
-                Some(libc::ESRCH) => "the target process did not exist.",
-                Some(libc::EPERM) => "the calling process does not have the permissions to terminate the target process(es).",
-                Some(libc::EINVAL) => "an invalid signal was specified.",
-                _ => "Unknown error occurred."
-            };
-
-                return if let Some(err_code) = err_code {
-                    Err(BottomError::GenericError(format!(
-                        "Error code {} - {}",
-                        err_code, err,
-                    )))
-                } else {
-                    Err(BottomError::GenericError(format!(
-                        "Error code ??? - {}",
-                        err,
-                    )))
-                };
-            }
+            send_signal(pid, libc::SIGTERM)?;
         }
     } else if cfg!(target_family = "windows") {
         #[cfg(target_family = "windows")]
@@ -1,4 +1,4 @@
-use super::ProcWidgetState;
+use super::{data_harvester::processes::EnvironmentCache, ProcWidgetState};
 use crate::{
     data_conversion::ConvertedProcessData,
     utils::error::{
@@ -13,6 +13,7 @@ const DELIMITER_LIST: [char; 6] = ['=', '>', '<', '(', ')', '\"'];
 const COMPARISON_LIST: [&str; 3] = [">", "=", "<"];
 const OR_LIST: [&str; 2] = ["or", "||"];
 const AND_LIST: [&str; 2] = ["and", "&&"];
+const ENV_PREFIX: &str = "env:";
 
 /// I only separated this as otherwise, the states.rs file gets huge... and this should
 /// belong in another file anyways, IMO.
@@ -31,6 +32,7 @@ pub trait ProcessQuery {
     /// - Write/s: Use prefix `w`.  Can compare.
     /// - Total read: Use prefix `read`.  Can compare.
     /// - Total write: Use prefix `write`.  Can compare.
+    /// - Environment variables: Use prefix `env:`, as in `env:KEY=value`.  Can use r/m/c.
     ///
     /// For queries, whitespaces are our delimiters.  We will merge together any adjacent non-prefixed
     /// or quoted elements after splitting to treat as process names.
@@ -242,15 +244,53 @@ impl ProcessQuery for ProcWidgetState {
                     }
                 } else {
                     //  Get prefix type...
-                    let prefix_type = queue_top.parse::<PrefixType>()?;
-                    let content = if let PrefixType::Name = prefix_type {
-                        Some(queue_top)
+                    let (prefix_type, content) = if queue_top.to_lowercase().starts_with(ENV_PREFIX)
+                    {
+                        // The env prefix shares a token with the start of its content.
+                        let content = queue_top[ENV_PREFIX.len()..].to_string();
+                        if content.is_empty() {
+                            (PrefixType::Env, query.pop_front())
+                        } else {
+                            (PrefixType::Env, Some(content))
+                        }
                     } else {
-                        query.pop_front()
+                        let prefix_type = queue_top.parse::<PrefixType>()?;
+                        if let PrefixType::Name = prefix_type {
+                            (prefix_type, Some(queue_top))
+                        } else {
+                            (prefix_type, query.pop_front())
+                        }
                     };
 
                     if let Some(content) = content {
                         match &prefix_type {
+                            PrefixType::Env => {
+                                // Since "=" is a delimiter, stitch the variable back together.
+                                let mut env_query = content;
+                                while query.front().map_or(false, |queue_next| queue_next == "=") {
+                                    query.pop_front();
+                                    env_query.push('=');
+
+                                    if let Some(queue_next) = query.front() {
+                                        if !DELIMITER_LIST
+                                            .iter()
+                                            .any(|delimiter| queue_next.starts_with(*delimiter))
+                                        {
+                                            env_query.push_str(queue_next);
+                                            query.pop_front();
+                                        }
+                                    }
+                                }
+
+                                return Ok(Prefix {
+                                    or: None,
+                                    regex_prefix: Some((
+                                        prefix_type,
+                                        StringQuery::Value(env_query),
+                                    )),
+                                    compare_prefix: None,
+                                });
+                            }
                             PrefixType::Name => {
                                 return Ok(Prefix {
                                     or: None,
@@ -459,10 +499,13 @@ impl Query {
         Ok(())
     }
 
-    pub fn check(&self, process: &ConvertedProcessData, is_using_command: bool) -> bool {
+    pub fn check(
+        &self, process: &ConvertedProcessData, is_using_command: bool,
+        mut environments: Option<&mut EnvironmentCache>,
+    ) -> bool {
         self.query
             .iter()
-            .all(|ok| ok.check(process, is_using_command))
+            .all(|ok| ok.check(process, is_using_command, environments.as_deref_mut()))
     }
 }
 
@@ -499,11 +542,17 @@ impl Or {
         Ok(())
     }
 
-    pub fn check(&self, process: &ConvertedProcessData, is_using_command: bool) -> bool {
+    pub fn check(
+        &self, process: &ConvertedProcessData, is_using_command: bool,
+        mut environments: Option<&mut EnvironmentCache>,
+    ) -> bool {
         if let Some(rhs) = &self.rhs {
-            self.lhs.check(process, is_using_command) || rhs.check(process, is_using_command)
+            self.lhs
+                .check(process, is_using_command, environments.as_deref_mut())
+                || rhs.check(process, is_using_command, environments.as_deref_mut())
         } else {
-            self.lhs.check(process, is_using_command)
+            self.lhs
+                .check(process, is_using_command, environments.as_deref_mut())
         }
     }
 }
@@ -544,11 +593,25 @@ impl And {
         Ok(())
     }
 
-    pub fn check(&self, process: &ConvertedProcessData, is_using_command: bool) -> bool {
+    pub fn check(
+        &self, process: &ConvertedProcessData, is_using_command: bool,
+        mut environments: Option<&mut EnvironmentCache>,
+    ) -> bool {
         if let Some(rhs) = &self.rhs {
-            self.lhs.check(process, is_using_command) && rhs.check(process, is_using_command)
+            // Reading a process' environment is costly, so only do so if the other side matches.
+            if self.lhs.is_env_prefix() {
+                rhs.check(process, is_using_command, environments.as_deref_mut())
+                    && self
+                        .lhs
+                        .check(process, is_using_command, environments.as_deref_mut())
+            } else {
+                self.lhs
+                    .check(process, is_using_command, environments.as_deref_mut())
+                    && rhs.check(process, is_using_command, environments.as_deref_mut())
+            }
         } else {
-            self.lhs.check(process, is_using_command)
+            self.lhs
+                .check(process, is_using_command, environments.as_deref_mut())
         }
     }
 }
@@ -574,6 +637,7 @@ pub enum PrefixType {
     TWrite,
     Name,
     State,
+    Env,
     __Nonexhaustive,
 }
 
@@ -622,7 +686,7 @@ impl Prefix {
         } else if let Some((prefix_type, query_content)) = &mut self.regex_prefix {
             if let StringQuery::Value(regex_string) = query_content {
                 match prefix_type {
-                    PrefixType::Pid | PrefixType::Name | PrefixType::State => {
+                    PrefixType::Pid | PrefixType::Name | PrefixType::State | PrefixType::Env => {
                         let escaped_regex: String;
                         let final_regex_string = &format!(
                             "{}{}{}{}",
@@ -653,7 +717,10 @@ impl Prefix {
         Ok(())
     }
 
-    pub fn check(&self, process: &ConvertedProcessData, is_using_command: bool) -> bool {
+    pub fn check(
+        &self, process: &ConvertedProcessData, is_using_command: bool,
+        mut environments: Option<&mut EnvironmentCache>,
+    ) -> bool {
         fn matches_condition(condition: &QueryComparison, lhs: f64, rhs: f64) -> bool {
             match condition {
                 QueryComparison::Equal => (lhs - rhs).abs() < std::f64::EPSILON,
@@ -665,7 +732,7 @@ impl Prefix {
         }
 
         if let Some(and) = &self.or {
-            and.check(process, is_using_command)
+            and.check(process, is_using_command, environments.as_deref_mut())
         } else if let Some((prefix_type, query_content)) = &self.regex_prefix {
             if let StringQuery::Regex(r) = query_content {
                 match prefix_type {
@@ -676,6 +743,13 @@ impl Prefix {
                     }),
                     PrefixType::Pid => r.is_match(process.pid.to_string().as_str()),
                     PrefixType::State => r.is_match(process.process_state.as_str()),
+                    // Processes whose environment can't be read never match, nor does
+                    // anything if the processes aren't local.
+                    PrefixType::Env => environments
+                        .and_then(|environments| environments.get(process.pid, process.start_time))
+                        .map_or(false, |environ| {
+                            environ.iter().any(|variable| r.is_match(variable))
+                        }),
                     _ => true,
                 }
             } else {
@@ -727,6 +801,12 @@ impl Prefix {
     }
 }
 
+impl Prefix {
+    fn is_env_prefix(&self) -> bool {
+        matches!(self.regex_prefix, Some((PrefixType::Env, _)))
+    }
+}
+
 impl Debug for Prefix {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let Some(or) = &self.or {
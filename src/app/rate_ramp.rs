@@ -0,0 +1,81 @@
+//! Slows down harvesting while the CPU stays busy, so that bottom adds less load to a system
+//! that is already struggling, then goes back to the configured rate once the load drops.
+
+use super::data_harvester::cpu::CpuData;
+
+pub struct RateRamp {
+    /// The configured update rate, which is returned to once the load drops.
+    base_rate_in_milliseconds: u64,
+    max_rate_in_milliseconds: u64,
+    cpu_threshold_percent: f64,
+    /// How many updates in a row the CPU has to be above or below the threshold before the rate
+    /// changes.
+    tick_count: u64,
+    current_rate_in_milliseconds: u64,
+    /// How many updates in a row have been on the same side of the threshold.
+    streak: u64,
+    was_above_threshold: bool,
+}
+
+impl RateRamp {
+    pub fn new(
+        base_rate_in_milliseconds: u64, max_rate_in_milliseconds: u64, cpu_threshold_percent: f64,
+        tick_count: u64,
+    ) -> Self {
+        RateRamp {
+            base_rate_in_milliseconds,
+            max_rate_in_milliseconds,
+            cpu_threshold_percent,
+            tick_count,
+            current_rate_in_milliseconds: base_rate_in_milliseconds,
+            streak: 0,
+            was_above_threshold: false,
+        }
+    }
+
+    pub fn get_current_rate(&self) -> u64 {
+        self.current_rate_in_milliseconds
+    }
+
+    /// Checks the latest CPU usage, and returns the new update rate if it should change.  The
+    /// rate doubles each time the CPU stays above the threshold for long enough, up to the
+    /// maximum, and goes straight back to the configured rate once it stays below it.
+    pub fn update(&mut self, cpu_harvest: &[CpuData]) -> Option<u64> {
+        // The average entry is skipped, as it is only there if it is shown.
+        let cores = cpu_harvest
+            .iter()
+            .filter(|cpu| cpu.cpu_name != "AVG")
+            .collect::<Vec<_>>();
+        if cores.is_empty() {
+            return None;
+        }
+        let cpu_usage_percent =
+            cores.iter().map(|cpu| cpu.cpu_usage).sum::<f64>() / cores.len() as f64;
+
+        let is_above_threshold = cpu_usage_percent > self.cpu_threshold_percent;
+        if is_above_threshold == self.was_above_threshold {
+            self.streak += 1;
+        } else {
+            self.was_above_threshold = is_above_threshold;
+            self.streak = 1;
+        }
+
+        if self.streak < self.tick_count {
+            return None;
+        }
+        self.streak = 0;
+
+        let new_rate = if is_above_threshold {
+            (self.current_rate_in_milliseconds * 2).min(self.max_rate_in_milliseconds)
+        } else {
+            self.base_rate_in_milliseconds
+        };
+
+        if new_rate == self.current_rate_in_milliseconds {
+            None
+        } else {
+            self.current_rate_in_milliseconds = new_rate;
+            Some(new_rate)
+        }
+    }
+}
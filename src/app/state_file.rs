@@ -0,0 +1,50 @@
+//! State that bottom keeps between runs, such as the watched processes.  Unlike the config
+//! file, this is written by bottom itself, so it lives in the data directory.
+
+use std::{fs, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    constants::DEFAULT_STATE_FILE_PATH,
+    utils::error::{self, BottomError},
+};
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct StateFile {
+    /// Names of processes that are highlighted wherever they appear in the process widgets.
+    #[serde(default)]
+    pub watched: Vec<String>,
+}
+
+impl StateFile {
+    /// Reads the state file.  A missing or unreadable state file is treated as empty, since
+    /// losing the state should never prevent bottom from starting.
+    pub fn read() -> Self {
+        get_state_file_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| toml::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn write(&self) -> error::Result<()> {
+        let path = get_state_file_path().ok_or_else(|| {
+            BottomError::GenericError("could not find a data directory".to_string())
+        })?;
+        let contents =
+            toml::to_string(self).map_err(|err| BottomError::ConversionError(err.to_string()))?;
+
+        if let Some(parent_path) = path.parent() {
+            fs::create_dir_all(parent_path)?;
+        }
+        fs::write(path, contents)?;
+        Ok(())
+    }
+}
+
+fn get_state_file_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|mut path| {
+        path.push(DEFAULT_STATE_FILE_PATH);
+        path
+    })
+}
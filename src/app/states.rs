@@ -1,6 +1,10 @@
-use std::{collections::HashMap, time::Instant};
+use std::{
+    collections::{HashMap, HashSet},
+    time::Instant,
+};
 
 use unicode_segmentation::GraphemeCursor;
+use unicode_width::UnicodeWidthStr;
 
 use tui::widgets::TableState;
 
@@ -46,10 +50,21 @@ pub struct AppDeleteDialogState {
     pub is_on_yes: bool, // Defaults to "No"
 }
 
+/// A short message that is shown to the user for a brief moment.
+#[derive(Default)]
+pub struct AppMessageDialogState {
+    pub message: Option<String>,
+    pub shown_at: Option<Instant>,
+}
+
 pub struct AppHelpDialogState {
     pub is_showing_help: bool,
     pub scroll_state: ParagraphScrollState,
     pub index_shortcuts: Vec<u16>,
+    /// Whether keys are currently being typed into the search query.
+    pub is_searching: bool,
+    /// Only bindings containing this (ignoring case) are shown, if it is not empty.
+    pub search_query: String,
 }
 
 impl Default for AppHelpDialogState {
@@ -58,6 +73,8 @@ impl Default for AppHelpDialogState {
             is_showing_help: false,
             scroll_state: ParagraphScrollState::default(),
             index_shortcuts: vec![0; constants::HELP_TEXT.len()],
+            is_searching: false,
+            search_query: String::default(),
         }
     }
 }
@@ -115,6 +132,8 @@ pub struct ProcessSearchState {
     pub is_ignoring_case: bool,
     pub is_searching_whole_word: bool,
     pub is_searching_with_regex: bool,
+    /// Whether to show only the processes that don't match the search.
+    pub is_inverted: bool,
 }
 
 impl Default for ProcessSearchState {
@@ -124,6 +143,7 @@ impl Default for ProcessSearchState {
             is_ignoring_case: true,
             is_searching_whole_word: false,
             is_searching_with_regex: false,
+            is_inverted: false,
         }
     }
 }
@@ -140,6 +160,10 @@ impl ProcessSearchState {
     pub fn search_toggle_regex(&mut self) {
         self.is_searching_with_regex = !self.is_searching_with_regex;
     }
+
+    pub fn search_toggle_invert(&mut self) {
+        self.is_inverted = !self.is_inverted;
+    }
 }
 
 pub struct ColumnInfo {
@@ -150,6 +174,8 @@ pub struct ColumnInfo {
 pub struct ProcColumn {
     pub ordered_columns: Vec<ProcessSorting>,
     pub column_mapping: HashMap<ProcessSorting, ColumnInfo>,
+    /// Headers to show instead of the usual names of columns.  Sorting is unaffected.
+    pub custom_headers: HashMap<ProcessSorting, String>,
     pub longest_header_len: u16,
     pub column_state: TableState,
     pub scroll_direction: ScrollDirection,
@@ -163,16 +189,21 @@ impl Default for ProcColumn {
         let ordered_columns = vec![
             Count,
             Pid,
+            ParentPid,
             ProcessName,
             Command,
             CpuPercent,
             Mem,
             MemPercent,
+            Swap,
             ReadPerSecond,
             WritePerSecond,
             TotalRead,
             TotalWrite,
             State,
+            Threads,
+            OomScore,
+            CpuAffinity,
         ];
 
         let mut column_mapping = HashMap::new();
@@ -234,7 +265,8 @@ impl Default for ProcColumn {
                         },
                     );
                 }
-                Count => {
+                // These are optional, and a few are only available on Linux.
+                Count | ParentPid | Swap | Threads | OomScore | CpuAffinity => {
                     column_mapping.insert(
                         column,
                         ColumnInfo {
@@ -259,6 +291,7 @@ impl Default for ProcColumn {
         ProcColumn {
             ordered_columns,
             column_mapping,
+            custom_headers: HashMap::new(),
             longest_header_len,
             column_state: TableState::default(),
             scroll_direction: ScrollDirection::default(),
@@ -301,6 +334,45 @@ impl ProcColumn {
             .sum()
     }
 
+    /// Sets the headers to show instead of the usual names of columns.
+    pub fn set_custom_headers(&mut self, custom_headers: HashMap<ProcessSorting, String>) {
+        self.custom_headers = custom_headers;
+        self.longest_header_len = self
+            .ordered_columns
+            .iter()
+            .map(|column| self.get_header(column).len())
+            .max()
+            .unwrap_or(0) as u16;
+    }
+
+    /// Returns the header shown for a column, which is its name unless a custom header is set.
+    pub fn get_header(&self, column: &ProcessSorting) -> String {
+        match self.custom_headers.get(column) {
+            Some(custom_header) => custom_header.clone(),
+            None => column.to_string(),
+        }
+    }
+
+    /// Returns the column that a mnemonic in the sort widget sorts by, if that column is
+    /// currently shown.  Mnemonics with two columns pick whichever one is shown.
+    pub fn get_mnemonic_column(&self, mnemonic: char) -> Option<ProcessSorting> {
+        let candidates: &[ProcessSorting] = match mnemonic {
+            'C' => &[CpuPercent],
+            'M' => &[MemPercent, Mem],
+            'P' => &[Pid],
+            'N' => &[ProcessName, Command],
+            'R' => &[ReadPerSecond],
+            'T' => &[Threads],
+            'O' => &[OomScore],
+            _ => &[],
+        };
+
+        candidates
+            .iter()
+            .find(|column| self.is_enabled(column))
+            .cloned()
+    }
+
     /// ALWAYS call this when opening the sorted window.
     pub fn set_to_sorted_index(&mut self, proc_sorting_type: &ProcessSorting) {
         // TODO [Custom Columns]: If we add custom columns, this may be needed!  Since column indices will change, this runs the risk of OOB.  So, when you change columns, CALL THIS AND ADAPT!
@@ -318,6 +390,15 @@ impl ProcColumn {
         self.backup_prev_scroll_position = self.previous_scroll_position;
     }
 
+    /// Returns the headers of the shown columns, without any shortcuts or sort indicators.
+    pub fn get_plain_column_headers(&self) -> Vec<String> {
+        self.ordered_columns
+            .iter()
+            .filter(|column_type| self.is_enabled(column_type))
+            .map(|column_type| self.get_header(column_type))
+            .collect()
+    }
+
     pub fn get_column_headers(
         &self, proc_sorting_type: &ProcessSorting, sort_reverse: bool,
     ) -> Vec<String> {
@@ -333,11 +414,11 @@ impl ProcColumn {
 
                 if mapping.enabled {
                     Some(if proc_sorting_type == column_type {
-                        column_type.to_string()
+                        self.get_header(column_type)
                             + command_str.as_str()
                             + if sort_reverse { "▼" } else { "▲" }
                     } else {
-                        column_type.to_string() + command_str.as_str()
+                        self.get_header(column_type) + command_str.as_str()
                     })
                 } else {
                     None
@@ -347,6 +428,59 @@ impl ProcColumn {
     }
 }
 
+/// Finds processes that appeared since the previous update, so that bursts of new processes
+/// stand out rather than blending into the table.
+#[derive(Default)]
+pub struct SpawnTracker {
+    /// When the last checked update was harvested, so each update is only checked once.
+    last_update_instant: Option<Instant>,
+    last_seen_pids: HashSet<crate::Pid>,
+    /// Recently spawned processes, and how many more updates each is highlighted for.
+    highlighted_pids: HashMap<crate::Pid, u8>,
+    /// How many processes spawned in the latest update.
+    pub spawned_count: usize,
+}
+
+impl SpawnTracker {
+    pub fn update(&mut self, update_instant: Instant, pids: HashSet<crate::Pid>) {
+        if self.last_update_instant == Some(update_instant) {
+            return;
+        }
+
+        self.highlighted_pids
+            .values_mut()
+            .for_each(|remaining_updates| *remaining_updates -= 1);
+        self.highlighted_pids
+            .retain(|_, remaining_updates| *remaining_updates > 0);
+
+        // Every process would look new on the first update, so nothing is highlighted then.
+        if self.last_update_instant.is_some() {
+            let spawned_pids = pids.difference(&self.last_seen_pids).collect::<Vec<_>>();
+            self.spawned_count = spawned_pids.len();
+            for pid in spawned_pids {
+                self.highlighted_pids
+                    .insert(*pid, constants::SPAWNED_PROCESS_HIGHLIGHT_UPDATES);
+            }
+        }
+
+        self.last_update_instant = Some(update_instant);
+        self.last_seen_pids = pids;
+    }
+
+    pub fn is_highlighted(&self, pid: crate::Pid) -> bool {
+        self.highlighted_pids.contains_key(&pid)
+    }
+}
+
+/// What is shown beneath the row of an expanded process.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExpandedProcessView {
+    /// Its working directory, executable, owner, and other details.
+    Details,
+    /// Its whole command, wrapped over as many lines as it needs.
+    Command,
+}
+
 pub struct ProcWidgetState {
     pub process_search_state: ProcessSearchState,
     pub is_grouped: bool,
@@ -358,11 +492,30 @@ pub struct ProcWidgetState {
     pub is_sort_open: bool,
     pub columns: ProcColumn,
     pub is_tree_mode: bool,
+    /// Whether only processes owned by the user running bottom are shown.
+    pub is_showing_only_own: bool,
+    /// Whether the PPID column is shown while not grouped, as parents mean nothing for groups.
+    pub is_showing_ppid: bool,
+    /// The process whose details are shown beneath its row, if any.
+    pub expanded_pid: Option<crate::Pid>,
+    pub expanded_process_details: Option<processes::ProcessDetails>,
+    pub expanded_view: ExpandedProcessView,
+    /// The process to select once the list is next re-sorted, so that changing the sort keeps
+    /// the same process selected rather than whichever one moves into its row.
+    pub pid_to_reselect: Option<crate::Pid>,
+    /// The fingerprint of the last finalized process data.
+    pub process_data_fingerprint: u64,
+    /// Whether the cached rows need to be rebuilt before drawing.
+    pub is_process_data_dirty: bool,
+    /// The formatted cells of every process, reused between draws while the data is unchanged.
+    pub cached_process_rows: Vec<Vec<String>>,
+    pub spawn_tracker: SpawnTracker,
 }
 
 impl ProcWidgetState {
     pub fn init(
         is_case_sensitive: bool, is_match_whole_word: bool, is_use_regex: bool, is_grouped: bool,
+        is_showing_only_own: bool, is_showing_ppid: bool, shown_columns: &[ProcessSorting],
     ) -> Self {
         let mut process_search_state = ProcessSearchState::default();
         if is_case_sensitive {
@@ -384,6 +537,13 @@ impl ProcWidgetState {
         if is_grouped {
             columns.toggle(&ProcessSorting::Count);
             columns.toggle(&ProcessSorting::Pid);
+        } else if is_showing_ppid {
+            columns.toggle(&ProcessSorting::ParentPid);
+        }
+        for column in shown_columns {
+            if let Some(mapping) = columns.column_mapping.get_mut(column) {
+                mapping.enabled = true;
+            }
         }
 
         ProcWidgetState {
@@ -397,9 +557,24 @@ impl ProcWidgetState {
             is_sort_open: false,
             columns,
             is_tree_mode: false,
+            is_showing_only_own,
+            is_showing_ppid,
+            expanded_pid: None,
+            expanded_process_details: None,
+            expanded_view: ExpandedProcessView::Details,
+            pid_to_reselect: None,
+            process_data_fingerprint: 0,
+            is_process_data_dirty: true,
+            cached_process_rows: Vec::new(),
+            spawn_tracker: SpawnTracker::default(),
         }
     }
 
+    pub fn collapse_process_details(&mut self) {
+        self.expanded_pid = None;
+        self.expanded_process_details = None;
+    }
+
     /// Updates sorting when using the column list.
     /// ...this really should be part of the ProcColumn struct (along with the sorting fields),
     /// but I'm too lazy.
@@ -430,6 +605,7 @@ impl ProcWidgetState {
                 match self.process_sorting_type {
                     ProcessSorting::State
                     | ProcessSorting::Pid
+                    | ProcessSorting::ParentPid
                     | ProcessSorting::ProcessName
                     | ProcessSorting::Command => {
                         // Also invert anything that uses alphabetical sorting by default.
@@ -441,6 +617,18 @@ impl ProcWidgetState {
         }
     }
 
+    /// Sorts by the column matching a mnemonic, as if it were selected in the sort widget.
+    /// Returns whether there was such a column.
+    pub fn select_sort_mnemonic(&mut self, mnemonic: char) -> bool {
+        if let Some(column) = self.columns.get_mnemonic_column(mnemonic) {
+            self.columns.set_to_sorted_index(&column);
+            self.update_sorting_with_columns();
+            true
+        } else {
+            false
+        }
+    }
+
     pub fn toggle_command_and_name(&mut self, is_using_command: bool) {
         self.columns
             .column_mapping
@@ -506,6 +694,16 @@ impl ProcWidgetState {
         self.process_search_state.search_state.reset();
     }
 
+    /// Replaces the search query, with the cursor placed at its end.
+    pub fn set_search_query(&mut self, query: String) {
+        let search_state = &mut self.process_search_state.search_state;
+        search_state.grapheme_cursor = GraphemeCursor::new(query.len(), query.len(), true);
+        search_state.char_cursor_position = UnicodeWidthStr::width(query.as_str());
+        search_state.cursor_direction = CursorDirection::Right;
+        search_state.current_search_query = query;
+        self.update_query();
+    }
+
     pub fn search_walk_forward(&mut self, start_position: usize) {
         self.process_search_state
             .search_state
@@ -533,6 +731,12 @@ pub struct ProcState {
     pub widget_states: HashMap<u64, ProcWidgetState>,
     pub force_update: Option<u64>,
     pub force_update_all: bool,
+    /// Process names that are always shown at the top of every process widget.
+    pub pinned_names: Vec<String>,
+    /// Whether to show pinned processes that are not currently running.
+    pub show_pinned_not_running: bool,
+    /// Process names that are highlighted wherever they appear in the process widgets.
+    pub watched_names: Vec<String>,
 }
 
 impl ProcState {
@@ -541,6 +745,32 @@ impl ProcState {
             widget_states,
             force_update: None,
             force_update_all: false,
+            pinned_names: Vec::new(),
+            show_pinned_not_running: false,
+            watched_names: Vec::new(),
+        }
+    }
+
+    /// Pins the given process name if it isn't already pinned, otherwise unpins it.
+    pub fn toggle_pinned(&mut self, name: &str) {
+        if let Some(index) = self.pinned_names.iter().position(|pinned| pinned == name) {
+            self.pinned_names.remove(index);
+        } else {
+            self.pinned_names.push(name.to_string());
+        }
+        self.force_update_all = true;
+    }
+
+    /// Watches the given process name if it isn't already watched, otherwise unwatches it.
+    pub fn toggle_watched(&mut self, name: &str) {
+        if let Some(index) = self
+            .watched_names
+            .iter()
+            .position(|watched| watched == name)
+        {
+            self.watched_names.remove(index);
+        } else {
+            self.watched_names.push(name.to_string());
         }
     }
 
@@ -556,6 +786,12 @@ impl ProcState {
 pub struct NetWidgetState {
     pub current_display_time: u64,
     pub autohide_timer: Option<Instant>,
+    pub pan_offset: u64,
+    /// Whether the RX and TX series are left out of the graph.
+    pub is_rx_hidden: bool,
+    pub is_tx_hidden: bool,
+    /// Whether the graph fills from the top.
+    pub is_y_inverted: bool,
 }
 
 impl NetWidgetState {
@@ -563,6 +799,10 @@ impl NetWidgetState {
         NetWidgetState {
             current_display_time,
             autohide_timer,
+            pan_offset: 0,
+            is_rx_hidden: false,
+            is_tx_hidden: false,
+            is_y_inverted: false,
         }
     }
 }
@@ -570,6 +810,10 @@ impl NetWidgetState {
 pub struct NetState {
     pub force_update: Option<u64>,
     pub widget_states: HashMap<u64, NetWidgetState>,
+    /// Whether the totals are since bottom started rather than since boot.
+    pub is_showing_session_total: bool,
+    /// Whether RX and TX are graphed separately, each with their own y-axis.
+    pub is_showing_split_axes: bool,
 }
 
 impl NetState {
@@ -577,6 +821,8 @@ impl NetState {
         NetState {
             force_update: None,
             widget_states,
+            is_showing_session_total: false,
+            is_showing_split_axes: false,
         }
     }
 
@@ -595,6 +841,13 @@ pub struct CpuWidgetState {
     pub autohide_timer: Option<Instant>,
     pub scroll_state: AppScrollWidgetState,
     pub is_multi_graph_mode: bool,
+    pub pan_offset: u64,
+    pub is_showing_breakdown: bool,
+    pub is_showing_heatmap: bool,
+    /// The positions of the legend entries that are not drawn when all entries are shown.
+    pub hidden_entries: HashSet<usize>,
+    /// Whether the graph fills from the top.
+    pub is_y_inverted: bool,
 }
 
 impl CpuWidgetState {
@@ -605,6 +858,11 @@ impl CpuWidgetState {
             autohide_timer,
             scroll_state: AppScrollWidgetState::default(),
             is_multi_graph_mode: false,
+            pan_offset: 0,
+            is_showing_breakdown: false,
+            is_showing_heatmap: false,
+            hidden_entries: HashSet::new(),
+            is_y_inverted: false,
         }
     }
 }
@@ -631,9 +889,35 @@ impl CpuState {
     }
 }
 
+/// What the memory chart's legend shows for RAM and swap usage.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MemLegendDisplay {
+    Both,
+    Percentage,
+    Value,
+}
+
+impl MemLegendDisplay {
+    /// Returns the display to switch to next, cycling through all of them.
+    pub fn next(self) -> Self {
+        match self {
+            MemLegendDisplay::Both => MemLegendDisplay::Percentage,
+            MemLegendDisplay::Percentage => MemLegendDisplay::Value,
+            MemLegendDisplay::Value => MemLegendDisplay::Both,
+        }
+    }
+}
+
 pub struct MemWidgetState {
     pub current_display_time: u64,
     pub autohide_timer: Option<Instant>,
+    pub pan_offset: u64,
+    pub is_showing_breakdown: bool,
+    pub legend_display: MemLegendDisplay,
+    /// Whether RAM and swap are drawn as gauges of their current usage instead of a graph.
+    pub is_showing_gauge: bool,
+    /// Whether the graph fills from the top.
+    pub is_y_inverted: bool,
 }
 
 impl MemWidgetState {
@@ -641,12 +925,19 @@ impl MemWidgetState {
         MemWidgetState {
             current_display_time,
             autohide_timer,
+            pan_offset: 0,
+            is_showing_breakdown: false,
+            legend_display: MemLegendDisplay::Both,
+            is_showing_gauge: false,
+            is_y_inverted: false,
         }
     }
 }
 pub struct MemState {
     pub force_update: Option<u64>,
     pub widget_states: HashMap<u64, MemWidgetState>,
+    /// Whether memory usage is currently at or above the configured warning threshold.
+    pub is_over_warn_threshold: bool,
 }
 
 impl MemState {
@@ -654,6 +945,7 @@ impl MemState {
         MemState {
             force_update: None,
             widget_states,
+            is_over_warn_threshold: false,
         }
     }
 
@@ -666,14 +958,36 @@ impl MemState {
     }
 }
 
+/// The column the temperature table is sorted by.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TempSorting {
+    Temperature,
+    SensorName,
+}
+
 pub struct TempWidgetState {
     pub scroll_state: AppScrollWidgetState,
+    pub sorting_type: TempSorting,
+    pub is_sort_descending: bool,
 }
 
 impl TempWidgetState {
     pub fn init() -> Self {
         TempWidgetState {
             scroll_state: AppScrollWidgetState::default(),
+            sorting_type: TempSorting::Temperature,
+            is_sort_descending: true,
+        }
+    }
+
+    /// Sorts by the given column, or reverses the order if already sorted by it.  Temperatures
+    /// start off hottest-first, and names start off alphabetical.
+    pub fn sort_by(&mut self, sorting_type: TempSorting) {
+        if self.sorting_type == sorting_type {
+            self.is_sort_descending = !self.is_sort_descending;
+        } else {
+            self.sorting_type = sorting_type;
+            self.is_sort_descending = sorting_type == TempSorting::Temperature;
         }
     }
 }
@@ -696,25 +1010,46 @@ impl TempState {
     }
 }
 
+/// The optional columns of the disk table, which are shown after the mount point.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DiskColumns {
+    pub file_system: bool,
+    pub mount_options: bool,
+}
+
 pub struct DiskWidgetState {
     pub scroll_state: AppScrollWidgetState,
+    /// Whether disks and mount points are shown by only the last part of their path, such as
+    /// `sda1` instead of `/dev/sda1`.
+    pub is_showing_basenames: bool,
 }
 
 impl DiskWidgetState {
     pub fn init() -> Self {
         DiskWidgetState {
             scroll_state: AppScrollWidgetState::default(),
+            is_showing_basenames: false,
         }
     }
 }
 
 pub struct DiskState {
     pub widget_states: HashMap<u64, DiskWidgetState>,
+    pub force_update: Option<u64>,
+    /// Whether to show disks that are hidden by their file system or mount point.
+    pub is_showing_hidden: bool,
+    /// Whether disks are grouped by file system, rather than only sorted by name.
+    pub is_sorted_by_file_system: bool,
 }
 
 impl DiskState {
     pub fn init(widget_states: HashMap<u64, DiskWidgetState>) -> Self {
-        DiskState { widget_states }
+        DiskState {
+            widget_states,
+            force_update: None,
+            is_showing_hidden: false,
+            is_sorted_by_file_system: false,
+        }
     }
 
     pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut DiskWidgetState> {
@@ -14,7 +14,7 @@ use std::{
         mpsc, Arc,
     },
     thread,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use anyhow::{Context, Result};
@@ -34,8 +34,11 @@ fn main() -> Result<()> {
 
     let config_path = read_config(matches.value_of("CONFIG_LOCATION"))
         .context("Unable to access the given config file location.")?;
-    let config: Config = create_or_get_config(&config_path)
+    let mut config: Config = create_or_get_config(&config_path)
         .context("Unable to properly parse or create the config file.")?;
+    if matches.is_present("LITE") {
+        apply_lite_defaults(&mut config);
+    }
 
     // Get widget layout separately
     let (widget_layout, default_widget_id, default_widget_type_option) =
@@ -51,6 +54,39 @@ fn main() -> Result<()> {
         &default_widget_type_option,
     )?;
 
+    // Set up recording or replaying of harvested data, if requested
+    let recorder = match matches.value_of("RECORD") {
+        Some(record_path) => Some(
+            app::data_harvester::recording::Recorder::new(record_path)
+                .context("Unable to create the given recording file.")?,
+        ),
+        None => None,
+    };
+    let replay_frames = match matches.value_of("REPLAY") {
+        Some(replay_path) => Some(
+            app::data_harvester::recording::read_recording(replay_path)
+                .context("Unable to read the given recording file.")?,
+        ),
+        None => None,
+    };
+    app.is_replaying = replay_frames.is_some();
+    let remote_host = matches.value_of("REMOTE").map(|host| host.to_string());
+    app.remote_host = remote_host.clone();
+    if app.is_replaying || app.remote_host.is_some() {
+        // The update rate of replayed or remote data can't be changed from here.
+        app.rate_ramp = None;
+    }
+
+    // Print data frames for a local bottom monitoring this host remotely, if requested
+    if matches.is_present("COLLECT") {
+        return collect_frames(app);
+    }
+
+    // Print JSON snapshots rather than drawing the UI, if requested
+    if matches.is_present("STREAM_JSON") {
+        return stream_json(app, recorder, replay_frames, remote_host);
+    }
+
     // Create painter and set colours.
     let mut painter = canvas::Painter::init(
         widget_layout,
@@ -59,11 +95,15 @@ fn main() -> Result<()> {
     );
     generate_config_colours(&config, &mut painter)?;
     painter.colours.generate_remaining_cpu_colours();
+    painter
+        .colours
+        .set_colour_depth(app.app_config_fields.colour_depth.clone());
     painter.complete_painter_init();
 
     // Set up input handling
     let (sender, receiver) = mpsc::channel();
-    create_input_thread(sender.clone());
+    let is_input_paused = Arc::new(AtomicBool::new(false));
+    create_input_thread(sender.clone(), is_input_paused.clone());
 
     // Cleaning loop
     {
@@ -79,12 +119,17 @@ fn main() -> Result<()> {
     }
 
     // Event loop
-    let (reset_sender, reset_receiver) = mpsc::channel();
+    let (thread_control_sender, thread_control_receiver) = mpsc::channel();
+    app.harvested_process_fields = app.get_process_fields();
     create_event_thread(
         sender,
-        reset_receiver,
+        thread_control_receiver,
         &app.app_config_fields,
         app.used_widgets.clone(),
+        recorder,
+        replay_frames,
+        remote_host,
+        app.harvested_process_fields,
     );
 
     // Set up up tui and crossterm
@@ -95,8 +140,15 @@ fn main() -> Result<()> {
     let mut terminal = Terminal::new(CrosstermBackend::new(stdout_val))?;
     terminal.hide_cursor()?;
 
-    // Set panic hook
-    panic::set_hook(Box::new(|info| panic_hook(info)));
+    // Set panic hook, which also writes a crash report if enabled
+    let crash_report = if app.app_config_fields.crash_report {
+        utils::crash_report::CrashReport::new(&config_path)
+    } else {
+        None
+    };
+    panic::set_hook(Box::new(move |info| {
+        panic_hook(info, crash_report.as_ref())
+    }));
 
     // Set termination hook
     let is_terminated = Arc::new(AtomicBool::new(false));
@@ -107,21 +159,74 @@ fn main() -> Result<()> {
     })
     .unwrap();
 
+    // What was shown in the last drawn frame, if idle redraws are paused.
+    let mut last_drawn_state = None;
+    let mut is_idle = false;
+
+    let quit_instant = app
+        .app_config_fields
+        .run_duration_in_seconds
+        .map(|run_duration| Instant::now() + Duration::from_secs(run_duration));
+
     while !is_terminated.load(Ordering::SeqCst) {
-        if let Ok(recv) = receiver.recv_timeout(Duration::from_millis(TICK_RATE_IN_MILLISECONDS)) {
+        if let Some(quit_instant) = quit_instant {
+            if Instant::now() >= quit_instant {
+                break;
+            }
+        }
+
+        let tick_rate = if is_idle {
+            IDLE_TICK_RATE_IN_MILLISECONDS
+        } else {
+            TICK_RATE_IN_MILLISECONDS
+        };
+        let mut has_input = false;
+
+        if let Ok(recv) = receiver.recv_timeout(Duration::from_millis(tick_rate)) {
             match recv {
                 BottomEvent::KeyInput(event) => {
-                    if handle_key_event_or_break(event, &mut app, &reset_sender) {
+                    has_input = true;
+                    if handle_key_event_or_break(event, &mut app, &thread_control_sender) {
                         break;
                     }
+                    send_process_fields_if_changed(&mut app, &thread_control_sender);
+                    send_cpu_breakdown_if_changed(&mut app, &thread_control_sender);
+                    show_pager_text(&mut terminal, &mut app, &is_input_paused)?;
                     handle_force_redraws(&mut app);
                 }
                 BottomEvent::MouseInput(event) => {
+                    has_input = true;
                     handle_mouse_event(event, &mut app);
                     handle_force_redraws(&mut app);
                 }
                 BottomEvent::Update(data) => {
-                    app.data_collection.eat_data(&data);
+                    app.last_update_instant = Some(Instant::now());
+                    app.is_remote_disconnected = false;
+                    app.data_collection.eat_data(
+                        &data,
+                        app.app_config_fields.rate_smoothing_alpha,
+                        &app.app_config_fields.byte_format,
+                    );
+
+                    // Slow down or speed back up harvesting based on how busy the CPU is
+                    if let Some(rate_ramp) = &mut app.rate_ramp {
+                        if let Some(update_rate_in_milliseconds) =
+                            rate_ramp.update(&app.data_collection.cpu_harvest)
+                        {
+                            thread_control_sender
+                                .send(ThreadControlEvent::UpdateUpdateRate(
+                                    update_rate_in_milliseconds,
+                                ))
+                                .ok();
+                        }
+                    }
+
+                    // The count limit is enforced on every update, rather than waiting for the
+                    // next clean, so the number of data points never goes over it.
+                    if let Some(max_data_points) = app.app_config_fields.max_data_points {
+                        app.data_collection
+                            .clean_data(constants::STALE_MAX_MILLISECONDS, Some(max_data_points));
+                    }
 
                     if !app.is_frozen {
                         // Convert all data into tui-compliant components
@@ -133,6 +238,9 @@ fn main() -> Result<()> {
                                 false,
                                 app.app_config_fields.use_basic_mode
                                     || app.app_config_fields.use_old_network_legend,
+                                app.app_config_fields.rate_precision,
+                                app.net_state.is_showing_session_total,
+                                &app.app_config_fields.byte_format,
                             );
                             app.canvas_data.network_data_rx = network_data.rx;
                             app.canvas_data.network_data_tx = network_data.tx;
@@ -148,8 +256,16 @@ fn main() -> Result<()> {
 
                         // Disk
                         if app.used_widgets.use_disk {
-                            app.canvas_data.disk_data =
-                                convert_disk_row(&app.data_collection, &app.filters.disk_filter);
+                            let (disk_data, disk_used_percent) = convert_disk_row(
+                                &app.data_collection,
+                                &app.filters.disk_filter,
+                                app.get_hidden_disks(),
+                                app.app_config_fields.disk_columns,
+                                app.disk_state.is_sorted_by_file_system,
+                                &app.app_config_fields.byte_format,
+                            );
+                            app.canvas_data.disk_data = disk_data;
+                            app.canvas_data.disk_used_percent = disk_used_percent;
                         }
 
                         // Temperatures
@@ -157,28 +273,74 @@ fn main() -> Result<()> {
                             app.canvas_data.temp_sensor_data = convert_temp_row(&app);
                         }
 
+                        // Pressure
+                        if app.used_widgets.use_pressure {
+                            app.canvas_data.pressure_data =
+                                convert_pressure_row(&app.data_collection);
+                        }
+
+                        // System info
+                        if app.used_widgets.use_system_info {
+                            app.canvas_data.system_info_data = convert_system_info_row(
+                                &app.data_collection,
+                                &app.app_config_fields.byte_format,
+                            );
+                        }
+
                         // Memory
                         if app.used_widgets.use_mem {
-                            app.canvas_data.mem_data =
+                            let (mem_data, mem_breakdown_data) =
                                 convert_mem_data_points(&app.data_collection, false);
+                            app.canvas_data.mem_data = mem_data;
+                            app.canvas_data.mem_breakdown_data = mem_breakdown_data;
                             app.canvas_data.swap_data =
                                 convert_swap_data_points(&app.data_collection, false);
-                            let memory_and_swap_labels = convert_mem_labels(&app.data_collection);
+                            let memory_and_swap_labels = convert_mem_labels(
+                                &app.data_collection,
+                                app.app_config_fields.percent_precision,
+                            );
                             app.canvas_data.mem_label_percent = memory_and_swap_labels.0;
                             app.canvas_data.mem_label_frac = memory_and_swap_labels.1;
                             app.canvas_data.swap_label_percent = memory_and_swap_labels.2;
                             app.canvas_data.swap_label_frac = memory_and_swap_labels.3;
+                            app.canvas_data.mem_pressure = convert_mem_pressure(
+                                &app.data_collection,
+                                &app.app_config_fields.mem_pressure_weights,
+                            );
+
+                            if app.update_mem_warning() && app.app_config_fields.mem_warn_bell {
+                                ring_terminal_bell()?;
+                            }
                         }
 
                         if app.used_widgets.use_cpu {
                             // CPU
-                            app.canvas_data.cpu_data =
-                                convert_cpu_data_points(&app.data_collection, false);
+                            app.canvas_data.cpu_data = convert_cpu_data_points(
+                                &app.data_collection,
+                                false,
+                                app.app_config_fields.percent_precision,
+                            );
+
+                            if app.app_config_fields.show_scheduler_activity {
+                                app.canvas_data.scheduler_activity_display =
+                                    Some(convert_scheduler_activity(&app.data_collection));
+                            }
                         }
 
                         // Processes
                         if app.used_widgets.use_proc {
                             update_all_process_lists(&mut app);
+                            app.canvas_data.zombie_warning = convert_zombie_warning(
+                                &app.data_collection,
+                                app.app_config_fields.zombie_warn_threshold,
+                            );
+                        }
+
+                        if app.app_config_fields.show_self_usage {
+                            app.canvas_data.self_usage_display = convert_self_usage(
+                                &app.data_collection,
+                                &app.app_config_fields.byte_format,
+                            );
                         }
 
                         // Battery
@@ -188,13 +350,34 @@ fn main() -> Result<()> {
                         }
                     }
                 }
+                BottomEvent::RemoteDisconnected => {
+                    app.is_remote_disconnected = true;
+                }
                 BottomEvent::Clean => {
-                    app.data_collection
-                        .clean_data(constants::STALE_MAX_MILLISECONDS);
+                    app.data_collection.clean_data(
+                        constants::STALE_MAX_MILLISECONDS,
+                        app.app_config_fields.max_data_points,
+                    );
                 }
             }
         }
 
+        if app.app_config_fields.pause_idle_redraws {
+            let drawn_state = (
+                app.canvas_data.get_fingerprint(),
+                app.is_data_delayed(),
+                app.is_remote_disconnected,
+                terminal.size()?,
+            );
+            is_idle = !has_input
+                && !app.is_showing_timed_elements()
+                && last_drawn_state == Some(drawn_state);
+            if is_idle {
+                continue;
+            }
+            last_drawn_state = Some(drawn_state);
+        }
+
         // TODO: [OPT] Should not draw if no change (ie: scroll max)
         try_drawing(&mut terminal, &mut app, &mut painter)?;
     }
@@ -202,3 +385,107 @@ fn main() -> Result<()> {
     cleanup_terminal(&mut terminal)?;
     Ok(())
 }
+
+/// Prints a timestamped JSON snapshot of the collected data to stdout, one line per update,
+/// until interrupted.
+fn stream_json(
+    mut app: app::App, recorder: Option<app::data_harvester::recording::Recorder>,
+    replay_frames: Option<Vec<app::data_harvester::recording::RecordedFrame>>,
+    remote_host: Option<String>,
+) -> Result<()> {
+    let is_terminated = Arc::new(AtomicBool::new(false));
+    let ist_clone = is_terminated.clone();
+    ctrlc::set_handler(move || {
+        ist_clone.store(true, Ordering::SeqCst);
+    })?;
+
+    let (sender, receiver) = mpsc::channel();
+    let (_thread_control_sender, thread_control_receiver) = mpsc::channel();
+    create_event_thread(
+        sender,
+        thread_control_receiver,
+        &app.app_config_fields,
+        app.used_widgets.clone(),
+        recorder,
+        replay_frames,
+        remote_host,
+        // Snapshots only list the basic fields of each process.
+        app::data_harvester::processes::ProcessFields::default(),
+    );
+
+    let mut stdout_val = stdout();
+    while !is_terminated.load(Ordering::SeqCst) {
+        if let Ok(BottomEvent::Update(data)) =
+            receiver.recv_timeout(Duration::from_millis(TICK_RATE_IN_MILLISECONDS))
+        {
+            app.data_collection.eat_data(
+                &data,
+                app.app_config_fields.rate_smoothing_alpha,
+                &app.app_config_fields.byte_format,
+            );
+            app.data_collection.clean_data(
+                constants::STALE_MAX_MILLISECONDS,
+                app.app_config_fields.max_data_points,
+            );
+
+            let snapshot = snapshot::Snapshot::from_data_collection(
+                &app.data_collection,
+                app.app_config_fields.time_format.as_deref(),
+            );
+            if writeln!(stdout_val, "{}", snapshot.to_json()?)
+                .and_then(|_| stdout_val.flush())
+                .is_err()
+            {
+                // Whatever was reading our output has gone away, so there's no point continuing.
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints each harvested frame to stdout in the recording format, for `--remote` to read over
+/// SSH, until stdout is closed.  Everything is collected, as the layout is chosen locally.
+fn collect_frames(app: app::App) -> Result<()> {
+    let (sender, receiver) = mpsc::channel();
+    let (thread_control_sender, thread_control_receiver) = mpsc::channel();
+    create_event_thread(
+        sender,
+        thread_control_receiver,
+        &app.app_config_fields,
+        app::layout_manager::UsedWidgets {
+            use_cpu: true,
+            use_mem: true,
+            use_net: true,
+            use_proc: true,
+            use_disk: true,
+            use_temp: true,
+            use_battery: true,
+            use_pressure: true,
+            use_system_info: true,
+        },
+        None,
+        None,
+        None,
+        app::data_harvester::processes::ProcessFields::all(),
+    );
+    // The CPU usage breakdown is toggled locally, so it is always read unless disabled.
+    thread_control_sender
+        .send(ThreadControlEvent::UpdateCpuBreakdown(
+            !app.app_config_fields.disable_cpu_breakdown,
+        ))
+        .ok();
+
+    let mut recorder = app::data_harvester::recording::Recorder::stdout();
+    while let Ok(event) = receiver.recv() {
+        if let BottomEvent::Update(data) = event {
+            if recorder.record(&data).is_err() {
+                // The SSH connection has closed, so nothing is reading our output anymore.
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,13 +1,18 @@
 use itertools::izip;
-use std::collections::HashMap;
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    time::Instant,
+};
 
 use tui::{
     backend::Backend,
-    layout::{Constraint, Direction, Layout, Rect},
-    widgets::Text,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    widgets::{Block, Paragraph, Text},
     Frame, Terminal,
 };
 
+use buffer_capture::BufferCapture;
 use canvas_colours::*;
 use dialogs::*;
 use widgets::*;
@@ -19,18 +24,69 @@ use crate::{
         App,
     },
     constants::*,
-    data_conversion::{ConvertedBatteryData, ConvertedCpuData, ConvertedProcessData},
+    data_conversion::{
+        get_process_data_fingerprint, ConvertedBatteryData, ConvertedCpuData, ConvertedProcessData,
+        ConvertedTempData, ZombieWarning,
+    },
     utils::error,
+    Pid,
 };
 
+mod buffer_capture;
 mod canvas_colours;
 mod dialogs;
 mod drawing_utils;
+mod half_block;
+mod heatmap;
+#[cfg(feature = "pixel-graphs")]
+mod pixel_graph;
 mod widgets;
 
+pub use canvas_colours::{detect_colour_depth, ColourDepth};
+#[cfg(feature = "pixel-graphs")]
+pub use pixel_graph::{detect_image_protocol, ImageProtocol};
+
 /// Point is of time, data
 type Point = (f64, f64);
 
+const DATA_DELAYED_TEXT: &str = " Data delayed ";
+const REMOTE_DISCONNECTED_TEXT: &str = " Disconnected, reconnecting ";
+
+/// How the selected row of the process widget is emphasized.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SelectionStyle {
+    /// Highlight the background of the whole row.
+    FullRow,
+    /// Mark the row with a leading arrow.
+    Arrow,
+    /// Make the text of the row bold.
+    Bold,
+}
+
+impl Default for SelectionStyle {
+    fn default() -> Self {
+        SelectionStyle::FullRow
+    }
+}
+
+/// What the lines of graphs are drawn with.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GraphMarker {
+    Braille,
+    Dot,
+    /// The upper and lower halves of blocks, which more fonts can show than braille.
+    HalfBlock,
+    /// Images drawn with a graphics protocol the terminal supports.
+    #[cfg(feature = "pixel-graphs")]
+    Pixels(ImageProtocol),
+}
+
+impl Default for GraphMarker {
+    fn default() -> Self {
+        GraphMarker::Braille
+    }
+}
+
 #[derive(Default)]
 pub struct DisplayableData {
     pub rx_display: String,
@@ -40,17 +96,80 @@ pub struct DisplayableData {
     pub network_data_rx: Vec<Point>,
     pub network_data_tx: Vec<Point>,
     pub disk_data: Vec<Vec<String>>,
-    pub temp_sensor_data: Vec<Vec<String>>,
+    pub disk_used_percent: Vec<Option<f64>>,
+    pub temp_sensor_data: Vec<ConvertedTempData>,
+    pub pressure_data: Vec<Vec<String>>,
+    pub system_info_data: Vec<Vec<String>>,
     pub single_process_data: Vec<ConvertedProcessData>, // Contains single process data
     pub finalized_process_data_map: HashMap<u64, Vec<ConvertedProcessData>>, // What's actually displayed
+    /// The process and thread counts shown in each process widget's title.
+    pub process_summary_map: HashMap<u64, String>,
+    /// The single process data when the process baseline was marked, if one is marked.
+    pub process_diff_baseline: Option<Vec<ConvertedProcessData>>,
+    /// When each process in the baseline was first seen to have exited.
+    pub process_exit_times: HashMap<Pid, Instant>,
     pub mem_label_percent: String,
     pub swap_label_percent: String,
     pub mem_label_frac: String,
     pub swap_label_frac: String,
     pub mem_data: Vec<Point>,
     pub swap_data: Vec<Point>,
+    pub mem_breakdown_data: Vec<Vec<Point>>,
+    /// The memory pressure score from 0 to 100, if it is available.
+    pub mem_pressure: Option<f64>,
     pub cpu_data: Vec<ConvertedCpuData>,
     pub battery_data: Vec<ConvertedBatteryData>,
+    /// The CPU and memory usage of bottom itself, if it is being shown.
+    pub self_usage_display: Option<String>,
+    /// Context switch and interrupt rates, if they are being shown.
+    pub scheduler_activity_display: Option<String>,
+    /// Set if there are more zombie processes than the configured threshold.
+    pub zombie_warning: Option<ZombieWarning>,
+}
+
+impl DisplayableData {
+    /// Returns a cheap fingerprint of the values shown in each widget.  Graphs only contribute
+    /// their current values, so graphs scrolling over time does not change the fingerprint.
+    pub fn get_fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.rx_display.hash(&mut hasher);
+        self.tx_display.hash(&mut hasher);
+        self.total_rx_display.hash(&mut hasher);
+        self.total_tx_display.hash(&mut hasher);
+        self.disk_data.hash(&mut hasher);
+        self.temp_sensor_data.hash(&mut hasher);
+        self.pressure_data.hash(&mut hasher);
+        self.system_info_data.hash(&mut hasher);
+        self.mem_label_percent.hash(&mut hasher);
+        self.swap_label_percent.hash(&mut hasher);
+        self.mem_label_frac.hash(&mut hasher);
+        self.swap_label_frac.hash(&mut hasher);
+        self.mem_pressure
+            .map(|mem_pressure| mem_pressure.round() as u64)
+            .hash(&mut hasher);
+        self.self_usage_display.hash(&mut hasher);
+        self.zombie_warning.hash(&mut hasher);
+        self.scheduler_activity_display.hash(&mut hasher);
+        for cpu in &self.cpu_data {
+            cpu.legend_value.hash(&mut hasher);
+        }
+        for battery in &self.battery_data {
+            battery.charge_percentage.to_bits().hash(&mut hasher);
+            battery.state.hash(&mut hasher);
+            battery.duration_until_full.hash(&mut hasher);
+            battery.duration_until_empty.hash(&mut hasher);
+        }
+
+        let mut process_widget_ids = self.finalized_process_data_map.keys().collect::<Vec<_>>();
+        process_widget_ids.sort();
+        for widget_id in process_widget_ids {
+            get_process_data_fingerprint(&self.finalized_process_data_map[widget_id])
+                .hash(&mut hasher);
+            self.process_summary_map.get(widget_id).hash(&mut hasher);
+        }
+
+        hasher.finish()
+    }
 }
 
 /// Handles the canvas' state.  TODO: [OPT] implement this.
@@ -68,6 +187,8 @@ pub struct Painter {
     derived_widget_draw_locs: Vec<Vec<Vec<Vec<Rect>>>>,
     table_height_offset: u16,
     requires_boundary_recalculation: bool,
+    #[cfg(feature = "pixel-graphs")]
+    pixel_graphs: pixel_graph::PixelGraphs,
 }
 
 impl Painter {
@@ -153,6 +274,8 @@ impl Painter {
             derived_widget_draw_locs: Vec::default(),
             table_height_offset: if is_basic_mode { 2 } else { 4 } + table_gap,
             requires_boundary_recalculation: true,
+            #[cfg(feature = "pixel-graphs")]
+            pixel_graphs: pixel_graph::PixelGraphs::default(),
         }
     }
 
@@ -218,7 +341,17 @@ impl Painter {
         }
 
         terminal.autoresize()?;
+        #[cfg(feature = "pixel-graphs")]
+        self.pixel_graphs.start_frame();
         terminal.draw(|mut f| {
+            let min_width = app_state.app_config_fields.min_terminal_width;
+            let min_height = app_state.app_config_fields.min_terminal_height;
+            if f.size().width < min_width || f.size().height < min_height {
+                // Nothing else is drawn until the terminal is resized, as the layout would be garbled.
+                self.draw_terminal_too_small(&mut f, min_width, min_height);
+                return;
+            }
+
             if app_state.help_dialog_state.is_showing_help {
                 let gen_help_len = GENERAL_HELP_TEXT.len() as u16 + 3;
                 let border_len = f.size().height.saturating_sub(gen_help_len) / 2;
@@ -395,8 +528,24 @@ impl Painter {
                         true,
                         app_state.current_widget.widget_id,
                     ),
+                    Pressure => self.draw_pressure_table(
+                        &mut f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
+                    SystemInfo => self.draw_system_info_table(
+                        &mut f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
                     _ => {}
                 }
+
+                if let Some(delayed_text) = get_data_delayed_text(app_state) {
+                    self.draw_data_delayed_indicator(&mut f, rect[0], delayed_text);
+                }
             } else if app_state.app_config_fields.use_basic_mode {
                 // Basic mode.  This basically removes all graphs but otherwise
                 // the same info.
@@ -582,19 +731,198 @@ impl Painter {
                         });
                 }
             }
+
+            if !app_state.help_dialog_state.is_showing_help
+                && !app_state.delete_dialog_state.is_showing_dd
+            {
+                if let Some(label) = &app_state.app_config_fields.label {
+                    self.draw_label(&mut f, label);
+                }
+                if let Some(self_usage_display) = &app_state.canvas_data.self_usage_display {
+                    self.draw_self_usage(&mut f, self_usage_display);
+                }
+            }
+
+            // Done after everything else is drawn, so the capture matches what is on screen.
+            if let Some(widget_id) = app_state.graph_capture_widget_id.take() {
+                let capture_loc = app_state.widget_map.get(&widget_id).and_then(|widget| {
+                    match (widget.top_left_corner, widget.bottom_right_corner) {
+                        (Some((tlc_x, tlc_y)), Some((brc_x, brc_y))) => Some(Rect::new(
+                            tlc_x,
+                            tlc_y,
+                            brc_x.saturating_sub(tlc_x),
+                            brc_y.saturating_sub(tlc_y),
+                        )),
+                        _ => None,
+                    }
+                });
+
+                if let Some(capture_loc) = capture_loc {
+                    let mut captured_graph = String::new();
+                    f.render_widget(
+                        BufferCapture {
+                            output: &mut captured_graph,
+                        },
+                        capture_loc,
+                    );
+                    app_state.copy_graph_capture(&captured_graph);
+                }
+            }
+
+            self.draw_message_dialog(&mut f, app_state);
+            self.draw_quit_prompt(&mut f, app_state);
+
+            #[cfg(feature = "pixel-graphs")]
+            f.render_widget(self.pixel_graphs.mask(), f.size());
         })?;
 
         app_state.is_force_redraw = false;
         app_state.is_determining_widget_boundary = false;
 
+        #[cfg(feature = "pixel-graphs")]
+        {
+            if let GraphMarker::Pixels(protocol) = app_state.app_config_fields.graph_marker {
+                if self.pixel_graphs.flush(protocol)? {
+                    terminal.clear()?;
+                    return self.draw_data(terminal, app_state);
+                }
+            }
+        }
+
         Ok(())
     }
 
+    /// Draws a single centered note in place of everything else when the terminal is
+    /// smaller than the configured minimum size.
+    fn draw_terminal_too_small<B: Backend>(
+        &self, f: &mut Frame<'_, B>, min_width: u16, min_height: u16,
+    ) {
+        let draw_loc = f.size();
+        let middle_chunk = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(draw_loc.height.saturating_sub(1) / 2),
+                    Constraint::Length(1),
+                    Constraint::Min(0),
+                ]
+                .as_ref(),
+            )
+            .split(draw_loc)[1];
+
+        f.render_widget(
+            Paragraph::new(
+                [Text::styled(
+                    format!("Terminal too small: {}x{} needed", min_width, min_height),
+                    self.colours.text_style,
+                )]
+                .iter(),
+            )
+            .alignment(Alignment::Center)
+            .wrap(true),
+            middle_chunk,
+        );
+    }
+
+    /// Draws a placeholder note in place of a widget whose data collection has been
+    /// disabled by the user.
+    fn draw_disabled_widget<B: Backend>(
+        &self, f: &mut Frame<'_, B>, block: Block<'_>, draw_loc: Rect,
+    ) {
+        f.render_widget(
+            Paragraph::new(
+                [Text::styled(
+                    "Data collection is disabled",
+                    self.colours.disabled_text_style,
+                )]
+                .iter(),
+            )
+            .block(block),
+            draw_loc,
+        );
+    }
+
+    /// Draws a note along the bottom border of a widget, to show that its data has not
+    /// been updated for a while or that the remote host being monitored is disconnected.
+    fn draw_data_delayed_indicator<B: Backend>(
+        &self, f: &mut Frame<'_, B>, draw_loc: Rect, delayed_text: &str,
+    ) {
+        let width = std::cmp::min(
+            delayed_text.chars().count() as u16,
+            draw_loc.width.saturating_sub(2),
+        );
+        if width == 0 || draw_loc.height < 2 {
+            return;
+        }
+
+        f.render_widget(
+            Paragraph::new([Text::styled(delayed_text, self.colours.invalid_query_style)].iter()),
+            Rect::new(
+                draw_loc.x + draw_loc.width - width - 1,
+                draw_loc.y + draw_loc.height - 1,
+                width,
+                1,
+            ),
+        );
+    }
+
+    /// Draws the instance label in the middle of the top border.
+    fn draw_label<B: Backend>(&self, f: &mut Frame<'_, B>, label: &str) {
+        let terminal_area = f.size();
+        let label = format!(" {} ", label);
+        let width = std::cmp::min(
+            label.chars().count() as u16,
+            terminal_area.width.saturating_sub(2),
+        );
+        if width == 0 {
+            return;
+        }
+
+        f.render_widget(
+            Paragraph::new([Text::styled(&label, self.colours.widget_title_style)].iter()),
+            Rect::new(
+                terminal_area.x + (terminal_area.width - width) / 2,
+                terminal_area.y,
+                width,
+                1,
+            ),
+        );
+    }
+
+    /// Draws the usage of bottom itself along the top border, in the top right corner.
+    fn draw_self_usage<B: Backend>(&self, f: &mut Frame<'_, B>, self_usage_display: &str) {
+        let terminal_area = f.size();
+        let width = std::cmp::min(
+            self_usage_display.chars().count() as u16,
+            terminal_area.width.saturating_sub(2),
+        );
+        if width == 0 {
+            return;
+        }
+
+        f.render_widget(
+            Paragraph::new(
+                [Text::styled(
+                    self_usage_display,
+                    self.colours.widget_title_style,
+                )]
+                .iter(),
+            ),
+            Rect::new(
+                terminal_area.x + terminal_area.width - width - 1,
+                terminal_area.y,
+                width,
+                1,
+            ),
+        );
+    }
+
     fn draw_widgets_with_constraints<B: Backend>(
         &self, f: &mut Frame<'_, B>, app_state: &mut App, widgets: &BottomColRow,
         widget_draw_locs: &[Rect],
     ) {
         use BottomWidgetType::*;
+        let delayed_text = get_data_delayed_text(app_state);
         for (widget, widget_draw_loc) in widgets.children.iter().zip(widget_draw_locs) {
             match &widget.widget_type {
                 Empty => {}
@@ -621,8 +949,31 @@ impl Painter {
                     true,
                     widget.widget_id,
                 ),
+                Pressure => {
+                    self.draw_pressure_table(f, app_state, *widget_draw_loc, widget.widget_id)
+                }
+                SystemInfo => {
+                    self.draw_system_info_table(f, app_state, *widget_draw_loc, widget.widget_id)
+                }
                 _ => {}
             }
+
+            if let Some(delayed_text) = delayed_text {
+                if widget.widget_type != Empty {
+                    self.draw_data_delayed_indicator(f, *widget_draw_loc, delayed_text);
+                }
+            }
         }
     }
 }
+
+/// Returns the note to show along the bottom of each widget if its data isn't current.
+fn get_data_delayed_text(app_state: &App) -> Option<&'static str> {
+    if app_state.is_remote_disconnected {
+        Some(REMOTE_DISCONNECTED_TEXT)
+    } else if app_state.is_data_delayed() {
+        Some(DATA_DELAYED_TEXT)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,24 @@
+use tui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+/// A "widget" that doesn't draw anything, but instead copies whatever has already been
+/// drawn in its area out as lines of text.  Render this after the widget to capture.
+pub struct BufferCapture<'a> {
+    pub output: &'a mut String,
+}
+
+impl<'a> Widget for BufferCapture<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        let lines = (area.top()..area.bottom())
+            .map(|y| {
+                (area.left()..area.right())
+                    .map(|x| buf.get(x, y).symbol.as_str())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+
+        *self.output = lines.join("\n");
+    }
+}
@@ -1,8 +1,12 @@
+use std::collections::HashMap;
+
 use tui::style::{Color, Style};
 
 use colour_utils::*;
 
-use crate::{constants::*, utils::error};
+use crate::{constants::*, options::ConfigColourStop, utils::error};
+
+pub use colour_utils::{detect_colour_depth, ColourDepth};
 
 mod colour_utils;
 
@@ -13,6 +17,8 @@ pub struct CanvasColours {
     pub table_header_style: Style,
     pub ram_style: Style,
     pub swap_style: Style,
+    pub buffers_style: Style,
+    pub cached_style: Style,
     pub rx_style: Style,
     pub tx_style: Style,
     pub total_rx_style: Style,
@@ -20,6 +26,7 @@ pub struct CanvasColours {
     pub all_colour_style: Style,
     pub avg_colour_style: Style,
     pub cpu_colour_styles: Vec<Style>,
+    pub cpu_breakdown_styles: Vec<Style>,
     pub border_style: Style,
     pub highlighted_border_style: Style,
     pub text_style: Style,
@@ -29,6 +36,27 @@ pub struct CanvasColours {
     pub battery_bar_styles: Vec<Style>,
     pub invalid_query_style: Style,
     pub disabled_text_style: Style,
+    /// Tree mode entries that don't match the search, but are kept to show the tree.
+    pub disabled_entry_style: Style,
+    /// The background of every other table row, if zebra stripes are on.
+    pub alt_row_style: Style,
+    pub pinned_process_style: Style,
+    pub watched_process_style: Style,
+    pub new_process_style: Style,
+    pub exited_process_style: Style,
+    pub spawned_process_style: Style,
+    pub stopped_process_style: Style,
+    pub mem_alarm_style: Style,
+    pub disk_alarm_style: Style,
+    /// Colour stops for the CPU graph and legend, sorted by ascending percentage.
+    pub cpu_threshold_styles: Vec<(f64, Style)>,
+    /// Colour stops for the memory graph and legend, sorted by ascending percentage.
+    pub mem_threshold_styles: Vec<(f64, Style)>,
+    /// Process row styles by state code, such as `D` for uninterruptible sleep.
+    pub process_state_styles: HashMap<char, Style>,
+    /// How many colours the terminal can show, which colours made while drawing are also
+    /// downsampled to.
+    pub colour_depth: ColourDepth,
 }
 
 impl Default for CanvasColours {
@@ -44,6 +72,8 @@ impl Default for CanvasColours {
             table_header_style: Style::default().fg(STANDARD_HIGHLIGHT_COLOUR),
             ram_style: Style::default().fg(STANDARD_FIRST_COLOUR),
             swap_style: Style::default().fg(STANDARD_SECOND_COLOUR),
+            buffers_style: Style::default().fg(STANDARD_THIRD_COLOUR),
+            cached_style: Style::default().fg(STANDARD_FOURTH_COLOUR),
             rx_style: Style::default().fg(STANDARD_FIRST_COLOUR),
             tx_style: Style::default().fg(STANDARD_SECOND_COLOUR),
             total_rx_style: Style::default().fg(STANDARD_THIRD_COLOUR),
@@ -51,6 +81,14 @@ impl Default for CanvasColours {
             all_colour_style: Style::default().fg(ALL_COLOUR),
             avg_colour_style: Style::default().fg(AVG_COLOUR),
             cpu_colour_styles: Vec::new(),
+            cpu_breakdown_styles: vec![
+                Style::default().fg(Color::LightGreen),
+                Style::default().fg(Color::LightRed),
+                Style::default().fg(Color::LightYellow),
+                Style::default().fg(Color::LightMagenta),
+                Style::default().fg(Color::LightCyan),
+                Style::default().fg(Color::LightBlue),
+            ],
             border_style: Style::default().fg(text_colour),
             highlighted_border_style: Style::default().fg(STANDARD_HIGHLIGHT_COLOUR),
             text_style: Style::default().fg(text_colour),
@@ -66,6 +104,24 @@ impl Default for CanvasColours {
             ],
             invalid_query_style: Style::default().fg(tui::style::Color::Red),
             disabled_text_style: Style::default().fg(Color::DarkGray),
+            disabled_entry_style: Style::default().fg(Color::DarkGray),
+            alt_row_style: Style::default().bg(Color::Indexed(236)),
+            pinned_process_style: Style::default().fg(STANDARD_THIRD_COLOUR),
+            watched_process_style: Style::default().bg(Color::DarkGray),
+            new_process_style: Style::default().fg(Color::Green),
+            exited_process_style: Style::default().fg(Color::Red),
+            spawned_process_style: Style::default().fg(Color::Black).bg(Color::Green),
+            stopped_process_style: Style::default().fg(Color::DarkGray),
+            mem_alarm_style: Style::default().fg(Color::Red),
+            disk_alarm_style: Style::default().fg(Color::Red),
+            cpu_threshold_styles: Vec::new(),
+            mem_threshold_styles: Vec::new(),
+            // Stopped processes use the stopped process style unless a colour is set for them.
+            process_state_styles: [('R', Color::Green), ('D', Color::Yellow), ('Z', Color::Red)]
+                .iter()
+                .map(|(state_char, colour)| (*state_char, Style::default().fg(*colour)))
+                .collect(),
+            colour_depth: ColourDepth::TrueColour,
         }
     }
 }
@@ -98,6 +154,16 @@ impl CanvasColours {
         Ok(())
     }
 
+    pub fn set_buffers_colour(&mut self, colour: &str) -> error::Result<()> {
+        self.buffers_style = get_style_from_config(colour)?;
+        Ok(())
+    }
+
+    pub fn set_cached_colour(&mut self, colour: &str) -> error::Result<()> {
+        self.cached_style = get_style_from_config(colour)?;
+        Ok(())
+    }
+
     pub fn set_swap_colour(&mut self, colour: &str) -> error::Result<()> {
         self.swap_style = get_style_from_config(colour)?;
         Ok(())
@@ -133,6 +199,35 @@ impl CanvasColours {
         Ok(())
     }
 
+    pub fn set_cpu_breakdown_colours(&mut self, colours: &[String]) -> error::Result<()> {
+        if colours.len() != CPU_BREAKDOWN_LABELS.len() {
+            return Err(error::BottomError::ConfigError(format!(
+                "expected {} CPU breakdown colours, got {}.",
+                CPU_BREAKDOWN_LABELS.len(),
+                colours.len()
+            )));
+        }
+
+        self.cpu_breakdown_styles = colours
+            .iter()
+            .map(|colour| get_style_from_config(colour))
+            .collect::<error::Result<Vec<Style>>>()?;
+        Ok(())
+    }
+
+    /// Returns the style for a CPU entry when colouring by usage.
+    pub fn get_cpu_usage_gradient_style(&self, usage_percent: f64) -> Style {
+        Style::default().fg(downsample_colour(
+            get_usage_gradient_colour(usage_percent),
+            &self.colour_depth,
+        ))
+    }
+
+    /// Returns the colour of a CPU heatmap cell for the given usage.
+    pub fn get_cpu_heatmap_colour(&self, usage_percent: f64) -> Color {
+        downsample_colour(get_usage_gradient_colour(usage_percent), &self.colour_depth)
+    }
+
     pub fn set_cpu_colours(&mut self, colours: &[String]) -> error::Result<()> {
         let max_amount = std::cmp::min(colours.len(), NUM_COLOURS);
         for (itx, colour) in colours.iter().enumerate() {
@@ -176,6 +271,164 @@ impl CanvasColours {
         Ok(())
     }
 
+    pub fn set_pinned_process_colour(&mut self, colour: &str) -> error::Result<()> {
+        self.pinned_process_style = get_style_from_config(colour)?;
+        Ok(())
+    }
+
+    pub fn set_watched_process_bg_colour(&mut self, colour: &str) -> error::Result<()> {
+        self.watched_process_style = Style::default().bg(get_colour_from_config(colour)?);
+        Ok(())
+    }
+
+    pub fn set_disabled_entry_colour(&mut self, colour: &str) -> error::Result<()> {
+        self.disabled_entry_style = get_style_from_config(colour)?;
+        Ok(())
+    }
+
+    pub fn set_alt_row_colour(&mut self, colour: &str) -> error::Result<()> {
+        self.alt_row_style = Style::default().bg(get_colour_from_config(colour)?);
+        Ok(())
+    }
+
+    /// Returns the style of the table row at `row_index`, which is given the alternate row
+    /// background on odd rows if `zebra_stripes` is set.  Rows with their own background, such
+    /// as watched processes, keep it.
+    pub fn get_table_row_style(
+        &self, row_style: Option<Style>, row_index: usize, zebra_stripes: bool,
+    ) -> Option<Style> {
+        if zebra_stripes && row_index % 2 == 1 {
+            let row_style = row_style.unwrap_or(self.text_style);
+            if row_style.bg == Color::Reset {
+                Some(row_style.bg(self.alt_row_style.bg))
+            } else {
+                Some(row_style)
+            }
+        } else {
+            row_style
+        }
+    }
+
+    pub fn set_mem_alarm_colour(&mut self, colour: &str) -> error::Result<()> {
+        self.mem_alarm_style = get_style_from_config(colour)?;
+        Ok(())
+    }
+
+    pub fn set_disk_alarm_colour(&mut self, colour: &str) -> error::Result<()> {
+        self.disk_alarm_style = get_style_from_config(colour)?;
+        Ok(())
+    }
+
+    pub fn set_cpu_threshold_colours(&mut self, stops: &[ConfigColourStop]) -> error::Result<()> {
+        self.cpu_threshold_styles = get_threshold_styles(stops)?;
+        Ok(())
+    }
+
+    pub fn set_mem_threshold_colours(&mut self, stops: &[ConfigColourStop]) -> error::Result<()> {
+        self.mem_threshold_styles = get_threshold_styles(stops)?;
+        Ok(())
+    }
+
+    /// Returns the style of the CPU colour stop reached by the given usage, if any.
+    pub fn get_cpu_threshold_style(&self, usage_percent: f64) -> Option<Style> {
+        get_threshold_style(&self.cpu_threshold_styles, usage_percent)
+    }
+
+    /// Returns the style of the memory colour stop reached by the given usage, if any.
+    pub fn get_mem_threshold_style(&self, usage_percent: f64) -> Option<Style> {
+        get_threshold_style(&self.mem_threshold_styles, usage_percent)
+    }
+
+    /// Sets the process row colours of each state code, which must be a single character.  States
+    /// that aren't given keep their default colours.
+    pub fn set_process_state_colours(
+        &mut self, colours: &HashMap<String, String>,
+    ) -> error::Result<()> {
+        let process_state_styles = colours
+            .iter()
+            .map(|(state, colour)| {
+                let mut state_chars = state.chars();
+                match (state_chars.next(), state_chars.next()) {
+                    (Some(state_char), None) => Ok((state_char, get_style_from_config(colour)?)),
+                    _ => Err(error::BottomError::ConfigError(format!(
+                        "state \"{}\" must be a single state code, such as \"D\".",
+                        state
+                    ))),
+                }
+            })
+            .collect::<error::Result<Vec<_>>>()?;
+        self.process_state_styles.extend(process_state_styles);
+        Ok(())
+    }
+
+    /// Returns the style of processes with the given state code, if one is set.
+    pub fn get_process_state_style(&self, state_char: char) -> Option<Style> {
+        self.process_state_styles.get(&state_char).copied()
+    }
+
+    /// Downsamples every colour to ones the terminal can show.  Must be run after all other
+    /// colours are set.
+    pub fn set_colour_depth(&mut self, colour_depth: ColourDepth) {
+        self.currently_selected_text_colour =
+            downsample_colour(self.currently_selected_text_colour, &colour_depth);
+        self.currently_selected_bg_colour =
+            downsample_colour(self.currently_selected_bg_colour, &colour_depth);
+
+        for style in [
+            &mut self.currently_selected_text_style,
+            &mut self.table_header_style,
+            &mut self.ram_style,
+            &mut self.swap_style,
+            &mut self.buffers_style,
+            &mut self.cached_style,
+            &mut self.rx_style,
+            &mut self.tx_style,
+            &mut self.total_rx_style,
+            &mut self.total_tx_style,
+            &mut self.all_colour_style,
+            &mut self.avg_colour_style,
+            &mut self.border_style,
+            &mut self.highlighted_border_style,
+            &mut self.text_style,
+            &mut self.widget_title_style,
+            &mut self.graph_style,
+            &mut self.invalid_query_style,
+            &mut self.disabled_text_style,
+            &mut self.disabled_entry_style,
+            &mut self.alt_row_style,
+            &mut self.pinned_process_style,
+            &mut self.watched_process_style,
+            &mut self.new_process_style,
+            &mut self.exited_process_style,
+            &mut self.spawned_process_style,
+            &mut self.stopped_process_style,
+            &mut self.mem_alarm_style,
+            &mut self.disk_alarm_style,
+        ]
+        .iter_mut()
+        {
+            **style = downsample_style(**style, &colour_depth);
+        }
+
+        for style in self
+            .cpu_colour_styles
+            .iter_mut()
+            .chain(self.cpu_breakdown_styles.iter_mut())
+            .chain(self.battery_bar_styles.iter_mut())
+            .chain(
+                self.cpu_threshold_styles
+                    .iter_mut()
+                    .chain(self.mem_threshold_styles.iter_mut())
+                    .map(|(_percent, style)| style),
+            )
+            .chain(self.process_state_styles.values_mut())
+        {
+            *style = downsample_style(*style, &colour_depth);
+        }
+
+        self.colour_depth = colour_depth;
+    }
+
     pub fn set_battery_colors(&mut self, colours: &[String]) -> error::Result<()> {
         if colours.is_empty() {
             Err(error::BottomError::ConfigError(
@@ -192,3 +445,31 @@ impl CanvasColours {
         }
     }
 }
+
+fn get_threshold_styles(stops: &[ConfigColourStop]) -> error::Result<Vec<(f64, Style)>> {
+    let mut threshold_styles = stops
+        .iter()
+        .map(|stop| {
+            if stop.percent < 0.0 || stop.percent > 100.0 {
+                Err(error::BottomError::ConfigError(format!(
+                    "threshold percentage {} must be between 0 and 100.",
+                    stop.percent
+                )))
+            } else {
+                Ok((stop.percent, get_style_from_config(&stop.color)?))
+            }
+        })
+        .collect::<error::Result<Vec<_>>>()?;
+    threshold_styles.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    Ok(threshold_styles)
+}
+
+/// Returns the style of the highest colour stop at or below the given percentage.
+fn get_threshold_style(threshold_styles: &[(f64, Style)], percent: f64) -> Option<Style> {
+    threshold_styles
+        .iter()
+        .rev()
+        .find(|(stop_percent, _)| percent >= *stop_percent)
+        .map(|(_, style)| *style)
+}
@@ -235,3 +235,170 @@ The following are supported strings:
 pub fn get_style_from_color_name(color_name: &str) -> error::Result<Style> {
     Ok(Style::default().fg(convert_name_to_color(color_name)?))
 }
+
+/// Returns a colour on a green to yellow to red gradient, based on a usage percentage.
+pub fn get_usage_gradient_colour(usage_percent: f64) -> Color {
+    let ratio = (usage_percent / 100.0).max(0.0).min(1.0);
+    if ratio < 0.5 {
+        Color::Rgb((ratio * 2.0 * 255.0) as u8, 255, 0)
+    } else {
+        Color::Rgb(255, ((1.0 - ratio) * 2.0 * 255.0) as u8, 0)
+    }
+}
+
+/// How many colours the terminal can show.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColourDepth {
+    /// The 16 named colours.
+    Basic,
+    /// The 256 indexed colours.
+    Indexed,
+    /// Any RGB colour.
+    TrueColour,
+}
+
+/// The usual RGB values of the 16 named colours, in the order of their indices.
+const BASIC_COLOURS: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// The levels of each channel in the 6x6x6 colour cube of the indexed colours.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Terminals known to show only the 16 basic colours, such as the Linux console.
+const BASIC_COLOUR_TERMS: [&str; 8] = [
+    "linux",
+    "vt100",
+    "vt102",
+    "vt220",
+    "dumb",
+    "ansi",
+    "cons25",
+    "xterm-color",
+];
+
+/// Guesses how many colours the terminal can show from `COLORTERM` and `TERM`.  Colours are
+/// only downsampled for terminals known to be limited, and are otherwise left as they are,
+/// such as for unrecognised terminals or the Windows console.
+pub fn detect_colour_depth() -> ColourDepth {
+    if let Ok(colour_term) = std::env::var("COLORTERM") {
+        if colour_term == "truecolor" || colour_term == "24bit" {
+            return ColourDepth::TrueColour;
+        }
+    }
+
+    match std::env::var("TERM") {
+        Ok(term) if term.contains("truecolor") || term.contains("direct") => {
+            ColourDepth::TrueColour
+        }
+        Ok(term) if term.contains("256color") => ColourDepth::Indexed,
+        Ok(term)
+            if BASIC_COLOUR_TERMS.contains(&term.as_str())
+                || term.ends_with("-16color")
+                || term.ends_with("-8color") =>
+        {
+            ColourDepth::Basic
+        }
+        _ => ColourDepth::TrueColour,
+    }
+}
+
+/// Returns the closest colour to `colour` that can be shown with `colour_depth`.
+pub fn downsample_colour(colour: Color, colour_depth: &ColourDepth) -> Color {
+    match (colour_depth, colour) {
+        (ColourDepth::Indexed, Color::Rgb(r, g, b)) => Color::Indexed(get_closest_indexed(r, g, b)),
+        (ColourDepth::Basic, Color::Rgb(r, g, b)) => get_closest_basic(r, g, b),
+        (ColourDepth::Basic, Color::Indexed(index)) => {
+            if let Some((basic_colour, _rgb)) = BASIC_COLOURS.get(usize::from(index)) {
+                *basic_colour
+            } else {
+                let (r, g, b) = get_indexed_rgb(index);
+                get_closest_basic(r, g, b)
+            }
+        }
+        _ => colour,
+    }
+}
+
+/// Returns `style` with its foreground and background downsampled to `colour_depth`.
+pub fn downsample_style(style: Style, colour_depth: &ColourDepth) -> Style {
+    Style {
+        fg: downsample_colour(style.fg, colour_depth),
+        bg: downsample_colour(style.bg, colour_depth),
+        ..style
+    }
+}
+
+fn get_distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> i32 {
+    let (dr, dg, db) = (
+        i32::from(r1) - i32::from(r2),
+        i32::from(g1) - i32::from(g2),
+        i32::from(b1) - i32::from(b2),
+    );
+    dr * dr + dg * dg + db * db
+}
+
+fn get_closest_basic(r: u8, g: u8, b: u8) -> Color {
+    BASIC_COLOURS
+        .iter()
+        .min_by_key(|(_colour, rgb)| get_distance(*rgb, (r, g, b)))
+        .map(|(colour, _rgb)| *colour)
+        .unwrap_or(Color::Reset)
+}
+
+/// Returns the index of the closest colour in either the colour cube or the greyscale ramp.
+fn get_closest_indexed(r: u8, g: u8, b: u8) -> u8 {
+    let get_cube_level = |value: u8| -> u8 {
+        CUBE_LEVELS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_index, level)| (i32::from(**level) - i32::from(value)).abs())
+            .map(|(index, _level)| index as u8)
+            .unwrap_or(0)
+    };
+    let cube_index = 16 + 36 * get_cube_level(r) + 6 * get_cube_level(g) + get_cube_level(b);
+
+    let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let grey_index = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+
+    if get_distance(get_indexed_rgb(grey_index), (r, g, b))
+        < get_distance(get_indexed_rgb(cube_index), (r, g, b))
+    {
+        grey_index
+    } else {
+        cube_index
+    }
+}
+
+fn get_indexed_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => BASIC_COLOURS[usize::from(index)].1,
+        16..=231 => {
+            let cube_index = usize::from(index - 16);
+            (
+                CUBE_LEVELS[cube_index / 36],
+                CUBE_LEVELS[cube_index / 6 % 6],
+                CUBE_LEVELS[cube_index % 6],
+            )
+        }
+        _ => {
+            let grey = 8 + 10 * (index - 232);
+            (grey, grey, grey)
+        }
+    }
+}
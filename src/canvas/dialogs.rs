@@ -1,5 +1,7 @@
 pub mod dd_dialog;
 pub mod help_dialog;
+pub mod message_dialog;
 
 pub use dd_dialog::KillDialog;
 pub use help_dialog::HelpDialog;
+pub use message_dialog::MessageDialog;
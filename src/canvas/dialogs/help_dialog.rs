@@ -3,14 +3,13 @@ use unicode_width::UnicodeWidthStr;
 use tui::{
     backend::Backend,
     layout::{Alignment, Rect},
+    style::Style,
     terminal::Frame,
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Paragraph, Text},
 };
 
 use crate::{app::App, canvas::Painter, constants};
 
-const HELP_BASE: &str = " Help ── Esc to close ";
-
 pub trait HelpDialog {
     fn draw_help_dialog<B: Backend>(
         &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect,
@@ -31,54 +30,115 @@ impl HelpDialog for Painter {
         //     self.colours.border_style,
         // );
 
+        let search_query = app_state.help_dialog_state.search_query.clone();
+        let is_searching = app_state.help_dialog_state.is_searching;
+        let (help_title_start, help_title_end) = if is_searching || !search_query.is_empty() {
+            (
+                format!(
+                    " Help ── Search: {}{} ",
+                    search_query,
+                    if is_searching { "_" } else { "" }
+                ),
+                " Esc to clear ",
+            )
+        } else {
+            (" Help ".to_string(), " Esc to close ")
+        };
         let help_title = format!(
-            " Help ─{}─ Esc to close ",
-            "─".repeat(usize::from(draw_loc.width).saturating_sub(HELP_BASE.chars().count() + 2))
+            "{}─{}─{}",
+            help_title_start,
+            "─".repeat(usize::from(draw_loc.width).saturating_sub(
+                help_title_start.chars().count() + help_title_end.chars().count() + 4
+            )),
+            help_title_end
         );
 
+        let filtered_help_lines = if search_query.is_empty() {
+            None
+        } else {
+            let matching_lines = get_matching_help_lines(&search_query);
+            Some(if matching_lines.is_empty() {
+                vec![(
+                    format!("No bindings match \"{}\".", search_query),
+                    self.colours.text_style,
+                )]
+            } else {
+                matching_lines
+                    .into_iter()
+                    .map(|(is_header, line)| {
+                        (
+                            line.to_string(),
+                            if is_header {
+                                self.colours.table_header_style
+                            } else {
+                                self.colours.text_style
+                            },
+                        )
+                    })
+                    .collect::<Vec<(String, Style)>>()
+            })
+        };
+
         if app_state.should_get_widget_bounds() {
-            // We must also recalculate how many lines are wrapping to properly get scrolling to work on
-            // small terminal sizes... oh joy.
-
-            let mut overflow_buffer = 0;
-            let paragraph_width = std::cmp::max(draw_loc.width.saturating_sub(2), 1);
-            let mut prev_section_len = 0;
-
-            constants::HELP_TEXT
-                .iter()
-                .enumerate()
-                .for_each(|(itx, section)| {
-                    let mut buffer = 0;
-
-                    if itx == 0 {
-                        section.iter().for_each(|text_line| {
-                            buffer += UnicodeWidthStr::width(*text_line).saturating_sub(1) as u16
-                                / paragraph_width;
-                        });
-
-                        app_state.help_dialog_state.index_shortcuts[itx] = 0;
-                        prev_section_len = section.len() as u16 + buffer;
-                        overflow_buffer += buffer;
-                    } else {
-                        section.iter().for_each(|text_line| {
-                            buffer += UnicodeWidthStr::width(*text_line).saturating_sub(1) as u16
-                                / paragraph_width;
-                        });
-
-                        app_state.help_dialog_state.index_shortcuts[itx] =
-                            app_state.help_dialog_state.index_shortcuts[itx - 1]
-                                + 1
-                                + prev_section_len;
-                        prev_section_len = section.len() as u16 + buffer;
-                        overflow_buffer += buffer;
-                    }
-                });
-
-            app_state.help_dialog_state.scroll_state.max_scroll_index =
-                (self.styled_help_text.len() as u16
-                    + (constants::HELP_TEXT.len() as u16 - 5)
-                    + overflow_buffer)
-                    .saturating_sub(draw_loc.height);
+            if let Some(filtered_help_lines) = &filtered_help_lines {
+                // Every filtered line is its own row, so only wrapping needs to be accounted for.
+                let paragraph_width = std::cmp::max(draw_loc.width.saturating_sub(2), 1);
+                let row_count = filtered_help_lines
+                    .iter()
+                    .map(|(line, _)| {
+                        1 + UnicodeWidthStr::width(line.as_str()).saturating_sub(1) as u16
+                            / paragraph_width
+                    })
+                    .sum::<u16>();
+
+                app_state.help_dialog_state.scroll_state.max_scroll_index =
+                    (row_count + 1).saturating_sub(draw_loc.height.saturating_sub(2));
+            } else {
+                // We must also recalculate how many lines are wrapping to properly get scrolling to work on
+                // small terminal sizes... oh joy.
+
+                let mut overflow_buffer = 0;
+                let paragraph_width = std::cmp::max(draw_loc.width.saturating_sub(2), 1);
+                let mut prev_section_len = 0;
+
+                constants::HELP_TEXT
+                    .iter()
+                    .enumerate()
+                    .for_each(|(itx, section)| {
+                        let mut buffer = 0;
+
+                        if itx == 0 {
+                            section.iter().for_each(|text_line| {
+                                buffer += UnicodeWidthStr::width(*text_line).saturating_sub(1)
+                                    as u16
+                                    / paragraph_width;
+                            });
+
+                            app_state.help_dialog_state.index_shortcuts[itx] = 0;
+                            prev_section_len = section.len() as u16 + buffer;
+                            overflow_buffer += buffer;
+                        } else {
+                            section.iter().for_each(|text_line| {
+                                buffer += UnicodeWidthStr::width(*text_line).saturating_sub(1)
+                                    as u16
+                                    / paragraph_width;
+                            });
+
+                            app_state.help_dialog_state.index_shortcuts[itx] =
+                                app_state.help_dialog_state.index_shortcuts[itx - 1]
+                                    + 1
+                                    + prev_section_len;
+                            prev_section_len = section.len() as u16 + buffer;
+                            overflow_buffer += buffer;
+                        }
+                    });
+
+                app_state.help_dialog_state.scroll_state.max_scroll_index =
+                    (self.styled_help_text.len() as u16
+                        + (constants::HELP_TEXT.len() as u16 - 5)
+                        + overflow_buffer)
+                        .saturating_sub(draw_loc.height);
+            }
 
             // Fix if over-scrolled
             if app_state
@@ -98,26 +158,64 @@ impl HelpDialog for Painter {
             }
         }
 
+        let filtered_help_text = filtered_help_lines.map(|filtered_help_lines| {
+            filtered_help_lines
+                .into_iter()
+                .map(|(line, style)| Text::styled(format!("{}\n", line), style))
+                .collect::<Vec<_>>()
+        });
+
         f.render_widget(
-            Paragraph::new(self.styled_help_text.iter())
-                .block(
-                    Block::default()
-                        .title(&help_title)
-                        .title_style(self.colours.border_style)
-                        .style(self.colours.border_style)
-                        .borders(Borders::ALL)
-                        .border_style(self.colours.border_style),
-                )
-                .style(self.colours.text_style)
-                .alignment(Alignment::Left)
-                .wrap(true)
-                .scroll(
-                    app_state
-                        .help_dialog_state
-                        .scroll_state
-                        .current_scroll_index,
-                ),
+            Paragraph::new(
+                filtered_help_text
+                    .as_ref()
+                    .unwrap_or(&self.styled_help_text)
+                    .iter(),
+            )
+            .block(
+                Block::default()
+                    .title(&help_title)
+                    .title_style(self.colours.border_style)
+                    .style(self.colours.border_style)
+                    .borders(Borders::ALL)
+                    .border_style(self.colours.border_style),
+            )
+            .style(self.colours.text_style)
+            .alignment(Alignment::Left)
+            .wrap(true)
+            .scroll(
+                app_state
+                    .help_dialog_state
+                    .scroll_state
+                    .current_scroll_index,
+            ),
             draw_loc,
         );
     }
 }
+
+/// Returns the lines of every help section besides the contents that contain the query,
+/// ignoring case.  Each line is paired with whether it is the header of its section, and
+/// sections without any matching lines are left out entirely.
+fn get_matching_help_lines(query: &str) -> Vec<(bool, &'static str)> {
+    let query = query.to_lowercase();
+    let mut matching_lines = Vec::new();
+
+    constants::HELP_TEXT.iter().skip(1).for_each(|section| {
+        let section_matches = section[1..]
+            .iter()
+            .map(|line| line.trim_end())
+            .filter(|line| line.to_lowercase().contains(&query))
+            .collect::<Vec<_>>();
+
+        if !section_matches.is_empty() {
+            if !matching_lines.is_empty() {
+                matching_lines.push((false, ""));
+            }
+            matching_lines.push((true, section[0].trim_end()));
+            matching_lines.extend(section_matches.into_iter().map(|line| (false, line)));
+        }
+    });
+
+    matching_lines
+}
@@ -0,0 +1,76 @@
+use std::time::Instant;
+
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Rect},
+    terminal::Frame,
+    widgets::{Block, Borders, Clear, Paragraph, Text},
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    app::{App, AppMessageDialogState},
+    canvas::Painter,
+    constants::MESSAGE_TIMEOUT_MILLISECONDS,
+};
+
+const QUIT_PROMPT_TEXT: &str = "Quit? Press y to confirm, or any other key to cancel";
+
+pub trait MessageDialog {
+    fn draw_message_dialog<B: Backend>(&self, f: &mut Frame<'_, B>, app_state: &mut App);
+
+    fn draw_quit_prompt<B: Backend>(&self, f: &mut Frame<'_, B>, app_state: &App);
+
+    fn draw_bottom_popup<B: Backend>(&self, f: &mut Frame<'_, B>, message: &str);
+}
+
+impl MessageDialog for Painter {
+    fn draw_message_dialog<B: Backend>(&self, f: &mut Frame<'_, B>, app_state: &mut App) {
+        let is_expired = match app_state.message_dialog_state.shown_at {
+            Some(shown_at) => {
+                Instant::now().duration_since(shown_at).as_millis()
+                    >= MESSAGE_TIMEOUT_MILLISECONDS as u128
+            }
+            None => return,
+        };
+
+        if is_expired {
+            app_state.message_dialog_state = AppMessageDialogState::default();
+        } else if let Some(message) = &app_state.message_dialog_state.message {
+            self.draw_bottom_popup(f, message);
+        }
+    }
+
+    fn draw_quit_prompt<B: Backend>(&self, f: &mut Frame<'_, B>, app_state: &App) {
+        // Unlike messages, the prompt stays until a key is pressed.
+        if app_state.is_showing_quit_prompt {
+            self.draw_bottom_popup(f, QUIT_PROMPT_TEXT);
+        }
+    }
+
+    /// Draws a small popup along the bottom of the screen.
+    fn draw_bottom_popup<B: Backend>(&self, f: &mut Frame<'_, B>, message: &str) {
+        let width = std::cmp::min(message.width() as u16 + 4, f.size().width);
+        let height = std::cmp::min(3, f.size().height);
+        let draw_loc = Rect::new(
+            (f.size().width - width) / 2,
+            f.size().height.saturating_sub(height + 1),
+            width,
+            height,
+        );
+
+        f.render_widget(Clear, draw_loc);
+        f.render_widget(
+            Paragraph::new([Text::styled(message, self.colours.text_style)].iter())
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(self.colours.highlighted_border_style),
+                )
+                .alignment(Alignment::Center),
+            draw_loc,
+        );
+        #[cfg(feature = "pixel-graphs")]
+        self.pixel_graphs.cover(draw_loc);
+    }
+}
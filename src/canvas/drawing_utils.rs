@@ -163,3 +163,30 @@ pub fn calculate_basic_use_bars(use_percentage: f64, num_bars_available: usize)
         num_bars_available,
     )
 }
+
+/// Returns the labels for the start, middle, and end of a graph's time axis, relative to now.
+pub fn get_time_axis_labels(display_time: u64, pan_offset: u64) -> [String; 3] {
+    let format_time = |time: u64| {
+        if time == 0 {
+            "now".to_string()
+        } else {
+            format!("-{}s", time / 1000)
+        }
+    };
+
+    [
+        format_time(display_time + pan_offset),
+        format_time(display_time / 2 + pan_offset),
+        format_time(pan_offset),
+    ]
+}
+
+/// Returns the indicator shown in a graph's title, which is "LIVE" unless the graph
+/// has been panned back in time, in which case it is how far back the view ends.
+pub fn get_pan_indicator(pan_offset: u64) -> String {
+    if pan_offset == 0 {
+        "LIVE".to_string()
+    } else {
+        format!("-{}s", pan_offset / 1000)
+    }
+}
@@ -0,0 +1,349 @@
+use std::{borrow::Cow, cmp::max};
+
+use tui::{
+    backend::Backend,
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    symbols::Marker,
+    widgets::{Dataset, GraphType, Widget},
+    Frame,
+};
+use unicode_width::UnicodeWidthStr;
+
+use super::{GraphMarker, Painter, Point};
+
+const UPPER_HALF_BLOCK: &str = "▀";
+const LOWER_HALF_BLOCK: &str = "▄";
+const FULL_BLOCK: &str = "█";
+
+/// How many columns of pixels an image is assumed to have per cell, which only decides how far
+/// its series are downsampled.
+#[cfg(feature = "pixel-graphs")]
+const PIXEL_COLUMNS_PER_CELL: usize = 8;
+
+/// The series of a graph.  Charts can only draw braille, dots, or whole blocks, so when using
+/// [`GraphMarker::HalfBlock`] or [`GraphMarker::Pixels`], the datasets are left without points
+/// and the points are instead drawn separately with [`GraphSeries::draw_points`].
+pub struct GraphSeries<'a> {
+    graph_marker: GraphMarker,
+    is_raw: bool,
+    is_y_inverted: bool,
+    series: Vec<(Cow<'a, str>, Style, Cow<'a, [Point]>)>,
+}
+
+impl<'a> GraphSeries<'a> {
+    /// Unless `is_raw` is set, series with more points than the graph has columns are
+    /// downsampled in [`GraphSeries::draw_points`].
+    pub fn new(graph_marker: &GraphMarker, is_raw: bool) -> Self {
+        GraphSeries {
+            graph_marker: graph_marker.clone(),
+            is_raw,
+            is_y_inverted: false,
+            series: Vec::new(),
+        }
+    }
+
+    /// Flips the series vertically in [`GraphSeries::draw_points`], so that the graph fills from
+    /// the top.  The chart's y-axis labels must be given in reverse to match.
+    pub fn invert_y(&mut self, is_y_inverted: bool) {
+        self.is_y_inverted = is_y_inverted;
+    }
+
+    /// Adds a series, which is drawn over any series added before it.
+    pub fn push<S: Into<Cow<'a, str>>>(&mut self, name: S, style: Style, data: &'a [Point]) {
+        self.series.push((name.into(), style, Cow::Borrowed(data)));
+    }
+
+    /// Returns the datasets to give the chart.
+    pub fn datasets(&self) -> Vec<Dataset<'_>> {
+        self.series
+            .iter()
+            .map(|(name, style, data)| {
+                let dataset = Dataset::default()
+                    .name(name.as_ref())
+                    .style(*style)
+                    .graph_type(GraphType::Line);
+
+                match self.graph_marker {
+                    GraphMarker::Braille => dataset.marker(Marker::Braille).data(&data[..]),
+                    GraphMarker::Dot => dataset.marker(Marker::Dot).data(&data[..]),
+                    GraphMarker::HalfBlock => dataset,
+                    #[cfg(feature = "pixel-graphs")]
+                    GraphMarker::Pixels(_) => dataset,
+                }
+            })
+            .collect()
+    }
+
+    /// Downsamples the series to the width of the graph and flips them if inverted, then draws
+    /// the points as half blocks, or queues them to be drawn as an image, if needed.  This must
+    /// be done before drawing the chart in `chart_area` with the same labels, so that its legend
+    /// is drawn over the points.  Images are queued on the painter.
+    #[cfg_attr(not(feature = "pixel-graphs"), allow(unused_variables))]
+    pub fn draw_points<B: Backend, S: AsRef<str>>(
+        &mut self, f: &mut Frame<'_, B>, painter: &Painter, chart_area: Rect,
+        x_labels: Option<&[String]>, y_labels: &[S], x_bounds: [f64; 2], y_bounds: [f64; 2],
+    ) {
+        let graph_area = get_chart_graph_area(chart_area, x_labels, y_labels);
+
+        if let (Some(graph_area), false) = (graph_area, self.is_raw) {
+            let columns_per_cell = match self.graph_marker {
+                GraphMarker::Braille => 2,
+                GraphMarker::Dot | GraphMarker::HalfBlock => 1,
+                #[cfg(feature = "pixel-graphs")]
+                GraphMarker::Pixels(_) => PIXEL_COLUMNS_PER_CELL,
+            };
+            let bucket_count = usize::from(graph_area.width) * columns_per_cell;
+            for (_name, _style, data) in &mut self.series {
+                if let Some(downsampled) = downsample(data, x_bounds, bucket_count) {
+                    *data = Cow::Owned(downsampled);
+                }
+            }
+        }
+
+        // The chart draws braille and dots from the same points, so they are flipped even if
+        // there is no room to draw them here.
+        if self.is_y_inverted {
+            for (_name, _style, data) in &mut self.series {
+                *data = Cow::Owned(
+                    data.iter()
+                        .map(|(time, value)| (*time, y_bounds[0] + y_bounds[1] - value))
+                        .collect(),
+                );
+            }
+        }
+
+        let graph_area = match graph_area {
+            Some(graph_area) => graph_area,
+            None => return,
+        };
+
+        let separate_series = self
+            .series
+            .iter()
+            .map(|(_name, style, data)| (data.as_ref(), style.fg))
+            .collect::<Vec<_>>();
+        match self.graph_marker {
+            GraphMarker::HalfBlock => f.render_widget(
+                HalfBlockGraph {
+                    series: &separate_series,
+                    x_bounds,
+                    y_bounds,
+                },
+                graph_area,
+            ),
+            #[cfg(feature = "pixel-graphs")]
+            GraphMarker::Pixels(_) => {
+                painter
+                    .pixel_graphs
+                    .queue(graph_area, &separate_series, x_bounds, y_bounds)
+            }
+            GraphMarker::Braille | GraphMarker::Dot => {}
+        }
+    }
+}
+
+/// Splits the points within `x_bounds` into `bucket_count` equal slices of time and keeps only
+/// the first, last, lowest, and highest point of each, in their original order, which draws the
+/// same line as all of the points at that resolution, spikes included.  The closest points
+/// outside of the bounds are kept so the line still reaches the edges.  Returns [`None`] if
+/// there are already few enough points.
+fn downsample(points: &[Point], x_bounds: [f64; 2], bucket_count: usize) -> Option<Vec<Point>> {
+    if bucket_count == 0 || points.len() <= bucket_count * 4 || x_bounds[1] <= x_bounds[0] {
+        return None;
+    }
+
+    let start = points
+        .iter()
+        .position(|(time, _value)| *time >= x_bounds[0])
+        .unwrap_or_else(|| points.len());
+    let end = points
+        .iter()
+        .rposition(|(time, _value)| *time <= x_bounds[1])
+        .map_or(0, |index| index + 1)
+        .max(start);
+    let get_bucket = |time: f64| -> usize {
+        let bucket = (time - x_bounds[0]) / (x_bounds[1] - x_bounds[0]) * bucket_count as f64;
+        (bucket as usize).min(bucket_count - 1)
+    };
+
+    let mut downsampled = Vec::with_capacity(bucket_count * 4 + 2);
+    if start > 0 {
+        downsampled.push(points[start - 1]);
+    }
+
+    let mut bucket_start = start;
+    while bucket_start < end {
+        let bucket = get_bucket(points[bucket_start].0);
+        let mut bucket_end = bucket_start + 1;
+        let (mut lowest, mut highest) = (bucket_start, bucket_start);
+        while bucket_end < end && get_bucket(points[bucket_end].0) == bucket {
+            if points[bucket_end].1 < points[lowest].1 {
+                lowest = bucket_end;
+            }
+            if points[bucket_end].1 > points[highest].1 {
+                highest = bucket_end;
+            }
+            bucket_end += 1;
+        }
+
+        let mut kept = [bucket_start, lowest, highest, bucket_end - 1];
+        kept.sort_unstable();
+        let mut previous = None;
+        for index in kept.iter() {
+            if previous != Some(*index) {
+                downsampled.push(points[*index]);
+                previous = Some(*index);
+            }
+        }
+        bucket_start = bucket_end;
+    }
+
+    if end < points.len() {
+        downsampled.push(points[end]);
+    }
+
+    Some(downsampled)
+}
+
+/// Draws lines through the points of each series using the upper and lower halves of each cell,
+/// for twice the vertical resolution of whole blocks in fonts that can't show braille well.
+struct HalfBlockGraph<'a> {
+    /// Later series are drawn over earlier ones.
+    series: &'a [(&'a [Point], Color)],
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+}
+
+impl<'a> Widget for HalfBlockGraph<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.width == 0
+            || area.height == 0
+            || self.x_bounds[1] <= self.x_bounds[0]
+            || self.y_bounds[1] <= self.y_bounds[0]
+        {
+            return;
+        }
+
+        let width = usize::from(area.width);
+        let pixel_height = usize::from(area.height) * 2;
+        let mut pixels: Vec<Option<Color>> = vec![None; width * pixel_height];
+
+        let to_pixel = |(time, value): Point| -> (f64, f64) {
+            (
+                (time - self.x_bounds[0]) / (self.x_bounds[1] - self.x_bounds[0])
+                    * (width - 1) as f64,
+                (self.y_bounds[1] - value) / (self.y_bounds[1] - self.y_bounds[0])
+                    * (pixel_height - 1) as f64,
+            )
+        };
+        let mut set_pixel = |(col, row): (f64, f64), colour: Color| {
+            let (col, row) = (col.round(), row.round());
+            if col >= 0.0 && row >= 0.0 && (col as usize) < width && (row as usize) < pixel_height {
+                pixels[row as usize * width + col as usize] = Some(colour);
+            }
+        };
+
+        for (points, colour) in self.series {
+            if let [point] = points {
+                set_pixel(to_pixel(*point), *colour);
+            }
+
+            for segment in points.windows(2) {
+                let (start, end) = (to_pixel(segment[0]), to_pixel(segment[1]));
+                if (start.0 < 0.0 && end.0 < 0.0)
+                    || (start.0 > (width - 1) as f64 && end.0 > (width - 1) as f64)
+                {
+                    continue;
+                }
+
+                // Step once per pixel along the longer direction so the line has no gaps.
+                let step_count = (end.0 - start.0)
+                    .abs()
+                    .max((end.1 - start.1).abs())
+                    .ceil()
+                    .max(1.0) as usize;
+                for step in 0..=step_count {
+                    let fraction = step as f64 / step_count as f64;
+                    set_pixel(
+                        (
+                            start.0 + (end.0 - start.0) * fraction,
+                            start.1 + (end.1 - start.1) * fraction,
+                        ),
+                        *colour,
+                    );
+                }
+            }
+        }
+
+        for row in 0..usize::from(area.height) {
+            for col in 0..width {
+                let (symbol, colour) = match (
+                    pixels[row * 2 * width + col],
+                    pixels[(row * 2 + 1) * width + col],
+                ) {
+                    (Some(upper), Some(_)) => (FULL_BLOCK, upper),
+                    (Some(upper), None) => (UPPER_HALF_BLOCK, upper),
+                    (None, Some(lower)) => (LOWER_HALF_BLOCK, lower),
+                    (None, None) => continue,
+                };
+
+                buf.get_mut(area.left() + col as u16, area.top() + row as u16)
+                    .set_symbol(symbol)
+                    .set_fg(colour);
+            }
+        }
+    }
+}
+
+/// Returns where a chart drawn in `chart_area` (inside of its block) puts its points, which
+/// follows how tui places the axes and their labels.
+fn get_chart_graph_area<S: AsRef<str>>(
+    chart_area: Rect, x_labels: Option<&[String]>, y_labels: &[S],
+) -> Option<Rect> {
+    if chart_area.width == 0 || chart_area.height == 0 {
+        return None;
+    }
+
+    let mut x = chart_area.left();
+    let mut y = chart_area.bottom() - 1;
+
+    if x_labels.is_some() && y > chart_area.top() {
+        y -= 1;
+    }
+
+    let mut label_width = y_labels
+        .iter()
+        .map(|label| UnicodeWidthStr::width(label.as_ref()))
+        .max()
+        .unwrap_or(0) as u16;
+    if let Some(first_x_label) = x_labels.and_then(|x_labels| x_labels.first()) {
+        label_width = max(
+            label_width,
+            UnicodeWidthStr::width(first_x_label.as_str()) as u16,
+        );
+    }
+    if x + label_width < chart_area.right() {
+        x += label_width;
+    }
+
+    if x_labels.is_some() && y > chart_area.top() {
+        y -= 1;
+    }
+    if x + 1 < chart_area.right() {
+        x += 1;
+    }
+
+    if x < chart_area.right() && y > 1 {
+        Some(Rect::new(
+            x,
+            chart_area.top(),
+            chart_area.right() - x,
+            y - chart_area.top() + 1,
+        ))
+    } else {
+        None
+    }
+}
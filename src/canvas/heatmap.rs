@@ -0,0 +1,90 @@
+use tui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::Widget,
+};
+
+use super::Point;
+
+const HEATMAP_CELL: &str = "█";
+
+/// A grid where each row is a series, each column is a slice of time within `x_bounds`, and
+/// each cell is coloured by the average value of its series over that slice.
+pub struct Heatmap<'a, F: Fn(f64) -> Color> {
+    /// The label and points of each row.  Points must be sorted by time.
+    pub rows: Vec<(&'a str, &'a [Point])>,
+    pub x_bounds: [f64; 2],
+    pub label_style: Style,
+    pub colour_fn: F,
+}
+
+impl<'a, F: Fn(f64) -> Color> Widget for Heatmap<'a, F> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        let label_width = self
+            .rows
+            .iter()
+            .map(|(label, _)| label.chars().count() as u16 + 1)
+            .max()
+            .unwrap_or(0)
+            .min(area.width / 2);
+        let cell_count = area.width.saturating_sub(label_width);
+        if cell_count == 0 {
+            return;
+        }
+        let cell_duration = (self.x_bounds[1] - self.x_bounds[0]) / f64::from(cell_count);
+
+        for (row, (label, points)) in self.rows.iter().take(area.height as usize).enumerate() {
+            let y = area.top() + row as u16;
+            buf.set_stringn(
+                area.left(),
+                y,
+                label,
+                label_width.saturating_sub(1) as usize,
+                self.label_style,
+            );
+
+            let mut point_index = 0;
+            let mut last_value = None;
+            while let Some((time, value)) = points.get(point_index) {
+                if *time >= self.x_bounds[0] {
+                    break;
+                }
+                last_value = Some(*value);
+                point_index += 1;
+            }
+
+            for cell in 0..cell_count {
+                let cell_end = self.x_bounds[0] + cell_duration * f64::from(cell + 1);
+
+                let (mut sum, mut count) = (0.0, 0);
+                while let Some((time, value)) = points.get(point_index) {
+                    if *time > cell_end {
+                        break;
+                    }
+                    sum += value;
+                    count += 1;
+                    last_value = Some(*value);
+                    point_index += 1;
+                }
+
+                // Cells that fall between two samples take on the earlier sample, while
+                // cells before the first or after the last sample are left empty.
+                let value = if count > 0 {
+                    Some(sum / f64::from(count))
+                } else if point_index < points.len() {
+                    last_value
+                } else {
+                    None
+                };
+
+                if let Some(value) = value {
+                    buf.get_mut(area.left() + label_width + cell, y)
+                        .set_symbol(HEATMAP_CELL)
+                        .set_fg((self.colour_fn)(value));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,535 @@
+//! Graphs drawn as actual images using the sixel or kitty graphics protocols, for terminals
+//! that support either.  Only the final drawing differs from the other markers; the points
+//! are the same ones given to the charts.  Images can't be put in tui's buffer, so they are
+//! queued while drawing and written straight to the terminal once tui has flushed.
+
+use std::{
+    cell::RefCell,
+    cmp::{max, min},
+    env,
+    io::{self, Write},
+};
+
+use tui::{buffer::Buffer, layout::Rect, style::Color, widgets::Widget};
+
+use super::Point;
+
+/// The cell size assumed if the terminal doesn't report its size in pixels.
+const FALLBACK_CELL_SIZE: (usize, usize) = (10, 20);
+
+/// The most data sent in one kitty graphics escape sequence, as required by the protocol.
+const KITTY_CHUNK_SIZE: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageProtocol {
+    Sixel,
+    Kitty,
+}
+
+/// Guesses which image protocol the terminal supports from its environment variables, as
+/// querying the terminal would race with reading input.  Always returns [`None`] if running
+/// inside a multiplexer.
+pub fn detect_image_protocol() -> Option<ImageProtocol> {
+    if env::var_os("TMUX").is_some() {
+        return None;
+    }
+
+    let term = env::var("TERM").unwrap_or_default();
+    let term_program = env::var("TERM_PROGRAM").unwrap_or_default();
+
+    if env::var_os("KITTY_WINDOW_ID").is_some()
+        || term.contains("kitty")
+        || term.contains("ghostty")
+        || term_program == "ghostty"
+    {
+        Some(ImageProtocol::Kitty)
+    } else if term.starts_with("foot")
+        || term.contains("mlterm")
+        || term.contains("sixel")
+        || term_program == "WezTerm"
+        || term_program == "iTerm.app"
+        || env::var_os("CONTOUR_PROFILE").is_some()
+    {
+        Some(ImageProtocol::Sixel)
+    } else {
+        None
+    }
+}
+
+/// A graph waiting to be written once the rest of the frame has been drawn.
+struct PendingGraph {
+    area: Rect,
+    series: Vec<(Vec<Point>, Color)>,
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+    /// Whether each cell of the area has something drawn in it, such as a legend, which the
+    /// image must leave uncovered.  Filled in by [`PixelGraphMask`].
+    covered_cells: Vec<bool>,
+}
+
+/// The graphs drawn as images in the current frame.
+#[derive(Default)]
+pub struct PixelGraphs {
+    pending: RefCell<Vec<PendingGraph>>,
+    /// Areas drawn over the graphs by popups, which can be blank but must still stay clear.
+    covers: RefCell<Vec<Rect>>,
+    last_areas: Vec<Rect>,
+}
+
+impl PixelGraphs {
+    /// Forgets anything queued in a previous frame.  Call this before drawing.
+    pub fn start_frame(&mut self) {
+        self.pending.borrow_mut().clear();
+        self.covers.borrow_mut().clear();
+    }
+
+    /// Queues the series to be drawn as one image filling `graph_area`.
+    pub fn queue(
+        &self, graph_area: Rect, series: &[(&[Point], Color)], x_bounds: [f64; 2],
+        y_bounds: [f64; 2],
+    ) {
+        self.pending.borrow_mut().push(PendingGraph {
+            area: graph_area,
+            series: series
+                .iter()
+                .map(|(points, colour)| (points.to_vec(), *colour))
+                .collect(),
+            x_bounds,
+            y_bounds,
+            covered_cells: Vec::new(),
+        });
+    }
+
+    /// Keeps images out of an area that is drawn over the graphs.
+    pub fn cover(&self, area: Rect) {
+        self.covers.borrow_mut().push(area);
+    }
+
+    /// Returns a widget that finds which cells of each queued graph have been drawn over.
+    /// Render it over the whole frame after everything else.
+    pub fn mask(&self) -> PixelGraphMask<'_> {
+        PixelGraphMask { pixel_graphs: self }
+    }
+
+    /// Writes the queued graphs to the terminal.  Returns whether the whole screen has to be
+    /// redrawn, which happens when sixels were moved or removed, since their old pixels stay
+    /// on screen until something is drawn over them.
+    pub fn flush(&mut self, protocol: ImageProtocol) -> io::Result<bool> {
+        let pending = self.pending.borrow();
+        let areas = pending.iter().map(|graph| graph.area).collect::<Vec<_>>();
+        if protocol == ImageProtocol::Sixel
+            && !self.last_areas.is_empty()
+            && areas != self.last_areas
+        {
+            self.last_areas = Vec::new();
+            return Ok(true);
+        }
+
+        let (cell_width, cell_height) = match protocol {
+            // Kitty scales images to fit the cells they are placed in, so save on what is sent
+            // every frame by drawing at half the resolution.
+            ImageProtocol::Kitty => {
+                let (cell_width, cell_height) = get_cell_size();
+                (max(cell_width / 2, 2), max(cell_height / 2, 4))
+            }
+            ImageProtocol::Sixel => get_cell_size(),
+        };
+
+        let mut output = Vec::new();
+        if protocol == ImageProtocol::Kitty {
+            // Deletes every image placed in the previous frame.
+            output.extend_from_slice(b"\x1b_Ga=d,d=A,q=2\x1b\\");
+        }
+        for graph in pending.iter() {
+            let image = rasterize(graph, cell_width, cell_height);
+            match protocol {
+                ImageProtocol::Kitty => write_kitty_image(&mut output, graph, &image),
+                ImageProtocol::Sixel => write_sixel_image(&mut output, graph, &image),
+            }
+        }
+
+        let mut stdout = io::stdout();
+        stdout.write_all(&output)?;
+        stdout.flush()?;
+
+        self.last_areas = areas;
+        Ok(false)
+    }
+}
+
+/// A "widget" that doesn't draw anything, but records which cells of the queued graphs
+/// already have something drawn in them.
+pub struct PixelGraphMask<'a> {
+    pixel_graphs: &'a PixelGraphs,
+}
+
+impl<'a> Widget for PixelGraphMask<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let covers = self.pixel_graphs.covers.borrow();
+        for graph in self.pixel_graphs.pending.borrow_mut().iter_mut() {
+            let graph_area = graph.area.intersection(area).intersection(buf.area);
+            graph.area = graph_area;
+            graph.covered_cells = (graph_area.top()..graph_area.bottom())
+                .flat_map(|y| (graph_area.left()..graph_area.right()).map(move |x| (x, y)))
+                .map(|(x, y)| {
+                    buf.get(x, y).symbol != " "
+                        || covers
+                            .iter()
+                            .any(|cover| cover.intersects(Rect::new(x, y, 1, 1)))
+                })
+                .collect();
+        }
+    }
+}
+
+/// An image where each pixel is either transparent or a colour.
+struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Option<(u8, u8, u8)>>,
+}
+
+/// Returns the size of each cell in pixels, as reported by the terminal.
+#[cfg(unix)]
+fn get_cell_size() -> (usize, usize) {
+    let mut window_size = libc::winsize {
+        ws_row: 0,
+        ws_col: 0,
+        ws_xpixel: 0,
+        ws_ypixel: 0,
+    };
+    let result = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut window_size) };
+
+    if result == 0
+        && window_size.ws_row > 0
+        && window_size.ws_col > 0
+        && window_size.ws_xpixel > 0
+        && window_size.ws_ypixel > 0
+    {
+        (
+            usize::from(window_size.ws_xpixel / window_size.ws_col),
+            usize::from(window_size.ws_ypixel / window_size.ws_row),
+        )
+    } else {
+        FALLBACK_CELL_SIZE
+    }
+}
+
+#[cfg(not(unix))]
+fn get_cell_size() -> (usize, usize) {
+    FALLBACK_CELL_SIZE
+}
+
+/// Draws the lines of a graph, leaving the pixels of covered cells transparent.
+fn rasterize(graph: &PendingGraph, cell_width: usize, cell_height: usize) -> Image {
+    let width = usize::from(graph.area.width) * cell_width;
+    let height = usize::from(graph.area.height) * cell_height;
+    let mut pixels = vec![None; width * height];
+    let (x_bounds, y_bounds) = (graph.x_bounds, graph.y_bounds);
+
+    if width == 0 || height == 0 || x_bounds[1] <= x_bounds[0] || y_bounds[1] <= y_bounds[0] {
+        return Image {
+            width,
+            height,
+            pixels,
+        };
+    }
+
+    let line_width = max(cell_height / 10, 1) as isize;
+    let to_pixel = |(time, value): Point| -> (f64, f64) {
+        (
+            (time - x_bounds[0]) / (x_bounds[1] - x_bounds[0]) * (width - 1) as f64,
+            (y_bounds[1] - value) / (y_bounds[1] - y_bounds[0]) * (height - 1) as f64,
+        )
+    };
+    let mut set_pixel = |(col, row): (f64, f64), colour: (u8, u8, u8)| {
+        let (col, row) = (col.round() as isize, row.round() as isize);
+        for row in row - line_width / 2..row - line_width / 2 + line_width {
+            for col in col - line_width / 2..col - line_width / 2 + line_width {
+                if col >= 0 && row >= 0 && (col as usize) < width && (row as usize) < height {
+                    pixels[row as usize * width + col as usize] = Some(colour);
+                }
+            }
+        }
+    };
+
+    for (points, colour) in &graph.series {
+        let colour = get_rgb(*colour);
+        if let [point] = points.as_slice() {
+            set_pixel(to_pixel(*point), colour);
+        }
+
+        for segment in points.windows(2) {
+            let (start, end) = (to_pixel(segment[0]), to_pixel(segment[1]));
+            if (start.0 < 0.0 && end.0 < 0.0)
+                || (start.0 > (width - 1) as f64 && end.0 > (width - 1) as f64)
+            {
+                continue;
+            }
+
+            let step_count = (end.0 - start.0)
+                .abs()
+                .max((end.1 - start.1).abs())
+                .ceil()
+                .max(1.0) as usize;
+            for step in 0..=step_count {
+                let fraction = step as f64 / step_count as f64;
+                set_pixel(
+                    (
+                        start.0 + (end.0 - start.0) * fraction,
+                        start.1 + (end.1 - start.1) * fraction,
+                    ),
+                    colour,
+                );
+            }
+        }
+    }
+
+    let area_width = usize::from(graph.area.width);
+    for (cell_index, _) in graph
+        .covered_cells
+        .iter()
+        .enumerate()
+        .filter(|(_, is_covered)| **is_covered)
+    {
+        let (cell_col, cell_row) = (cell_index % area_width, cell_index / area_width);
+        for row in cell_row * cell_height..(cell_row + 1) * cell_height {
+            for col in cell_col * cell_width..(cell_col + 1) * cell_width {
+                pixels[row * width + col] = None;
+            }
+        }
+    }
+
+    Image {
+        width,
+        height,
+        pixels,
+    }
+}
+
+/// Writes an image with the kitty graphics protocol, scaled to fill the graph's area and placed
+/// under any text.
+fn write_kitty_image(output: &mut Vec<u8>, graph: &PendingGraph, image: &Image) {
+    let rgba = image
+        .pixels
+        .iter()
+        .flat_map(|pixel| match pixel {
+            Some((red, green, blue)) => vec![*red, *green, *blue, 255],
+            None => vec![0, 0, 0, 0],
+        })
+        .collect::<Vec<_>>();
+    let encoded = encode_base64(&rgba);
+    let chunks = encoded
+        .as_bytes()
+        .chunks(KITTY_CHUNK_SIZE)
+        .collect::<Vec<_>>();
+
+    write_cursor_position(output, graph.area.x, graph.area.y);
+    for (itx, chunk) in chunks.iter().enumerate() {
+        let has_more = if itx + 1 < chunks.len() { 1 } else { 0 };
+        if itx == 0 {
+            output.extend_from_slice(
+                format!(
+                    "\x1b_Ga=T,f=32,s={},v={},c={},r={},C=1,z=-1,q=2,m={};",
+                    image.width, image.height, graph.area.width, graph.area.height, has_more
+                )
+                .as_bytes(),
+            );
+        } else {
+            output.extend_from_slice(format!("\x1b_Gm={};", has_more).as_bytes());
+        }
+        output.extend_from_slice(chunk);
+        output.extend_from_slice(b"\x1b\\");
+    }
+}
+
+/// Writes an image as a sixel with a transparent background.  As sixels are drawn over text,
+/// the uncovered cells of the area are first erased to clear the previous frame's pixels.
+fn write_sixel_image(output: &mut Vec<u8>, graph: &PendingGraph, image: &Image) {
+    let area = graph.area;
+    output.extend_from_slice(b"\x1b[0m");
+    for row in 0..area.height {
+        let mut col = 0;
+        while col < area.width {
+            let is_covered = |col: u16| {
+                graph
+                    .covered_cells
+                    .get(usize::from(row) * usize::from(area.width) + usize::from(col))
+                    .copied()
+                    .unwrap_or(false)
+            };
+            if is_covered(col) {
+                col += 1;
+                continue;
+            }
+
+            let run_start = col;
+            while col < area.width && !is_covered(col) {
+                col += 1;
+            }
+            write_cursor_position(output, area.x + run_start, area.y + row);
+            output.extend_from_slice(format!("\x1b[{}X", col - run_start).as_bytes());
+        }
+    }
+
+    let mut palette: Vec<(u8, u8, u8)> = Vec::new();
+    let colour_indices = image
+        .pixels
+        .iter()
+        .map(|pixel| {
+            pixel.map(
+                |colour| match palette.iter().position(|entry| *entry == colour) {
+                    Some(index) => index,
+                    None => {
+                        palette.push(colour);
+                        palette.len() - 1
+                    }
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+
+    write_cursor_position(output, area.x, area.y);
+    output
+        .extend_from_slice(format!("\x1bP0;1;0q\"1;1;{};{}", image.width, image.height).as_bytes());
+    for (index, (red, green, blue)) in palette.iter().enumerate() {
+        output.extend_from_slice(
+            format!(
+                "#{};2;{};{};{}",
+                index,
+                u32::from(*red) * 100 / 255,
+                u32::from(*green) * 100 / 255,
+                u32::from(*blue) * 100 / 255
+            )
+            .as_bytes(),
+        );
+    }
+
+    // Each sixel is a column of six pixels, so the image is written six rows at a time, with
+    // one pass over the row band per colour.
+    for band_top in (0..image.height).step_by(6) {
+        let band_rows = band_top..min(band_top + 6, image.height);
+        for index in 0..palette.len() {
+            let sixels = (0..image.width)
+                .map(|col| {
+                    band_rows.clone().fold(0_u8, |bits, row| {
+                        if colour_indices[row * image.width + col] == Some(index) {
+                            bits | (1 << (row - band_top))
+                        } else {
+                            bits
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+            if sixels.iter().all(|bits| *bits == 0) {
+                continue;
+            }
+
+            output.extend_from_slice(format!("#{}", index).as_bytes());
+            write_sixel_runs(output, &sixels);
+            output.push(b'$');
+        }
+        output.push(b'-');
+    }
+    output.extend_from_slice(b"\x1b\\");
+}
+
+/// Writes a row of sixels, compressing repeats.
+fn write_sixel_runs(output: &mut Vec<u8>, sixels: &[u8]) {
+    let mut col = 0;
+    while col < sixels.len() {
+        let bits = sixels[col];
+        let run_length = sixels[col..]
+            .iter()
+            .take_while(|other_bits| **other_bits == bits)
+            .count();
+        let sixel = b'?' + bits;
+        if run_length > 3 {
+            output.extend_from_slice(format!("!{}", run_length).as_bytes());
+            output.push(sixel);
+        } else {
+            output.extend(std::iter::repeat(sixel).take(run_length));
+        }
+        col += run_length;
+    }
+}
+
+fn write_cursor_position(output: &mut Vec<u8>, x: u16, y: u16) {
+    output.extend_from_slice(format!("\x1b[{};{}H", y + 1, x + 1).as_bytes());
+}
+
+fn encode_base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let group = (u32::from(chunk[0]) << 16)
+            | (u32::from(*chunk.get(1).unwrap_or(&0)) << 8)
+            | u32::from(*chunk.get(2).unwrap_or(&0));
+        for itx in 0..4 {
+            if itx <= chunk.len() {
+                encoded.push(ALPHABET[(group >> (18 - 6 * itx) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+/// Returns the colour as RGB, using xterm's default palette for named and indexed colours.
+fn get_rgb(colour: Color) -> (u8, u8, u8) {
+    const NAMED_COLOURS: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (205, 0, 0),
+        (0, 205, 0),
+        (205, 205, 0),
+        (0, 0, 238),
+        (205, 0, 205),
+        (0, 205, 205),
+        (229, 229, 229),
+        (127, 127, 127),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (92, 92, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+
+    match colour {
+        Color::Reset | Color::Gray => NAMED_COLOURS[7],
+        Color::Black => NAMED_COLOURS[0],
+        Color::Red => NAMED_COLOURS[1],
+        Color::Green => NAMED_COLOURS[2],
+        Color::Yellow => NAMED_COLOURS[3],
+        Color::Blue => NAMED_COLOURS[4],
+        Color::Magenta => NAMED_COLOURS[5],
+        Color::Cyan => NAMED_COLOURS[6],
+        Color::DarkGray => NAMED_COLOURS[8],
+        Color::LightRed => NAMED_COLOURS[9],
+        Color::LightGreen => NAMED_COLOURS[10],
+        Color::LightYellow => NAMED_COLOURS[11],
+        Color::LightBlue => NAMED_COLOURS[12],
+        Color::LightMagenta => NAMED_COLOURS[13],
+        Color::LightCyan => NAMED_COLOURS[14],
+        Color::White => NAMED_COLOURS[15],
+        Color::Rgb(red, green, blue) => (red, green, blue),
+        Color::Indexed(index) if index < 16 => NAMED_COLOURS[usize::from(index)],
+        Color::Indexed(index) if index < 232 => {
+            const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+            let index = usize::from(index - 16);
+            (
+                CUBE_LEVELS[index / 36],
+                CUBE_LEVELS[index / 6 % 6],
+                CUBE_LEVELS[index % 6],
+            )
+        }
+        Color::Indexed(index) => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
@@ -7,7 +7,9 @@ pub mod mem_basic;
 pub mod mem_graph;
 pub mod network_basic;
 pub mod network_graph;
+pub mod pressure_table;
 pub mod process_table;
+pub mod system_info_table;
 pub mod temp_table;
 
 pub use basic_table_arrows::BasicTableArrows;
@@ -19,5 +21,7 @@ pub use mem_basic::MemBasicWidget;
 pub use mem_graph::MemGraphWidget;
 pub use network_basic::NetworkBasicWidget;
 pub use network_graph::NetworkGraphWidget;
+pub use pressure_table::PressureTableWidget;
 pub use process_table::ProcessTableWidget;
+pub use system_info_table::SystemInfoTableWidget;
 pub use temp_table::TempTableWidget;
@@ -115,7 +115,9 @@ impl BatteryDisplayWidget for Painter {
                 .direction(Direction::Horizontal)
                 .split(draw_loc)[0];
 
-            if let Some(battery_details) = app_state
+            if !app_state.used_widgets.use_battery {
+                self.draw_disabled_widget(f, battery_block, margined_draw_loc);
+            } else if let Some(battery_details) = app_state
                 .canvas_data
                 .battery_data
                 .get(battery_widget_state.currently_selected_battery_index)
@@ -134,6 +136,7 @@ impl BatteryDisplayWidget for Painter {
 
                 let battery_items = vec![
                     ["Charge %", &bars],
+                    ["State", &battery_details.state],
                     ["Consumption", &battery_details.watt_consumption],
                     if let Some(duration_until_full) = &battery_details.duration_until_full {
                         ["Time to full", duration_until_full]
@@ -188,6 +191,23 @@ impl BatteryDisplayWidget for Painter {
                 );
             }
 
+            // With more than one battery, show each battery's charge in its tab so all of them
+            // can be seen at a glance.
+            let battery_data = &app_state.canvas_data.battery_data;
+            let battery_titles = if battery_data.len() > 1 {
+                battery_data
+                    .iter()
+                    .map(|battery| {
+                        format!("{} {:.0}%", battery.battery_name, battery.charge_percentage)
+                    })
+                    .collect::<Vec<_>>()
+            } else {
+                battery_data
+                    .iter()
+                    .map(|battery| battery.battery_name.clone())
+                    .collect::<Vec<_>>()
+            };
+
             // Has to be placed AFTER for tui 0.9, place BEFORE for 0.10.
             f.render_widget(
                 // Tabs::new(
@@ -200,15 +220,7 @@ impl BatteryDisplayWidget for Painter {
                 // )
                 // FIXME: [MOUSE] Support mouse for the tabs?
                 Tabs::default()
-                    .titles(
-                        app_state
-                            .canvas_data
-                            .battery_data
-                            .iter()
-                            .map(|battery| &battery.battery_name)
-                            .collect::<Vec<_>>()
-                            .as_ref(),
-                    )
+                    .titles(battery_titles.as_ref())
                     .block(battery_block)
                     .divider(tui::symbols::line::VERTICAL)
                     .style(self.colours.text_style)
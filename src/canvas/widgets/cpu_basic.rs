@@ -75,7 +75,7 @@ impl CpuBasicWidget for Painter {
                         };
 
                     let num_bars = calculate_basic_use_bars(use_percentage, bar_length);
-                    format!(
+                    let cpu_bar = format!(
                         "{:3}[{}{}{:3.0}%]\n",
                         if app_state.app_config_fields.show_average_cpu {
                             if cpu_index == 0 {
@@ -89,7 +89,9 @@ impl CpuBasicWidget for Painter {
                         "|".repeat(num_bars),
                         " ".repeat(bar_length - num_bars),
                         use_percentage.round(),
-                    )
+                    );
+
+                    (cpu_bar, use_percentage)
                 })
                 .collect::<Vec<_>>();
 
@@ -113,10 +115,15 @@ impl CpuBasicWidget for Painter {
                             //     style: self.colours.cpu_colour_styles
                             //         [cpu_index % self.colours.cpu_colour_styles.len()],
                             // })
+                            let (cpu_bar, use_percentage) = &cpu_bars[cpu_index];
                             Text::styled(
-                                &cpu_bars[cpu_index],
-                                self.colours.cpu_colour_styles
-                                    [cpu_index % self.colours.cpu_colour_styles.len()],
+                                cpu_bar,
+                                if app_state.app_config_fields.use_cpu_usage_gradient {
+                                    self.colours.get_cpu_usage_gradient_style(*use_percentage)
+                                } else {
+                                    self.colours.cpu_colour_styles
+                                        [cpu_index % self.colours.cpu_colour_styles.len()]
+                                },
                             )
                         })
                         .collect::<Vec<_>>();
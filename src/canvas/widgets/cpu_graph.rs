@@ -5,7 +5,12 @@ use std::cmp::max;
 use crate::{
     app::{layout_manager::WidgetDirection, App},
     canvas::{
-        drawing_utils::{get_start_position, get_variable_intrinsic_widths},
+        drawing_utils::{
+            get_pan_indicator, get_start_position, get_time_axis_labels,
+            get_variable_intrinsic_widths,
+        },
+        half_block::GraphSeries,
+        heatmap::Heatmap,
         Painter,
     },
     constants::*,
@@ -15,9 +20,9 @@ use crate::{
 use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Rect},
-    symbols::Marker,
+    style::Style,
     terminal::Frame,
-    widgets::{Axis, Block, Borders, Chart, Dataset, Row, Table},
+    widgets::{Axis, Block, Borders, Chart, Row, Table},
 };
 
 const CPU_SELECT_LEGEND_HEADER: [&str; 2] = ["CPU", "Show"];
@@ -149,90 +154,135 @@ impl CpuGraphWidget for Painter {
             //     Text::styled("0s".to_string(), self.colours.graph_style),
             // ];
 
-            let display_time_labels = [
-                format!("{}s", cpu_widget_state.current_display_time / 1000),
-                "0s".to_string(),
+            let x_bounds = [
+                -((cpu_widget_state.current_display_time + cpu_widget_state.pan_offset) as f64),
+                -(cpu_widget_state.pan_offset as f64),
             ];
+            let display_time_labels = get_time_axis_labels(
+                cpu_widget_state.current_display_time,
+                cpu_widget_state.pan_offset,
+            );
 
-            let x_axis = if app_state.app_config_fields.hide_time
+            let is_showing_time_labels = if app_state.app_config_fields.hide_time
                 || (app_state.app_config_fields.autohide_time
                     && cpu_widget_state.autohide_timer.is_none())
             {
-                Axis::default().bounds([-(cpu_widget_state.current_display_time as f64), 0.0])
+                false
             } else if let Some(time) = cpu_widget_state.autohide_timer {
                 if std::time::Instant::now().duration_since(time).as_millis()
                     < AUTOHIDE_TIMEOUT_MILLISECONDS as u128
                 {
-                    Axis::default()
-                        .bounds([-(cpu_widget_state.current_display_time as f64), 0.0])
-                        .style(self.colours.graph_style)
-                        .labels(&display_time_labels)
-                        .labels_style(self.colours.graph_style)
+                    true
                 } else {
                     cpu_widget_state.autohide_timer = None;
-                    Axis::default().bounds([-(cpu_widget_state.current_display_time as f64), 0.0])
+                    false
                 }
-            } else if draw_loc.height < TIME_LABEL_HEIGHT_LIMIT {
-                Axis::default().bounds([-(cpu_widget_state.current_display_time as f64), 0.0])
             } else {
+                draw_loc.height >= TIME_LABEL_HEIGHT_LIMIT
+            };
+            let time_labels = if is_showing_time_labels {
+                Some(&display_time_labels[..])
+            } else {
+                None
+            };
+            let x_axis = if let Some(time_labels) = time_labels {
                 Axis::default()
-                    .bounds([-(cpu_widget_state.current_display_time as f64), 0.0])
+                    .bounds(x_bounds)
                     .style(self.colours.graph_style)
-                    .labels(&display_time_labels)
+                    .labels(time_labels)
                     .labels_style(self.colours.graph_style)
+            } else {
+                Axis::default().bounds(x_bounds)
             };
 
+            let y_bounds = [0.0, 100.5];
+            let y_axis_labels = if cpu_widget_state.is_y_inverted {
+                ["100%", "0%"]
+            } else {
+                ["0%", "100%"]
+            };
             let y_axis = Axis::default()
                 .style(self.colours.graph_style)
-                .bounds([0.0, 100.5])
+                .bounds(y_bounds)
                 .labels_style(self.colours.graph_style)
-                .labels(&["0%", "100%"]);
+                .labels(&y_axis_labels);
 
-            let use_dot = app_state.app_config_fields.use_dot;
             let show_avg_cpu = app_state.app_config_fields.show_average_cpu;
             let current_scroll_position = cpu_widget_state.scroll_state.current_scroll_position;
-            let dataset_vector: Vec<Dataset<'_>> = if current_scroll_position == ALL_POSITION {
+            // The breakdown is of a single entry, so if all entries are shown, use the first one.
+            let breakdown_cpu = if cpu_widget_state.is_showing_breakdown {
+                let breakdown_position = if current_scroll_position == ALL_POSITION {
+                    ALL_POSITION + 1
+                } else {
+                    current_scroll_position
+                };
                 cpu_data
+                    .get(breakdown_position)
+                    .filter(|cpu| !cpu.breakdown_data.is_empty())
+            } else {
+                None
+            };
+            // Entries that have reached a threshold colour stop use that colour instead.
+            let get_threshold_style = |cpu: &ConvertedCpuData, default_style: Style| -> Style {
+                cpu.cpu_data
+                    .last()
+                    .and_then(|(_time, usage)| self.colours.get_cpu_threshold_style(*usage))
+                    .unwrap_or(default_style)
+            };
+            let mut graph_series = GraphSeries::new(
+                &app_state.app_config_fields.graph_marker,
+                app_state.app_config_fields.raw_graphs,
+            );
+            graph_series.invert_y(cpu_widget_state.is_y_inverted);
+            if let Some(cpu) = breakdown_cpu {
+                // Draw the largest stacked values first so the smaller ones are drawn over them.
+                for ((breakdown, label), style) in cpu
+                    .breakdown_data
+                    .iter()
+                    .zip(CPU_BREAKDOWN_LABELS.iter())
+                    .zip(self.colours.cpu_breakdown_styles.iter())
+                    .rev()
+                {
+                    graph_series.push(*label, *style, &breakdown[..]);
+                }
+            } else if current_scroll_position == ALL_POSITION {
+                for (itx, cpu) in cpu_data
                     .iter()
                     .enumerate()
                     .rev()
-                    .map(|(itx, cpu)| {
-                        Dataset::default()
-                            .marker(if use_dot {
-                                Marker::Dot
-                            } else {
-                                Marker::Braille
-                            })
-                            .style(if show_avg_cpu && itx == AVG_POSITION {
+                    .filter(|(itx, _cpu)| !cpu_widget_state.hidden_entries.contains(itx))
+                {
+                    graph_series.push(
+                        "",
+                        get_threshold_style(
+                            cpu,
+                            if show_avg_cpu && itx == AVG_POSITION {
                                 self.colours.avg_colour_style
                             } else {
                                 self.colours.cpu_colour_styles
                                     [itx % self.colours.cpu_colour_styles.len()]
-                            })
-                            .data(&cpu.cpu_data[..])
-                            .graph_type(tui::widgets::GraphType::Line)
-                    })
-                    .collect()
+                            },
+                        ),
+                        &cpu.cpu_data[..],
+                    );
+                }
             } else if let Some(cpu) = cpu_data.get(current_scroll_position) {
-                vec![Dataset::default()
-                    .marker(if use_dot {
-                        Marker::Dot
-                    } else {
-                        Marker::Braille
-                    })
-                    .style(if show_avg_cpu && current_scroll_position == AVG_POSITION {
-                        self.colours.avg_colour_style
-                    } else {
-                        self.colours.cpu_colour_styles[cpu_widget_state
-                            .scroll_state
-                            .current_scroll_position
-                            % self.colours.cpu_colour_styles.len()]
-                    })
-                    .data(&cpu.cpu_data[..])
-                    .graph_type(tui::widgets::GraphType::Line)]
-            } else {
-                vec![]
-            };
+                graph_series.push(
+                    "",
+                    get_threshold_style(
+                        cpu,
+                        if show_avg_cpu && current_scroll_position == AVG_POSITION {
+                            self.colours.avg_colour_style
+                        } else {
+                            self.colours.cpu_colour_styles[cpu_widget_state
+                                .scroll_state
+                                .current_scroll_position
+                                % self.colours.cpu_colour_styles.len()]
+                        },
+                    ),
+                    &cpu.cpu_data[..],
+                );
+            }
 
             let border_style = if app_state.current_widget.widget_id == widget_id {
                 self.colours.highlighted_border_style
@@ -245,28 +295,80 @@ impl CpuGraphWidget for Painter {
             // } else {
             //     Span::styled(" CPU ".to_string(), self.colours.widget_title_style)
             // };
-            let title = " CPU ";
+            let title = if cpu_widget_state.is_showing_heatmap {
+                format!(
+                    " CPU Heatmap ─ {} ",
+                    get_pan_indicator(cpu_widget_state.pan_offset)
+                )
+            } else if let Some(cpu) = breakdown_cpu {
+                format!(
+                    " {} Breakdown ─ {} ",
+                    cpu.cpu_name,
+                    get_pan_indicator(cpu_widget_state.pan_offset)
+                )
+            } else if let Some(scheduler_activity) =
+                &app_state.canvas_data.scheduler_activity_display
+            {
+                format!(
+                    " CPU ─ {} ─ {} ",
+                    scheduler_activity,
+                    get_pan_indicator(cpu_widget_state.pan_offset)
+                )
+            } else {
+                format!(" CPU ─ {} ", get_pan_indicator(cpu_widget_state.pan_offset))
+            };
             let title_style = if app_state.is_expanded {
                 border_style
             } else {
                 self.colours.widget_title_style
             };
 
-            f.render_widget(
-                // Chart::new(dataset_vector)
-                Chart::default()
-                    .datasets(&dataset_vector)
-                    .block(
-                        Block::default()
-                            .title(title)
-                            .title_style(title_style)
-                            .borders(Borders::ALL)
-                            .border_style(border_style),
-                    )
-                    .x_axis(x_axis)
-                    .y_axis(y_axis),
-                draw_loc,
-            );
+            let block = Block::default()
+                .title(&title)
+                .title_style(title_style)
+                .borders(Borders::ALL)
+                .border_style(border_style);
+
+            if cpu_widget_state.is_showing_heatmap {
+                // Each core gets its own row, so the "All" entry isn't drawn.
+                let heatmap_loc = block.inner(draw_loc);
+                f.render_widget(block, draw_loc);
+                f.render_widget(
+                    Heatmap {
+                        rows: cpu_data
+                            .iter()
+                            .enumerate()
+                            .skip(ALL_POSITION + 1)
+                            .filter(|(itx, _cpu)| !cpu_widget_state.hidden_entries.contains(itx))
+                            .map(|(_itx, cpu)| (cpu.cpu_name.as_str(), &cpu.cpu_data[..]))
+                            .collect(),
+                        x_bounds,
+                        label_style: self.colours.graph_style,
+                        colour_fn: |usage| self.colours.get_cpu_heatmap_colour(usage),
+                    },
+                    heatmap_loc,
+                );
+            } else {
+                graph_series.draw_points(
+                    f,
+                    self,
+                    block.inner(draw_loc),
+                    time_labels,
+                    &y_axis_labels,
+                    x_bounds,
+                    y_bounds,
+                );
+
+                f.render_widget(
+                    // Chart::new(dataset_vector)
+                    Chart::default()
+                        .datasets(&graph_series.datasets())
+                        .block(block)
+                        .x_axis(x_axis)
+                        .y_axis(y_axis),
+                    draw_loc,
+                );
+            }
         }
     }
 
@@ -307,6 +409,8 @@ impl CpuGraphWidget for Painter {
                 .current_scroll_position
                 .saturating_sub(start_position);
             let show_avg_cpu = app_state.app_config_fields.show_average_cpu;
+            let use_cpu_usage_gradient = app_state.app_config_fields.use_cpu_usage_gradient;
+            let hidden_entries = &cpu_widget_state.hidden_entries;
 
             let cpu_rows = sliced_cpu_data.iter().enumerate().filter_map(|(itx, cpu)| {
                 let cpu_string_row: Vec<Cow<'_, str>> = vec![
@@ -322,8 +426,23 @@ impl CpuGraphWidget for Painter {
                         cpu_string_row.into_iter(),
                         if itx == offset_scroll_index {
                             self.colours.currently_selected_text_style
+                        } else if hidden_entries.contains(&(itx + start_position)) {
+                            self.colours.disabled_text_style
                         } else if itx == ALL_POSITION {
                             self.colours.all_colour_style
+                        } else if let Some(threshold_style) = cpu
+                            .cpu_data
+                            .last()
+                            .and_then(|(_time, usage)| self.colours.get_cpu_threshold_style(*usage))
+                        {
+                            threshold_style
+                        } else if use_cpu_usage_gradient {
+                            self.colours.get_cpu_usage_gradient_style(
+                                cpu.cpu_data
+                                    .last()
+                                    .map(|(_time, usage)| *usage)
+                                    .unwrap_or(0.0),
+                            )
                         } else if show_avg_cpu {
                             if itx == AVG_POSITION {
                                 self.colours.avg_colour_style
@@ -1,5 +1,4 @@
-use lazy_static::lazy_static;
-use std::cmp::max;
+use std::{cmp::max, path::Path};
 use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Rect},
@@ -16,14 +15,19 @@ use crate::{
     constants::*,
 };
 
-const DISK_HEADERS: [&str; 7] = ["Disk", "Mount", "Used", "Free", "Total", "R/s", "W/s"];
-
-lazy_static! {
-    static ref DISK_HEADERS_LENS: Vec<usize> = DISK_HEADERS
-        .iter()
-        .map(|entry| max(FORCE_MIN_THRESHOLD, entry.len()))
-        .collect::<Vec<_>>();
-}
+/// The headers of the disk table along with their share of its width, with the optional type
+/// and options columns coming after the mount point.
+const DISK_HEADERS: [(&str, f64); 7] = [
+    ("Disk", 0.2),
+    ("Mount", 0.15),
+    ("Used", 0.13),
+    ("Free", 0.13),
+    ("Total", 0.13),
+    ("R/s", 0.13),
+    ("W/s", 0.13),
+];
+const FILE_SYSTEM_HEADER: (&str, f64) = ("Type", 0.1);
+const MOUNT_OPTIONS_HEADER: (&str, f64) = ("Options", 0.15);
 
 pub trait DiskTableWidget {
     fn draw_disk_table<B: Backend>(
@@ -62,14 +66,96 @@ impl DiskTableWidget for Painter {
                     .saturating_sub(start_position),
             ));
             let sliced_vec = &mut disk_data[start_position..];
-            let disk_rows = sliced_vec.iter().map(|disk| Row::Data(disk.iter()));
+            // Only what is shown is shortened, as mount thresholds are looked up by full path.
+            let shortened_disk_data;
+            let displayed_disk_data: &[Vec<String>] = if disk_widget_state.is_showing_basenames {
+                shortened_disk_data = sliced_vec
+                    .iter()
+                    .map(|disk| {
+                        disk.iter()
+                            .enumerate()
+                            .map(|(column, cell)| {
+                                if column < 2 {
+                                    get_basename(cell)
+                                } else {
+                                    cell.clone()
+                                }
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>();
+                &shortened_disk_data
+            } else {
+                sliced_vec
+            };
+            let disk_used_percent = &app_state.canvas_data.disk_used_percent;
+            let disk_warn_threshold_percent =
+                app_state.app_config_fields.disk_warn_threshold_percent;
+            let disk_mount_warn_threshold_percent = &app_state
+                .app_config_fields
+                .disk_mount_warn_threshold_percent;
+            let disk_alarm_style = self.colours.disk_alarm_style;
+            let zebra_stripes = app_state.app_config_fields.zebra_stripes;
+            let disk_rows = sliced_vec.iter().enumerate().map(|(itx, disk)| {
+                // Mounts without a known size have no used percentage, so are never alarmed.
+                let threshold = disk
+                    .get(1)
+                    .and_then(|mount| disk_mount_warn_threshold_percent.get(mount))
+                    .cloned()
+                    .or(disk_warn_threshold_percent);
+                let used_percent = disk_used_percent
+                    .get(start_position + itx)
+                    .cloned()
+                    .flatten();
 
-            // Calculate widths
+                let row_style = match (threshold, used_percent) {
+                    (Some(threshold), Some(used_percent)) if used_percent >= threshold => {
+                        Some(disk_alarm_style)
+                    }
+                    _ => None,
+                };
+                let displayed_disk = &displayed_disk_data[itx];
+                match self.colours.get_table_row_style(
+                    row_style,
+                    start_position + itx,
+                    zebra_stripes,
+                ) {
+                    Some(row_style) => Row::StyledData(displayed_disk.iter(), row_style),
+                    None => Row::Data(displayed_disk.iter()),
+                }
+            });
+
+            let disk_columns = app_state.app_config_fields.disk_columns;
+            let file_system_header;
+            let mut disk_headers = DISK_HEADERS[0..2].to_vec();
+            if disk_columns.file_system {
+                // The arrow shows that the disks are grouped by this column.
+                file_system_header = if app_state.disk_state.is_sorted_by_file_system {
+                    format!("{}▲", FILE_SYSTEM_HEADER.0)
+                } else {
+                    FILE_SYSTEM_HEADER.0.to_string()
+                };
+                disk_headers.push((file_system_header.as_str(), FILE_SYSTEM_HEADER.1));
+            }
+            if disk_columns.mount_options {
+                disk_headers.push(MOUNT_OPTIONS_HEADER);
+            }
+            disk_headers.extend_from_slice(&DISK_HEADERS[2..]);
+            let disk_headers_lens = disk_headers
+                .iter()
+                .map(|(header, _)| max(FORCE_MIN_THRESHOLD, header.chars().count()))
+                .collect::<Vec<_>>();
+
+            // Calculate widths, scaling the shares down to fit any optional columns.
             // TODO: [PRETTY] Ellipsis on strings?
             let width = f64::from(draw_loc.width);
-            let width_ratios = [0.2, 0.15, 0.13, 0.13, 0.13, 0.13, 0.13];
+            let total_ratio: f64 = disk_headers.iter().map(|(_, ratio)| ratio).sum();
+            let width_ratios = disk_headers
+                .iter()
+                .map(|(_, ratio)| ratio / total_ratio.max(1.0))
+                .collect::<Vec<_>>();
             let variable_intrinsic_results =
-                get_variable_intrinsic_widths(width as u16, &width_ratios, &DISK_HEADERS_LENS);
+                get_variable_intrinsic_widths(width as u16, &width_ratios, &disk_headers_lens);
             let intrinsic_widths = &variable_intrinsic_results.0[0..variable_intrinsic_results.1];
 
             // TODO: This seems to be bugged?  The selected text style gets "stuck"?  I think this gets fixed with tui 0.10?
@@ -141,22 +227,28 @@ impl DiskTableWidget for Painter {
                 .split(draw_loc)[0];
 
             // Draw!
-            f.render_stateful_widget(
-                Table::new(DISK_HEADERS.iter(), disk_rows)
-                    .block(disk_block)
-                    .header_style(self.colours.table_header_style)
-                    .highlight_style(highlight_style)
-                    .style(self.colours.text_style)
-                    .widths(
-                        &(intrinsic_widths
-                            .iter()
-                            .map(|calculated_width| Constraint::Length(*calculated_width as u16))
-                            .collect::<Vec<_>>()),
-                    )
-                    .header_gap(table_gap),
-                margined_draw_loc,
-                disk_table_state,
-            );
+            if !app_state.used_widgets.use_disk {
+                self.draw_disabled_widget(f, disk_block, margined_draw_loc);
+            } else {
+                f.render_stateful_widget(
+                    Table::new(disk_headers.iter().map(|(header, _)| *header), disk_rows)
+                        .block(disk_block)
+                        .header_style(self.colours.table_header_style)
+                        .highlight_style(highlight_style)
+                        .style(self.colours.text_style)
+                        .widths(
+                            &(intrinsic_widths
+                                .iter()
+                                .map(|calculated_width| {
+                                    Constraint::Length(*calculated_width as u16)
+                                })
+                                .collect::<Vec<_>>()),
+                        )
+                        .header_gap(table_gap),
+                    margined_draw_loc,
+                    disk_table_state,
+                );
+            }
 
             if app_state.should_get_widget_bounds() {
                 // Update draw loc in widget map
@@ -171,3 +263,12 @@ impl DiskTableWidget for Painter {
         }
     }
 }
+
+/// Returns the last part of a disk or mount path, or the whole path if it has no such part,
+/// such as `/` or `C:\`.
+fn get_basename(path: &str) -> String {
+    Path::new(path)
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string())
+}
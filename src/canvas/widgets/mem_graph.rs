@@ -1,11 +1,18 @@
-use crate::{app::App, canvas::Painter, constants::*};
+use crate::{
+    app::{App, MemLegendDisplay},
+    canvas::{
+        drawing_utils::{calculate_basic_use_bars, get_pan_indicator, get_time_axis_labels},
+        half_block::GraphSeries,
+        Painter,
+    },
+    constants::*,
+};
 
 use tui::{
     backend::Backend,
-    layout::{Constraint, Rect},
-    symbols::Marker,
+    layout::{Constraint, Direction, Layout, Rect},
     terminal::Frame,
-    widgets::{Axis, Block, Borders, Chart, Dataset},
+    widgets::{Axis, Block, Borders, Chart, Gauge, Paragraph, Text},
 };
 
 pub trait MemGraphWidget {
@@ -22,116 +29,231 @@ impl MemGraphWidget for Painter {
             let mem_data: &[(f64, f64)] = &app_state.canvas_data.mem_data;
             let swap_data: &[(f64, f64)] = &app_state.canvas_data.swap_data;
 
-            let display_time_labels = [
-                format!("{}s", mem_widget_state.current_display_time / 1000),
-                "0s".to_string(),
+            let x_bounds = [
+                -((mem_widget_state.current_display_time + mem_widget_state.pan_offset) as f64),
+                -(mem_widget_state.pan_offset as f64),
             ];
-            let x_axis = if app_state.app_config_fields.hide_time
+            let display_time_labels = get_time_axis_labels(
+                mem_widget_state.current_display_time,
+                mem_widget_state.pan_offset,
+            );
+            let is_showing_time_labels = if app_state.app_config_fields.hide_time
                 || (app_state.app_config_fields.autohide_time
                     && mem_widget_state.autohide_timer.is_none())
             {
-                Axis::default().bounds([-(mem_widget_state.current_display_time as f64), 0.0])
+                false
             } else if let Some(time) = mem_widget_state.autohide_timer {
                 if std::time::Instant::now().duration_since(time).as_millis()
                     < AUTOHIDE_TIMEOUT_MILLISECONDS as u128
                 {
-                    Axis::default()
-                        .bounds([-(mem_widget_state.current_display_time as f64), 0.0])
-                        .style(self.colours.graph_style)
-                        .labels(&display_time_labels)
-                        .labels_style(self.colours.graph_style)
+                    true
                 } else {
                     mem_widget_state.autohide_timer = None;
-                    Axis::default().bounds([-(mem_widget_state.current_display_time as f64), 0.0])
+                    false
                 }
-            } else if draw_loc.height < TIME_LABEL_HEIGHT_LIMIT {
-                Axis::default().bounds([-(mem_widget_state.current_display_time as f64), 0.0])
             } else {
+                draw_loc.height >= TIME_LABEL_HEIGHT_LIMIT
+            };
+            let time_labels = if is_showing_time_labels {
+                Some(&display_time_labels[..])
+            } else {
+                None
+            };
+            let x_axis = if let Some(time_labels) = time_labels {
                 Axis::default()
-                    .bounds([-(mem_widget_state.current_display_time as f64), 0.0])
+                    .bounds(x_bounds)
                     .style(self.colours.graph_style)
-                    .labels(&display_time_labels)
+                    .labels(time_labels)
                     .labels_style(self.colours.graph_style)
+            } else {
+                Axis::default().bounds(x_bounds)
             };
 
+            let y_bounds = [0.0, 100.5];
+            let y_axis_labels = if mem_widget_state.is_y_inverted {
+                ["100%", "0%"]
+            } else {
+                ["0%", "100%"]
+            };
             let y_axis = Axis::default()
                 .style(self.colours.graph_style)
-                .bounds([0.0, 100.5])
-                .labels(&["0%", "100%"])
+                .bounds(y_bounds)
+                .labels(&y_axis_labels)
                 .labels_style(self.colours.graph_style);
 
-            let mut mem_canvas_vec: Vec<Dataset<'_>> = vec![];
-            let mem_label = format!(
-                "RAM:{}{}",
-                app_state.canvas_data.mem_label_percent, app_state.canvas_data.mem_label_frac
-            );
-            mem_canvas_vec.push(
-                Dataset::default()
-                    .name(&mem_label)
-                    .marker(if app_state.app_config_fields.use_dot {
-                        Marker::Dot
-                    } else {
-                        Marker::Braille
-                    })
-                    .style(self.colours.ram_style)
-                    .data(&mem_data)
-                    .graph_type(tui::widgets::GraphType::Line),
+            let mut graph_series = GraphSeries::new(
+                &app_state.app_config_fields.graph_marker,
+                app_state.app_config_fields.raw_graphs,
             );
+            graph_series.invert_y(mem_widget_state.is_y_inverted);
 
-            let swap_label = format!(
-                "SWP:{}{}",
-                app_state.canvas_data.swap_label_percent, app_state.canvas_data.swap_label_frac
+            // The breakdown is stacked on top of used memory, so draw the outermost series first.
+            if mem_widget_state.is_showing_breakdown {
+                let breakdown_styles = [self.colours.buffers_style, self.colours.cached_style];
+                for ((series, label), style) in app_state
+                    .canvas_data
+                    .mem_breakdown_data
+                    .iter()
+                    .zip(MEM_BREAKDOWN_LABELS.iter())
+                    .zip(breakdown_styles.iter())
+                    .rev()
+                {
+                    graph_series.push(*label, *style, series);
+                }
+            }
+
+            // Only the legend changes with the display, the chart itself always shows percentages.
+            let get_legend_label = |name: &str, percent: &str, frac: &str| -> String {
+                match mem_widget_state.legend_display {
+                    MemLegendDisplay::Both => format!("{}:{}{}", name, percent, frac),
+                    MemLegendDisplay::Percentage => format!("{}:{}", name, percent),
+                    MemLegendDisplay::Value => format!("{}: {}", name, frac.trim()),
+                }
+            };
+
+            let mem_label = get_legend_label(
+                "RAM",
+                &app_state.canvas_data.mem_label_percent,
+                &app_state.canvas_data.mem_label_frac,
             );
-            mem_canvas_vec.push(
-                Dataset::default()
-                    .name(&swap_label)
-                    .marker(if app_state.app_config_fields.use_dot {
-                        Marker::Dot
-                    } else {
-                        Marker::Braille
-                    })
-                    .style(self.colours.swap_style)
-                    .data(&swap_data)
-                    .graph_type(tui::widgets::GraphType::Line),
+            let mem_style = mem_data
+                .last()
+                .and_then(|(_time, usage)| self.colours.get_mem_threshold_style(*usage))
+                .unwrap_or(self.colours.ram_style);
+            graph_series.push(&mem_label, mem_style, mem_data);
+
+            let swap_label = get_legend_label(
+                "SWP",
+                &app_state.canvas_data.swap_label_percent,
+                &app_state.canvas_data.swap_label_frac,
             );
+            let swap_style = swap_data
+                .last()
+                .and_then(|(_time, usage)| self.colours.get_mem_threshold_style(*usage))
+                .unwrap_or(self.colours.swap_style);
+            graph_series.push(&swap_label, swap_style, swap_data);
 
+            let pan_indicator = get_pan_indicator(mem_widget_state.pan_offset);
             let title = if app_state.is_expanded {
-                const TITLE_BASE: &str = " Memory ── Esc to go back ";
+                const TITLE_BASE: &str = " Memory ─  ── Esc to go back ";
                 format!(
-                    " Memory ─{}─ Esc to go back ",
-                    "─".repeat(
-                        usize::from(draw_loc.width).saturating_sub(TITLE_BASE.chars().count() + 2)
-                    )
+                    " Memory ─ {} ─{}─ Esc to go back ",
+                    pan_indicator,
+                    "─".repeat(usize::from(draw_loc.width).saturating_sub(
+                        TITLE_BASE.chars().count() + pan_indicator.chars().count() + 2
+                    ))
                 )
             } else {
-                " Memory ".to_string()
+                format!(" Memory ─ {} ", pan_indicator)
             };
-            let title_style = if app_state.is_expanded {
+            let is_over_warn_threshold = app_state.mem_state.is_over_warn_threshold;
+            let title_style = if is_over_warn_threshold {
+                self.colours.mem_alarm_style
+            } else if app_state.is_expanded {
                 self.colours.highlighted_border_style
             } else {
                 self.colours.widget_title_style
             };
+            let border_style = if is_over_warn_threshold {
+                self.colours.mem_alarm_style
+            } else if app_state.current_widget.widget_id == widget_id {
+                self.colours.highlighted_border_style
+            } else {
+                self.colours.border_style
+            };
 
-            f.render_widget(
-                // Chart::new(mem_canvas_vec)
-                Chart::default()
-                    .datasets(&mem_canvas_vec)
-                    .block(
-                        Block::default()
-                            .title(&title)
-                            .title_style(title_style)
-                            .borders(Borders::ALL)
-                            .border_style(if app_state.current_widget.widget_id == widget_id {
-                                self.colours.highlighted_border_style
-                            } else {
-                                self.colours.border_style
-                            }),
-                    )
-                    .x_axis(x_axis)
-                    .y_axis(y_axis)
-                    .hidden_legend_constraints((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
-                draw_loc,
-            );
+            let block = Block::default()
+                .title(&title)
+                .title_style(title_style)
+                .borders(Borders::ALL)
+                .border_style(border_style);
+
+            // The pressure gauge takes the last line inside the border, if there is room for it.
+            let inner_loc = block.inner(draw_loc);
+            let mem_pressure = app_state
+                .canvas_data
+                .mem_pressure
+                .filter(|_| draw_loc.height >= MEM_PRESSURE_GAUGE_HEIGHT_LIMIT);
+            let chart_loc = if mem_pressure.is_some() {
+                Rect {
+                    height: inner_loc.height.saturating_sub(1),
+                    ..inner_loc
+                }
+            } else {
+                inner_loc
+            };
+
+            f.render_widget(block, draw_loc);
+
+            if mem_widget_state.is_showing_gauge {
+                // Each gauge only shows the latest usage, so they split the chart's space.
+                let gauge_locs = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)].as_ref())
+                    .split(chart_loc);
+                let gauges = [
+                    (&mem_label, mem_style, mem_data),
+                    (&swap_label, swap_style, swap_data),
+                ];
+                for ((label, style, data), gauge_loc) in gauges.iter().zip(gauge_locs) {
+                    let usage = data.last().map(|(_time, usage)| *usage).unwrap_or(0.0);
+                    f.render_widget(
+                        Gauge::default()
+                            .style(*style)
+                            .ratio((usage / 100.0).max(0.0).min(1.0))
+                            .label(label),
+                        gauge_loc,
+                    );
+                }
+            } else {
+                graph_series.draw_points(
+                    f,
+                    self,
+                    chart_loc,
+                    time_labels,
+                    &y_axis_labels,
+                    x_bounds,
+                    y_bounds,
+                );
+
+                f.render_widget(
+                    // Chart::new(mem_canvas_vec)
+                    Chart::default()
+                        .datasets(&graph_series.datasets())
+                        .x_axis(x_axis)
+                        .y_axis(y_axis)
+                        .hidden_legend_constraints((
+                            Constraint::Ratio(3, 4),
+                            Constraint::Ratio(3, 4),
+                        )),
+                    chart_loc,
+                );
+            }
+
+            if let Some(mem_pressure) = mem_pressure {
+                // -9 due to 3 + 2 columns for the name & bar bounds, and 4 for the percentage
+                let bar_length = usize::from(inner_loc.width.saturating_sub(9));
+                let num_bars = calculate_basic_use_bars(mem_pressure, bar_length);
+                let pressure_text = [Text::styled(
+                    format!(
+                        "PRS[{}{}{:3.0}%]",
+                        "|".repeat(num_bars),
+                        " ".repeat(bar_length - num_bars),
+                        mem_pressure.round()
+                    ),
+                    self.colours
+                        .get_mem_threshold_style(mem_pressure)
+                        .unwrap_or(self.colours.text_style),
+                )];
+                f.render_widget(
+                    Paragraph::new(pressure_text.iter()),
+                    Rect {
+                        y: inner_loc.y + chart_loc.height,
+                        height: 1,
+                        ..inner_loc
+                    },
+                );
+            }
         }
 
         if app_state.should_get_widget_bounds() {
@@ -45,8 +45,19 @@ impl NetworkBasicWidget for Painter {
 
         let rx_label = format!("RX: {}\n", &app_state.canvas_data.rx_display);
         let tx_label = format!("TX: {}", &app_state.canvas_data.tx_display);
-        let total_rx_label = format!("Total RX: {}\n", &app_state.canvas_data.total_rx_display);
-        let total_tx_label = format!("Total TX: {}", &app_state.canvas_data.total_tx_display);
+        let total_label = if app_state.net_state.is_showing_session_total {
+            "Session"
+        } else {
+            "Boot"
+        };
+        let total_rx_label = format!(
+            "{} RX: {}\n",
+            total_label, &app_state.canvas_data.total_rx_display
+        );
+        let total_tx_label = format!(
+            "{} TX: {}",
+            total_label, &app_state.canvas_data.total_tx_display
+        );
 
         let net_text = [
             Text::styled(rx_label, self.colours.rx_style),
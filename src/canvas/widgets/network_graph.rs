@@ -1,9 +1,13 @@
 use lazy_static::lazy_static;
-use std::cmp::max;
+use std::{borrow::Cow, cmp::max};
 
 use crate::{
     app::App,
-    canvas::{drawing_utils::get_variable_intrinsic_widths, Painter},
+    canvas::{
+        drawing_utils::{get_pan_indicator, get_time_axis_labels, get_variable_intrinsic_widths},
+        half_block::GraphSeries,
+        Painter,
+    },
     constants::*,
     utils::gen_util::*,
 };
@@ -11,18 +15,23 @@ use crate::{
 use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Rect},
-    symbols::Marker,
+    style::Style,
     terminal::Frame,
-    widgets::{Axis, Block, Borders, Chart, Dataset, Row, Table},
+    widgets::{Axis, Block, Borders, Chart, Row, Table},
 };
 
-const NETWORK_HEADERS: [&str; 4] = ["RX", "TX", "Total RX", "Total TX"];
+const NETWORK_HEADERS: [&str; 4] = ["RX", "TX", "Boot RX", "Boot TX"];
+const NETWORK_SESSION_HEADERS: [&str; 4] = ["RX", "TX", "Session RX", "Session TX"];
 
 lazy_static! {
     static ref NETWORK_HEADERS_LENS: Vec<usize> = NETWORK_HEADERS
         .iter()
         .map(|entry| max(FORCE_MIN_THRESHOLD, entry.len()))
         .collect::<Vec<_>>();
+    static ref NETWORK_SESSION_HEADERS_LENS: Vec<usize> = NETWORK_SESSION_HEADERS
+        .iter()
+        .map(|entry| max(FORCE_MIN_THRESHOLD, entry.len()))
+        .collect::<Vec<_>>();
 }
 
 pub trait NetworkGraphWidget {
@@ -44,7 +53,22 @@ impl NetworkGraphWidget for Painter {
     fn draw_network<B: Backend>(
         &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
     ) {
-        if app_state.app_config_fields.use_old_network_legend {
+        if !app_state.used_widgets.use_net {
+            let border_style = if app_state.current_widget.widget_id == widget_id {
+                self.colours.highlighted_border_style
+            } else {
+                self.colours.border_style
+            };
+            self.draw_disabled_widget(
+                f,
+                Block::default()
+                    .title(" Network ")
+                    .title_style(self.colours.widget_title_style)
+                    .borders(Borders::ALL)
+                    .border_style(border_style),
+                draw_loc,
+            );
+        } else if app_state.app_config_fields.use_old_network_legend {
             let network_chunk = Layout::default()
                 .direction(Direction::Vertical)
                 .margin(0)
@@ -168,66 +192,89 @@ impl NetworkGraphWidget for Painter {
             (true_max_val, labels)
         }
 
+        let total_label = if app_state.net_state.is_showing_session_total {
+            "Session"
+        } else {
+            "Boot"
+        };
+
+        let is_showing_split_axes = app_state.net_state.is_showing_split_axes;
         if let Some(network_widget_state) = app_state.net_state.widget_states.get_mut(&widget_id) {
-            let network_data_rx: &[(f64, f64)] = &app_state.canvas_data.network_data_rx;
-            let network_data_tx: &[(f64, f64)] = &app_state.canvas_data.network_data_tx;
-
-            let (max_range, labels) = adjust_network_data_point(
-                network_data_rx,
-                network_data_tx,
-                -(network_widget_state.current_display_time as f64),
-                0.0,
-            );
-            let display_time_labels = [
-                format!("{}s", network_widget_state.current_display_time / 1000),
-                "0s".to_string(),
+            // Hidden series keep their legend entries, but are left out of the graph and scaling.
+            let network_data_rx: &[(f64, f64)] = if network_widget_state.is_rx_hidden {
+                &[]
+            } else {
+                &app_state.canvas_data.network_data_rx
+            };
+            let network_data_tx: &[(f64, f64)] = if network_widget_state.is_tx_hidden {
+                &[]
+            } else {
+                &app_state.canvas_data.network_data_tx
+            };
+            let rx_style = if network_widget_state.is_rx_hidden {
+                self.colours.disabled_text_style
+            } else {
+                self.colours.rx_style
+            };
+            let tx_style = if network_widget_state.is_tx_hidden {
+                self.colours.disabled_text_style
+            } else {
+                self.colours.tx_style
+            };
+
+            let x_bounds = [
+                -((network_widget_state.current_display_time + network_widget_state.pan_offset)
+                    as f64),
+                -(network_widget_state.pan_offset as f64),
             ];
-            let x_axis = if app_state.app_config_fields.hide_time
+            let display_time_labels = get_time_axis_labels(
+                network_widget_state.current_display_time,
+                network_widget_state.pan_offset,
+            );
+            let is_showing_time_labels = if app_state.app_config_fields.hide_time
                 || (app_state.app_config_fields.autohide_time
                     && network_widget_state.autohide_timer.is_none())
             {
-                Axis::default().bounds([-(network_widget_state.current_display_time as f64), 0.0])
+                false
             } else if let Some(time) = network_widget_state.autohide_timer {
                 if std::time::Instant::now().duration_since(time).as_millis()
                     < AUTOHIDE_TIMEOUT_MILLISECONDS as u128
                 {
-                    Axis::default()
-                        .bounds([-(network_widget_state.current_display_time as f64), 0.0])
-                        .style(self.colours.graph_style)
-                        .labels(&display_time_labels)
-                        .labels_style(self.colours.graph_style)
+                    true
                 } else {
                     network_widget_state.autohide_timer = None;
-                    Axis::default()
-                        .bounds([-(network_widget_state.current_display_time as f64), 0.0])
+                    false
                 }
-            } else if draw_loc.height < TIME_LABEL_HEIGHT_LIMIT {
-                Axis::default().bounds([-(network_widget_state.current_display_time as f64), 0.0])
             } else {
+                draw_loc.height >= TIME_LABEL_HEIGHT_LIMIT
+            };
+            let time_labels = if is_showing_time_labels {
+                Some(&display_time_labels[..])
+            } else {
+                None
+            };
+            let x_axis = if let Some(time_labels) = time_labels {
                 Axis::default()
-                    .bounds([-(network_widget_state.current_display_time as f64), 0.0])
+                    .bounds(x_bounds)
                     .style(self.colours.graph_style)
-                    .labels(&display_time_labels)
+                    .labels(time_labels)
                     .labels_style(self.colours.graph_style)
+            } else {
+                Axis::default().bounds(x_bounds)
             };
 
-            let y_axis_labels = labels;
-            let y_axis = Axis::default()
-                .style(self.colours.graph_style)
-                .bounds([0.0, max_range])
-                .labels(&y_axis_labels)
-                .labels_style(self.colours.graph_style);
-
+            let pan_indicator = get_pan_indicator(network_widget_state.pan_offset);
             let title = if app_state.is_expanded {
-                const TITLE_BASE: &str = " Network ── Esc to go back ";
+                const TITLE_BASE: &str = " Network ─  ── Esc to go back ";
                 format!(
-                    " Network ─{}─ Esc to go back ",
-                    "─".repeat(
-                        usize::from(draw_loc.width).saturating_sub(TITLE_BASE.chars().count() + 2)
-                    )
+                    " Network ─ {} ─{}─ Esc to go back ",
+                    pan_indicator,
+                    "─".repeat(usize::from(draw_loc.width).saturating_sub(
+                        TITLE_BASE.chars().count() + pan_indicator.chars().count() + 2
+                    ))
                 )
             } else {
-                " Network ".to_string()
+                format!(" Network ─ {} ", pan_indicator)
             };
             let title_style = if app_state.is_expanded {
                 self.colours.highlighted_border_style
@@ -241,104 +288,206 @@ impl NetworkGraphWidget for Painter {
                 (Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))
             };
 
-            let dataset = if app_state.app_config_fields.use_old_network_legend && !hide_legend {
-                let mut ret_val = vec![];
-                ret_val.push(
-                    Dataset::default()
-                        .name(format!("RX: {:7}", app_state.canvas_data.rx_display))
-                        .marker(if app_state.app_config_fields.use_dot {
-                            Marker::Dot
-                        } else {
-                            Marker::Braille
-                        })
-                        .style(self.colours.rx_style)
-                        .data(&network_data_rx)
-                        .graph_type(tui::widgets::GraphType::Line),
-                );
+            // The series alternate between RX and TX in both legend styles.
+            let series_entries: Vec<(Cow<'_, str>, Style, &[Point])> =
+                if app_state.app_config_fields.use_old_network_legend && !hide_legend {
+                    vec![
+                        (
+                            Cow::Owned(format!("RX: {:7}", app_state.canvas_data.rx_display)),
+                            rx_style,
+                            network_data_rx,
+                        ),
+                        (
+                            Cow::Owned(format!("TX: {:7}", app_state.canvas_data.tx_display)),
+                            tx_style,
+                            network_data_tx,
+                        ),
+                        (
+                            Cow::Owned(format!(
+                                "{} RX: {:7}",
+                                total_label, app_state.canvas_data.total_rx_display
+                            )),
+                            self.colours.total_rx_style,
+                            &[][..],
+                        ),
+                        (
+                            Cow::Owned(format!(
+                                "{} TX: {:7}",
+                                total_label, app_state.canvas_data.total_tx_display
+                            )),
+                            self.colours.total_tx_style,
+                            &[][..],
+                        ),
+                    ]
+                } else {
+                    vec![
+                        (
+                            Cow::Borrowed(app_state.canvas_data.rx_display.as_str()),
+                            rx_style,
+                            network_data_rx,
+                        ),
+                        (
+                            Cow::Borrowed(app_state.canvas_data.tx_display.as_str()),
+                            tx_style,
+                            network_data_tx,
+                        ),
+                    ]
+                };
+            let graph_marker = &app_state.app_config_fields.graph_marker;
+            let raw_graphs = app_state.app_config_fields.raw_graphs;
+            let is_y_inverted = network_widget_state.is_y_inverted;
+            // Labels are listed from the bottom of the y-axis up, so they are reversed to match
+            // inverted graphs.
+            let orient_labels = |mut labels: Vec<String>| -> Vec<String> {
+                if is_y_inverted {
+                    labels.reverse();
+                }
+                labels
+            };
 
-                ret_val.push(
-                    Dataset::default()
-                        .name(format!("TX: {:7}", app_state.canvas_data.tx_display))
-                        .marker(if app_state.app_config_fields.use_dot {
-                            Marker::Dot
-                        } else {
-                            Marker::Braille
-                        })
-                        .style(self.colours.tx_style)
-                        .data(&network_data_tx)
-                        .graph_type(tui::widgets::GraphType::Line),
+            let block = Block::default()
+                .title(&title)
+                .title_style(title_style)
+                .borders(Borders::ALL)
+                .border_style(if app_state.current_widget.widget_id == widget_id {
+                    self.colours.highlighted_border_style
+                } else {
+                    self.colours.border_style
+                });
+
+            if is_showing_split_axes {
+                // RX and TX each get their own chart and y-axis, so that a small rate isn't
+                // flattened against a much larger one.
+                let (rx_max_range, rx_labels) =
+                    adjust_network_data_point(network_data_rx, &[], x_bounds[0], x_bounds[1]);
+                let (tx_max_range, tx_labels) =
+                    adjust_network_data_point(&[], network_data_tx, x_bounds[0], x_bounds[1]);
+
+                // Pad the labels to the same width so both graphs start at the same column.
+                let label_width = rx_labels
+                    .iter()
+                    .chain(tx_labels.iter())
+                    .map(|label| label.chars().count())
+                    .max()
+                    .unwrap_or(0);
+                let pad_labels = |labels: Vec<String>| -> Vec<String> {
+                    labels
+                        .into_iter()
+                        .map(|label| format!("{:>width$}", label, width = label_width))
+                        .collect()
+                };
+                let rx_labels = orient_labels(pad_labels(rx_labels));
+                let tx_labels = orient_labels(pad_labels(tx_labels));
+
+                let mut rx_series = GraphSeries::new(graph_marker, raw_graphs);
+                let mut tx_series = GraphSeries::new(graph_marker, raw_graphs);
+                rx_series.invert_y(is_y_inverted);
+                tx_series.invert_y(is_y_inverted);
+                for (itx, (name, style, data)) in series_entries.into_iter().enumerate() {
+                    if itx % 2 == 0 {
+                        rx_series.push(name, style, data);
+                    } else {
+                        tx_series.push(name, style, data);
+                    }
+                }
+
+                let split_chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .margin(0)
+                    .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)].as_ref())
+                    .split(block.inner(draw_loc));
+                f.render_widget(block, draw_loc);
+
+                rx_series.draw_points(
+                    f,
+                    self,
+                    split_chunks[0],
+                    None,
+                    &rx_labels,
+                    x_bounds,
+                    [0.0, rx_max_range],
                 );
-                ret_val.push(
-                    Dataset::default()
-                        .name(format!(
-                            "Total RX: {:7}",
-                            app_state.canvas_data.total_rx_display
-                        ))
-                        .style(self.colours.total_rx_style),
+                f.render_widget(
+                    Chart::default()
+                        .datasets(&rx_series.datasets())
+                        .x_axis(Axis::<String>::default().bounds(x_bounds))
+                        .y_axis(
+                            Axis::default()
+                                .title("RX")
+                                .title_style(self.colours.rx_style)
+                                .style(self.colours.graph_style)
+                                .bounds([0.0, rx_max_range])
+                                .labels(&rx_labels)
+                                .labels_style(self.colours.graph_style),
+                        )
+                        .hidden_legend_constraints(legend_constraints),
+                    split_chunks[0],
                 );
-
-                ret_val.push(
-                    Dataset::default()
-                        .name(format!(
-                            "Total TX: {:7}",
-                            app_state.canvas_data.total_tx_display
-                        ))
-                        .style(self.colours.total_tx_style),
+                tx_series.draw_points(
+                    f,
+                    self,
+                    split_chunks[1],
+                    time_labels,
+                    &tx_labels,
+                    x_bounds,
+                    [0.0, tx_max_range],
+                );
+                f.render_widget(
+                    Chart::default()
+                        .datasets(&tx_series.datasets())
+                        .x_axis(x_axis)
+                        .y_axis(
+                            Axis::default()
+                                .title("TX")
+                                .title_style(self.colours.tx_style)
+                                .style(self.colours.graph_style)
+                                .bounds([0.0, tx_max_range])
+                                .labels(&tx_labels)
+                                .labels_style(self.colours.graph_style),
+                        )
+                        .hidden_legend_constraints(legend_constraints),
+                    split_chunks[1],
                 );
-
-                ret_val
             } else {
-                let mut ret_val = vec![];
-
-                ret_val.push(
-                    Dataset::default()
-                        .name(&app_state.canvas_data.rx_display)
-                        .marker(if app_state.app_config_fields.use_dot {
-                            Marker::Dot
-                        } else {
-                            Marker::Braille
-                        })
-                        .style(self.colours.rx_style)
-                        .data(&network_data_rx)
-                        .graph_type(tui::widgets::GraphType::Line),
+                let (max_range, y_axis_labels) = adjust_network_data_point(
+                    network_data_rx,
+                    network_data_tx,
+                    x_bounds[0],
+                    x_bounds[1],
                 );
-
-                ret_val.push(
-                    Dataset::default()
-                        .name(&app_state.canvas_data.tx_display)
-                        .marker(if app_state.app_config_fields.use_dot {
-                            Marker::Dot
-                        } else {
-                            Marker::Braille
-                        })
-                        .style(self.colours.tx_style)
-                        .data(&network_data_tx)
-                        .graph_type(tui::widgets::GraphType::Line),
+                let y_axis_labels = orient_labels(y_axis_labels);
+                let y_axis = Axis::default()
+                    .style(self.colours.graph_style)
+                    .bounds([0.0, max_range])
+                    .labels(&y_axis_labels)
+                    .labels_style(self.colours.graph_style);
+
+                let mut graph_series = GraphSeries::new(graph_marker, raw_graphs);
+                graph_series.invert_y(is_y_inverted);
+                for (name, style, data) in series_entries {
+                    graph_series.push(name, style, data);
+                }
+                graph_series.draw_points(
+                    f,
+                    self,
+                    block.inner(draw_loc),
+                    time_labels,
+                    &y_axis_labels,
+                    x_bounds,
+                    [0.0, max_range],
                 );
 
-                ret_val
-            };
-
-            f.render_widget(
-                // Chart::new(dataset)
-                Chart::default()
-                    .datasets(&dataset)
-                    .block(
-                        Block::default()
-                            .title(&title)
-                            .title_style(title_style)
-                            .borders(Borders::ALL)
-                            .border_style(if app_state.current_widget.widget_id == widget_id {
-                                self.colours.highlighted_border_style
-                            } else {
-                                self.colours.border_style
-                            }),
-                    )
-                    .x_axis(x_axis)
-                    .y_axis(y_axis)
-                    .hidden_legend_constraints(legend_constraints),
-                draw_loc,
-            );
+                f.render_widget(
+                    // Chart::new(dataset)
+                    Chart::default()
+                        .datasets(&graph_series.datasets())
+                        .block(block)
+                        .x_axis(x_axis)
+                        .y_axis(y_axis)
+                        .hidden_legend_constraints(legend_constraints),
+                    draw_loc,
+                );
+            }
         }
     }
 
@@ -363,7 +512,11 @@ impl NetworkGraphWidget for Painter {
 
         // Calculate widths
         let width_ratios: Vec<f64> = vec![0.25, 0.25, 0.25, 0.25];
-        let lens: &[usize] = &NETWORK_HEADERS_LENS;
+        let (headers, lens): (&[&str], &[usize]) = if app_state.net_state.is_showing_session_total {
+            (&NETWORK_SESSION_HEADERS, &NETWORK_SESSION_HEADERS_LENS)
+        } else {
+            (&NETWORK_HEADERS, &NETWORK_HEADERS_LENS)
+        };
         let width = f64::from(draw_loc.width);
 
         let variable_intrinsic_results =
@@ -372,7 +525,7 @@ impl NetworkGraphWidget for Painter {
 
         // Draw
         f.render_widget(
-            Table::new(NETWORK_HEADERS.iter(), mapped_network)
+            Table::new(headers.iter(), mapped_network)
                 .block(Block::default().borders(Borders::ALL).border_style(
                     if app_state.current_widget.widget_id == widget_id {
                         self.colours.highlighted_border_style
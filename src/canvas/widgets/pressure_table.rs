@@ -0,0 +1,107 @@
+use lazy_static::lazy_static;
+use std::cmp::max;
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    terminal::Frame,
+    widgets::{Block, Borders, Row, Table},
+};
+
+use crate::{
+    app,
+    canvas::{drawing_utils::get_variable_intrinsic_widths, Painter},
+    constants::*,
+};
+
+const PRESSURE_HEADERS: [&str; 5] = ["Resource", "Some 10s", "Some 60s", "Full 10s", "Full 60s"];
+
+lazy_static! {
+    static ref PRESSURE_HEADERS_LENS: Vec<usize> = PRESSURE_HEADERS
+        .iter()
+        .map(|entry| max(FORCE_MIN_THRESHOLD, entry.len()))
+        .collect::<Vec<_>>();
+}
+pub trait PressureTableWidget {
+    fn draw_pressure_table<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut app::App, draw_loc: Rect, widget_id: u64,
+    );
+}
+
+impl PressureTableWidget for Painter {
+    fn draw_pressure_table<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut app::App, draw_loc: Rect, widget_id: u64,
+    ) {
+        let pressure_rows = app_state
+            .canvas_data
+            .pressure_data
+            .iter()
+            .map(|pressure_row| Row::Data(pressure_row.iter()));
+
+        let table_gap = if draw_loc.height < TABLE_GAP_HEIGHT_LIMIT {
+            0
+        } else {
+            app_state.app_config_fields.table_gap
+        };
+
+        // Calculate widths
+        let width_ratios = [0.2, 0.2, 0.2, 0.2, 0.2];
+        let variable_intrinsic_results =
+            get_variable_intrinsic_widths(draw_loc.width, &width_ratios, &PRESSURE_HEADERS_LENS);
+        let intrinsic_widths = &(variable_intrinsic_results.0)[0..variable_intrinsic_results.1];
+
+        let border_and_title_style = if widget_id == app_state.current_widget.widget_id {
+            self.colours.highlighted_border_style
+        } else {
+            self.colours.border_style
+        };
+
+        let title = if app_state.is_expanded {
+            const TITLE_BASE: &str = " Pressure ── Esc to go back ";
+            format!(
+                " Pressure ─{}─ Esc to go back ",
+                "─".repeat(
+                    usize::from(draw_loc.width).saturating_sub(TITLE_BASE.chars().count() + 2)
+                )
+            )
+        } else {
+            " Pressure ".to_string()
+        };
+        let title_style = if app_state.is_expanded {
+            border_and_title_style
+        } else {
+            self.colours.widget_title_style
+        };
+
+        let pressure_block = Block::default()
+            .title(&title)
+            .title_style(title_style)
+            .borders(Borders::ALL)
+            .border_style(border_and_title_style);
+
+        // Draw
+        f.render_widget(
+            Table::new(PRESSURE_HEADERS.iter(), pressure_rows)
+                .block(pressure_block)
+                .header_style(self.colours.table_header_style)
+                .style(self.colours.text_style)
+                .widths(
+                    &(intrinsic_widths
+                        .iter()
+                        .map(|calculated_width| Constraint::Length(*calculated_width as u16))
+                        .collect::<Vec<_>>()),
+                )
+                .header_gap(table_gap),
+            draw_loc,
+        );
+
+        if app_state.should_get_widget_bounds() {
+            // Update draw loc in widget map
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+}
@@ -1,23 +1,32 @@
 use crate::{
-    app::{data_harvester::processes::ProcessSorting, App},
+    app::{
+        data_harvester::processes::{ProcessDetails, ProcessSorting},
+        App, ExpandedProcessView,
+    },
     canvas::{
         drawing_utils::{
             get_search_start_position, get_start_position, get_variable_intrinsic_widths,
         },
-        Painter,
+        Painter, SelectionStyle,
     },
     constants::*,
+    data_conversion::{
+        get_displayed_command, CommandDisplay, ConvertedProcessData, ProcessDiffStatus,
+        ZombieWarning,
+    },
+    utils::gen_util::{get_exact_byte_values, ByteFormat},
 };
 
 use tui::{
     backend::Backend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Modifier,
     terminal::Frame,
     widgets::{Block, Borders, Paragraph, Row, Table, Text},
 };
 
 use unicode_segmentation::{GraphemeIndices, UnicodeSegmentation};
-use unicode_width::UnicodeWidthStr;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 pub trait ProcessTableWidget {
     /// Draws and handles all process-related drawing.  Use this.
@@ -116,15 +125,62 @@ impl ProcessTableWidget for Painter {
                 .direction(Direction::Horizontal)
                 .split(draw_loc)[0];
 
+            let selection_style = &app_state.app_config_fields.selection_style;
             let (border_and_title_style, highlight_style) = if is_on_widget {
                 (
                     self.colours.highlighted_border_style,
-                    self.colours.currently_selected_text_style,
+                    match selection_style {
+                        SelectionStyle::FullRow => self.colours.currently_selected_text_style,
+                        SelectionStyle::Arrow => self.colours.text_style,
+                        SelectionStyle::Bold => self.colours.text_style.modifier(Modifier::BOLD),
+                    },
                 )
             } else {
                 (self.colours.border_style, self.colours.text_style)
             };
+            let highlight_symbol = if is_on_widget && *selection_style == SelectionStyle::Arrow {
+                "▶ "
+            } else {
+                ""
+            };
 
+            let title_name = if app_state.canvas_data.process_diff_baseline.is_some() {
+                "Processes vs. Baseline"
+            } else {
+                "Processes"
+            };
+            let title_name = match app_state.canvas_data.process_summary_map.get(&widget_id) {
+                Some(process_summary) => format!("{}: {}", title_name, process_summary),
+                None => title_name.to_string(),
+            };
+            let title_name = match proc_widget_state.spawn_tracker.spawned_count {
+                0 => title_name,
+                spawned_count => format!("{} (+{} new)", title_name, spawned_count),
+            };
+            let title_name = if proc_widget_state.is_showing_only_own {
+                format!("{} (own only)", title_name)
+            } else {
+                title_name
+            };
+            let title_name = if app_state.app_config_fields.per_core_cpu {
+                format!("{} (CPU% per core)", title_name)
+            } else {
+                title_name
+            };
+            let zombie_warning = &app_state.canvas_data.zombie_warning;
+            let title_name = match zombie_warning {
+                Some(ZombieWarning {
+                    zombie_count,
+                    likely_parent_pid: Some(likely_parent_pid),
+                }) => format!(
+                    "{} [{} zombies, parent {}]",
+                    title_name, zombie_count, likely_parent_pid
+                ),
+                Some(ZombieWarning { zombie_count, .. }) => {
+                    format!("{} [{} zombies]", title_name, zombie_count)
+                }
+                None => title_name,
+            };
             let title = if draw_border {
                 if app_state.is_expanded
                     && !proc_widget_state
@@ -133,22 +189,23 @@ impl ProcessTableWidget for Painter {
                         .is_enabled
                     && !proc_widget_state.is_sort_open
                 {
-                    const TITLE_BASE: &str = " Processes ── Esc to go back ";
                     format!(
-                        " Processes ─{}─ Esc to go back ",
-                        "─".repeat(
-                            usize::from(draw_loc.width)
-                                .saturating_sub(TITLE_BASE.chars().count() + 2)
-                        )
+                        " {} ─{}─ Esc to go back ",
+                        title_name,
+                        "─".repeat(usize::from(draw_loc.width).saturating_sub(
+                            title_name.chars().count() + " ── Esc to go back ".chars().count() + 3
+                        ))
                     )
                 } else {
-                    " Processes ".to_string()
+                    format!(" {} ", title_name)
                 }
             } else {
                 String::default()
             };
 
-            let title_style = if app_state.is_expanded {
+            let title_style = if zombie_warning.is_some() {
+                self.colours.mem_alarm_style
+            } else if app_state.is_expanded {
                 border_and_title_style
             } else {
                 self.colours.widget_title_style
@@ -197,59 +254,142 @@ impl ProcessTableWidget for Painter {
                 };
 
                 let sliced_vec = &process_data[start_position..];
+                let selected_offset = proc_widget_state
+                    .scroll_state
+                    .current_scroll_position
+                    .saturating_sub(start_position);
+
+                let table_inner_loc = process_block.inner(margined_draw_loc);
+
+                // Only show the details if the expanded process is the one that is selected.
+                let expanded_process = sliced_vec.get(selected_offset).filter(|process| {
+                    !proc_widget_state.is_grouped
+                        && proc_widget_state.expanded_pid == Some(process.pid)
+                });
+                let detail_lines = match (
+                    expanded_process,
+                    proc_widget_state.expanded_view,
+                    &proc_widget_state.expanded_process_details,
+                ) {
+                    (Some(_), ExpandedProcessView::Details, Some(details)) => {
+                        get_process_detail_lines(details, &app_state.app_config_fields.byte_format)
+                    }
+                    (Some(process), ExpandedProcessView::Command, _) => {
+                        get_command_lines(&process.command, usize::from(table_inner_loc.width))
+                    }
+                    _ => Vec::new(),
+                };
+
+                // Formatting every row is costly with many processes, so the cells are only rebuilt
+                // when the process data or columns change.  Scrolling just picks other cached rows.
+                if proc_widget_state.is_process_data_dirty
+                    || proc_widget_state.cached_process_rows.len() != process_data.len()
+                {
+                    let is_proc_widget_grouped = proc_widget_state.is_grouped;
+                    let is_using_command = proc_widget_state.is_using_command;
+                    let is_tree = proc_widget_state.is_tree_mode;
+                    let mem_enabled = proc_widget_state.columns.is_enabled(&ProcessSorting::Mem);
+                    let ppid_enabled = proc_widget_state
+                        .columns
+                        .is_enabled(&ProcessSorting::ParentPid);
+                    let state_enabled =
+                        proc_widget_state.columns.is_enabled(&ProcessSorting::State);
+                    let percent_precision =
+                        app_state.app_config_fields.percent_precision.unwrap_or(1);
+                    let command_display = &app_state.app_config_fields.command_display;
+                    let byte_format = &app_state.app_config_fields.byte_format;
+
+                    proc_widget_state.cached_process_rows = process_data
+                        .iter()
+                        .map(|process| {
+                            get_process_row_cells(
+                                process,
+                                is_proc_widget_grouped,
+                                is_using_command,
+                                command_display,
+                                is_tree,
+                                mem_enabled,
+                                ppid_enabled,
+                                state_enabled,
+                                percent_precision,
+                                byte_format,
+                            )
+                        })
+                        .collect();
+                    proc_widget_state.is_process_data_dirty = false;
+                }
+
                 let proc_table_state = &mut proc_widget_state.scroll_state.table_state;
-                proc_table_state.select(Some(
-                    proc_widget_state
-                        .scroll_state
-                        .current_scroll_position
-                        .saturating_sub(start_position),
-                ));
+                proc_table_state.select(Some(selected_offset));
 
                 // Draw!
-                let is_proc_widget_grouped = proc_widget_state.is_grouped;
-                let is_using_command = proc_widget_state.is_using_command;
-                let is_tree = proc_widget_state.is_tree_mode;
-                let mem_enabled = proc_widget_state.columns.is_enabled(&ProcessSorting::Mem);
-
-                // FIXME: [PROC OPTIMIZE] This can definitely be optimized; string references work fine here!
-                let process_rows = sliced_vec.iter().map(|process| {
-                    let data = vec![
-                        if is_proc_widget_grouped {
-                            process.group_pids.len().to_string()
-                        } else {
-                            process.pid.to_string()
-                        },
-                        if is_tree {
-                            if let Some(prefix) = &process.process_description_prefix {
-                                prefix.clone()
+                let watched_names = &app_state.proc_state.watched_names;
+                let spawn_tracker = &proc_widget_state.spawn_tracker;
+                let oom_score_warn_threshold = app_state.app_config_fields.oom_score_warn_threshold;
+                let zebra_stripes = app_state.app_config_fields.zebra_stripes;
+                let process_rows = sliced_vec
+                    .iter()
+                    .zip(&proc_widget_state.cached_process_rows[start_position..])
+                    .enumerate()
+                    .map(|(itx, (process, cells))| {
+                        let data = cells.iter();
+                        let diff_status = process.diff.map(|diff| diff.status);
+
+                        let row_style = if process.is_disabled_entry {
+                            Some(self.colours.disabled_entry_style)
+                        } else if process
+                            .group_pids
+                            .iter()
+                            .any(|pid| spawn_tracker.is_highlighted(*pid))
+                        {
+                            Some(self.colours.spawned_process_style)
+                        } else if diff_status == Some(ProcessDiffStatus::New) {
+                            Some(self.colours.new_process_style)
+                        } else if diff_status == Some(ProcessDiffStatus::Exited) {
+                            Some(self.colours.exited_process_style)
+                        } else if let Some(state_style) =
+                            self.colours.get_process_state_style(process.process_char)
+                        {
+                            Some(state_style)
+                        } else if process.is_stopped() {
+                            Some(self.colours.stopped_process_style)
+                        } else if oom_score_warn_threshold.is_some()
+                            && process.oom_score >= oom_score_warn_threshold
+                        {
+                            Some(self.colours.mem_alarm_style)
+                        } else if watched_names.contains(&process.name) {
+                            // Watched processes keep their pinned colour, but on a distinct background.
+                            Some(if process.is_pinned {
+                                self.colours
+                                    .pinned_process_style
+                                    .bg(self.colours.watched_process_style.bg)
                             } else {
-                                String::default()
-                            }
-                        } else if is_using_command {
-                            process.command.clone()
+                                self.colours.watched_process_style
+                            })
+                        } else if process.is_pinned {
+                            Some(self.colours.pinned_process_style)
                         } else {
-                            process.name.clone()
-                        },
-                        format!("{:.1}%", process.cpu_percent_usage),
-                        if mem_enabled {
-                            format!("{:.0}{}", process.mem_usage_str.0, process.mem_usage_str.1)
-                        } else {
-                            format!("{:.1}%", process.mem_percent_usage)
-                        },
-                        process.read_per_sec.clone(),
-                        process.write_per_sec.clone(),
-                        process.total_read.clone(),
-                        process.total_write.clone(),
-                        process.process_state.clone(),
-                    ]
-                    .into_iter();
-
-                    if process.is_disabled_entry {
-                        Row::StyledData(data, self.colours.disabled_text_style)
-                    } else {
-                        Row::Data(data)
-                    }
-                });
+                            None
+                        };
+
+                        match self.colours.get_table_row_style(
+                            row_style,
+                            start_position + itx,
+                            zebra_stripes,
+                        ) {
+                            Some(row_style) => Row::StyledData(data, row_style),
+                            None => Row::Data(data),
+                        }
+                    });
+
+                // Leave empty rows beneath the selected process for its details to be drawn over.
+                let empty_cells = vec![String::default()];
+                let mut process_rows = process_rows.collect::<Vec<_>>();
+                let detail_row_position = std::cmp::min(selected_offset + 1, process_rows.len());
+                process_rows.splice(
+                    detail_row_position..detail_row_position,
+                    detail_lines.iter().map(|_| Row::Data(empty_cells.iter())),
+                );
 
                 let process_headers = proc_widget_state.columns.get_column_headers(
                     &proc_widget_state.process_sorting_type,
@@ -265,19 +405,33 @@ impl ProcessTableWidget for Painter {
                 let width = f64::from(draw_loc.width);
 
                 // TODO: This is a ugly work-around for now.
-                let width_ratios = if proc_widget_state.is_grouped {
+                let mut width_ratios = if proc_widget_state.is_grouped {
                     if proc_widget_state.is_using_command {
-                        vec![0.05, 0.7, 0.05, 0.05, 0.0375, 0.0375, 0.0375, 0.0375]
+                        vec![
+                            0.05, 0.7, 0.05, 0.05, 0.05, 0.0375, 0.0375, 0.0375, 0.0375, 0.05,
+                            0.05, 0.05,
+                        ]
                     } else {
-                        vec![0.1, 0.2, 0.1, 0.1, 0.1, 0.1, 0.15, 0.15]
+                        vec![0.1, 0.2, 0.1, 0.1, 0.1, 0.1, 0.1, 0.15, 0.15, 0.1, 0.1, 0.1]
                     }
                 } else if proc_widget_state.is_using_command {
-                    vec![0.05, 0.7, 0.05, 0.05, 0.03, 0.03, 0.03, 0.03]
+                    vec![
+                        0.05, 0.7, 0.05, 0.05, 0.05, 0.03, 0.03, 0.03, 0.03, 0.05, 0.05, 0.05,
+                    ]
                 } else if proc_widget_state.is_tree_mode {
-                    vec![0.05, 0.4, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1]
+                    vec![0.05, 0.4, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1]
                 } else {
-                    vec![0.1, 0.2, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1]
+                    vec![
+                        0.1, 0.2, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1,
+                    ]
                 };
+                // The PPID column sits right after the PID column, and is as wide.
+                if proc_widget_state
+                    .columns
+                    .is_enabled(&ProcessSorting::ParentPid)
+                {
+                    width_ratios.insert(1, width_ratios[0]);
+                }
                 let variable_intrinsic_results = get_variable_intrinsic_widths(
                     width as u16,
                     &width_ratios,
@@ -292,6 +446,7 @@ impl ProcessTableWidget for Painter {
                         .block(process_block)
                         .header_style(self.colours.table_header_style)
                         .highlight_style(highlight_style)
+                        .highlight_symbol(highlight_symbol)
                         .style(self.colours.text_style)
                         .widths(
                             &(intrinsic_widths
@@ -305,6 +460,68 @@ impl ProcessTableWidget for Painter {
                     margined_draw_loc,
                     proc_table_state,
                 );
+
+                // Processes are always running, so an empty table means that nothing matched.
+                if process_data.is_empty() && !app_state.canvas_data.single_process_data.is_empty()
+                {
+                    let rows_top = table_inner_loc.y + 1 + table_gap;
+                    let rows_height =
+                        (table_inner_loc.y + table_inner_loc.height).saturating_sub(rows_top);
+                    let hint = if !proc_widget_state
+                        .process_search_state
+                        .search_state
+                        .is_blank_search
+                    {
+                        Some("Press / then Ctrl-u to clear the search")
+                    } else if proc_widget_state.is_showing_only_own {
+                        Some("Press u to show every user's processes")
+                    } else {
+                        None
+                    };
+                    let mut empty_text = vec![Text::styled(
+                        app_state.app_config_fields.process_empty_message.as_str(),
+                        self.colours.text_style,
+                    )];
+                    if let Some(hint) = hint {
+                        empty_text.push(Text::raw("\n"));
+                        empty_text.push(Text::styled(hint, self.colours.disabled_text_style));
+                    }
+                    let text_height = if hint.is_some() { 2 } else { 1 };
+
+                    if rows_height >= text_height {
+                        f.render_widget(
+                            Paragraph::new(empty_text.iter()).alignment(Alignment::Center),
+                            Rect::new(
+                                table_inner_loc.x,
+                                rows_top + (rows_height - text_height) / 2,
+                                table_inner_loc.width,
+                                text_height,
+                            ),
+                        );
+                    }
+                }
+
+                if !detail_lines.is_empty() {
+                    // The rows start after the header and the gap beneath it.
+                    let detail_top = table_inner_loc.y + 1 + table_gap + selected_offset as u16 + 1;
+                    let table_bottom = table_inner_loc.y + table_inner_loc.height;
+
+                    if detail_top < table_bottom {
+                        let detail_text = detail_lines
+                            .iter()
+                            .map(|line| Text::styled(line, self.colours.text_style))
+                            .collect::<Vec<_>>();
+                        f.render_widget(
+                            Paragraph::new(detail_text.iter()),
+                            Rect::new(
+                                table_inner_loc.x,
+                                detail_top,
+                                table_inner_loc.width,
+                                std::cmp::min(detail_lines.len() as u16, table_bottom - detail_top),
+                            ),
+                        );
+                    }
+                }
             } else {
                 f.render_widget(process_block, margined_draw_loc);
             }
@@ -379,7 +596,12 @@ impl ProcessTableWidget for Painter {
         {
             let is_on_widget = widget_id == app_state.current_widget.widget_id;
             let num_columns = usize::from(draw_loc.width);
-            let search_title = "> ";
+            // The prompt changes too, so an inverted search is obvious even without the options row.
+            let search_title = if proc_widget_state.process_search_state.is_inverted {
+                "!> "
+            } else {
+                "> "
+            };
 
             let num_chars_for_text = search_title.len();
             let cursor_position = proc_widget_state.get_cursor_position();
@@ -450,6 +672,12 @@ impl ProcessTableWidget for Painter {
                 self.colours.text_style
             };
 
+            let invert_style = if proc_widget_state.process_search_state.is_inverted {
+                self.colours.currently_selected_text_style
+            } else {
+                self.colours.text_style
+            };
+
             let option_text = vec![
                 Text::raw("\n"),
                 Text::styled(
@@ -466,6 +694,11 @@ impl ProcessTableWidget for Painter {
                     format!("Regex({})", if self.is_mac_os { "F3" } else { "Alt+R" }),
                     regex_style,
                 ),
+                Text::raw("  "),
+                Text::styled(
+                    format!("Invert({})", if self.is_mac_os { "F4" } else { "Alt+I" }),
+                    invert_style,
+                ),
             ];
 
             search_text.push(Text::raw("\n"));
@@ -568,7 +801,7 @@ impl ProcessTableWidget for Painter {
                         .unwrap()
                         .enabled
                 })
-                .map(|column_type| column_type.to_string())
+                .map(|column_type| proc_widget_state.columns.get_header(column_type))
                 .collect::<Vec<_>>();
 
             let table_gap = if draw_loc.height < TABLE_GAP_HEIGHT_LIMIT {
@@ -668,3 +901,251 @@ impl ProcessTableWidget for Painter {
         }
     }
 }
+
+/// Returns the text of each cell in a process's row.
+fn get_process_row_cells(
+    process: &ConvertedProcessData, is_grouped: bool, is_using_command: bool,
+    command_display: &CommandDisplay, is_tree: bool, mem_enabled: bool, ppid_enabled: bool,
+    state_enabled: bool, percent_precision: usize, byte_format: &ByteFormat,
+) -> Vec<String> {
+    // Against a baseline, usage is shown as the change since the baseline was marked.
+    let (cpu_cell, mem_cell) = if let Some(diff) = &process.diff {
+        (
+            format!("{:+.*}%", percent_precision, diff.cpu_percent_delta),
+            if mem_enabled {
+                let converted_delta =
+                    get_exact_byte_values(diff.mem_bytes_delta.abs() as u64, false, byte_format);
+                format!(
+                    "{}{:.0}{}",
+                    if diff.mem_bytes_delta < 0 { "-" } else { "+" },
+                    converted_delta.0,
+                    converted_delta.1
+                )
+            } else {
+                format!("{:+.*}%", percent_precision, diff.mem_percent_delta)
+            },
+        )
+    } else {
+        (
+            format!("{:.*}%", percent_precision, process.cpu_percent_usage),
+            if mem_enabled {
+                format!("{:.0}{}", process.mem_usage_str.0, process.mem_usage_str.1)
+            } else {
+                format!("{:.*}%", percent_precision, process.mem_percent_usage)
+            },
+        )
+    };
+
+    let mut cells = vec![
+        if is_grouped {
+            process.group_pids.len().to_string()
+        } else {
+            process.pid.to_string()
+        },
+        if is_tree {
+            if let Some(prefix) = &process.process_description_prefix {
+                prefix.clone()
+            } else {
+                String::default()
+            }
+        } else if is_using_command {
+            get_displayed_command(&process.command, command_display)
+        } else {
+            process.name.clone()
+        },
+        cpu_cell,
+        mem_cell,
+        process
+            .swap_bytes
+            .map(|swap_bytes| {
+                let converted_swap = get_exact_byte_values(swap_bytes, false, byte_format);
+                format!("{:.0}{}", converted_swap.0, converted_swap.1)
+            })
+            .unwrap_or_else(|| "N/A".to_string()),
+        process.read_per_sec.clone(),
+        process.write_per_sec.clone(),
+        process.total_read.clone(),
+        process.total_write.clone(),
+    ];
+    // The PPID column is hidden while grouped.
+    if ppid_enabled {
+        cells.insert(
+            1,
+            process
+                .ppid
+                .map(|ppid| ppid.to_string())
+                .unwrap_or_else(|| "N/A".to_string()),
+        );
+    }
+    // The state column is hidden while grouped.
+    if state_enabled {
+        cells.push(process.process_state.clone());
+    }
+    cells.push(
+        process
+            .thread_count
+            .map(|thread_count| thread_count.to_string())
+            .unwrap_or_else(|| "N/A".to_string()),
+    );
+    cells.push(
+        process
+            .oom_score
+            .map(|oom_score| oom_score.to_string())
+            .unwrap_or_else(|| "N/A".to_string()),
+    );
+    cells.push(
+        process
+            .cpu_affinity
+            .as_ref()
+            .map(|cpu_affinity| get_cpu_list_string(cpu_affinity))
+            .unwrap_or_else(|| "N/A".to_string()),
+    );
+    cells
+}
+
+/// Formats a sorted list of CPUs compactly, such as `0-3,8`.
+fn get_cpu_list_string(cpus: &[usize]) -> String {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for &cpu in cpus {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == cpu => *end = cpu,
+            _ => ranges.push((cpu, cpu)),
+        }
+    }
+
+    ranges
+        .iter()
+        .map(|&(start, end)| {
+            if start == end {
+                start.to_string()
+            } else {
+                format!("{}-{}", start, end)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Splits a command into lines that fit in `width` columns, breaking only between arguments
+/// unless a single argument is too long to fit.  Each option starts a new line, so it stays
+/// beside its value, such as `-cp` and its class path.
+fn get_command_lines(command: &str, width: usize) -> Vec<String> {
+    const FIRST_PREFIX: &str = "  Command: ";
+    const PREFIX: &str = "           ";
+    let line_width = width.saturating_sub(FIRST_PREFIX.len()).max(1);
+
+    let mut lines: Vec<String> = Vec::new();
+    let mut current_line = String::new();
+    for argument in command.split_whitespace() {
+        let current_width = UnicodeWidthStr::width(current_line.as_str());
+        if !current_line.is_empty()
+            && (argument.starts_with('-')
+                || current_width + 1 + UnicodeWidthStr::width(argument) > line_width)
+        {
+            lines.push(std::mem::take(&mut current_line));
+        } else if !current_line.is_empty() {
+            current_line.push(' ');
+        }
+
+        // Arguments that don't fit on a line of their own are split wherever they run out.
+        for character in argument.chars() {
+            let character_width = UnicodeWidthChar::width(character).unwrap_or(0);
+            if !current_line.is_empty()
+                && UnicodeWidthStr::width(current_line.as_str()) + character_width > line_width
+            {
+                lines.push(std::mem::take(&mut current_line));
+            }
+            current_line.push(character);
+        }
+    }
+    if !current_line.is_empty() {
+        lines.push(current_line);
+    }
+    if lines.is_empty() {
+        lines.push("N/A".to_string());
+    }
+
+    let line_count = lines.len();
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(itx, line)| {
+            format!(
+                "{}{}{}",
+                if itx == 0 { FIRST_PREFIX } else { PREFIX },
+                line,
+                if itx + 1 < line_count { "\n" } else { "" }
+            )
+        })
+        .collect()
+}
+
+fn get_process_detail_lines(details: &ProcessDetails, byte_format: &ByteFormat) -> Vec<String> {
+    let or_na = |value: &str| -> String {
+        if value.is_empty() {
+            "N/A".to_string()
+        } else {
+            value.to_string()
+        }
+    };
+
+    let mut lines = vec![
+        format!("  Command: {}\n", or_na(&details.command)),
+        format!("  Cwd:     {}\n", or_na(&details.cwd)),
+        format!("  Exe:     {}\n", or_na(&details.exe)),
+        format!(
+            "  Threads: {}\n",
+            details
+                .thread_count
+                .map(|thread_count| thread_count.to_string())
+                .unwrap_or_else(|| "N/A".to_string())
+        ),
+        format!("  User:    {}\n", details.user.as_deref().unwrap_or("N/A")),
+        format!(
+            "  OOM adj: {}\n",
+            details
+                .oom_score_adj
+                .map(|oom_score_adj| oom_score_adj.to_string())
+                .unwrap_or_else(|| "N/A".to_string())
+        ),
+        format!(
+            "  CPUs:    {}\n",
+            details
+                .cpu_affinity
+                .as_ref()
+                .map(|cpu_affinity| get_cpu_list_string(cpu_affinity))
+                .unwrap_or_else(|| "N/A".to_string())
+        ),
+    ];
+
+    // The largest memory regions each get their own line, lined up beneath the first.
+    if details.memory_regions.is_empty() {
+        lines.push("  Memory:  N/A".to_string());
+    } else {
+        lines.extend(
+            details
+                .memory_regions
+                .iter()
+                .enumerate()
+                .map(|(itx, (region, size))| {
+                    let (size_value, size_unit) = get_exact_byte_values(*size, false, byte_format);
+                    format!(
+                        "{}{:>6.1}{:<3} {}\n",
+                        if itx == 0 {
+                            "  Memory:  "
+                        } else {
+                            "           "
+                        },
+                        size_value,
+                        size_unit,
+                        region
+                    )
+                }),
+        );
+        if let Some(last_line) = lines.last_mut() {
+            last_line.pop();
+        }
+    }
+
+    lines
+}
@@ -1,4 +1,3 @@
-use lazy_static::lazy_static;
 use std::cmp::max;
 
 use tui::{
@@ -9,22 +8,15 @@ use tui::{
 };
 
 use crate::{
-    app,
+    app::{self, TempSorting},
     canvas::{
         drawing_utils::{get_start_position, get_variable_intrinsic_widths},
         Painter,
     },
     constants::*,
+    data_conversion::sort_temp_data,
 };
 
-const TEMP_HEADERS: [&str; 2] = ["Sensor", "Temp"];
-
-lazy_static! {
-    static ref TEMP_HEADERS_LENS: Vec<usize> = TEMP_HEADERS
-        .iter()
-        .map(|entry| max(FORCE_MIN_THRESHOLD, entry.len()))
-        .collect::<Vec<_>>();
-}
 pub trait TempTableWidget {
     fn draw_temp_table<B: Backend>(
         &self, f: &mut Frame<'_, B>, app_state: &mut app::App, draw_loc: Rect, draw_border: bool,
@@ -38,7 +30,13 @@ impl TempTableWidget for Painter {
         widget_id: u64,
     ) {
         if let Some(temp_widget_state) = app_state.temp_state.widget_states.get_mut(&widget_id) {
-            let temp_sensor_data: &mut [Vec<String>] = &mut app_state.canvas_data.temp_sensor_data;
+            let mut temp_sensor_data: Vec<_> =
+                app_state.canvas_data.temp_sensor_data.iter().collect();
+            sort_temp_data(
+                &mut temp_sensor_data,
+                temp_widget_state.sorting_type,
+                temp_widget_state.is_sort_descending,
+            );
 
             let table_gap = if draw_loc.height < TABLE_GAP_HEIGHT_LIMIT {
                 0
@@ -63,13 +61,46 @@ impl TempTableWidget for Painter {
                     .saturating_sub(start_position),
             ));
             let sliced_vec = &temp_sensor_data[start_position..];
-            let temperature_rows = sliced_vec.iter().map(|temp_row| Row::Data(temp_row.iter()));
+            let zebra_stripes = app_state.app_config_fields.zebra_stripes;
+            let temperature_rows = sliced_vec.iter().enumerate().map(|(itx, temp_row)| {
+                let data = vec![&temp_row.sensor, &temp_row.value].into_iter();
+                match self
+                    .colours
+                    .get_table_row_style(None, start_position + itx, zebra_stripes)
+                {
+                    Some(row_style) => Row::StyledData(data, row_style),
+                    None => Row::Data(data),
+                }
+            });
+
+            // The header shows the unit, as it may be hidden after each temperature, along with
+            // each column's sort shortcut and which column is sorted.
+            let sort_arrow = if temp_widget_state.is_sort_descending {
+                "▼"
+            } else {
+                "▲"
+            };
+            let (sensor_arrow, temp_arrow) = match temp_widget_state.sorting_type {
+                TempSorting::SensorName => (sort_arrow, ""),
+                TempSorting::Temperature => ("", sort_arrow),
+            };
+            let sensor_header = format!("Sensor(n){}", sensor_arrow);
+            let temp_header = format!(
+                "Temp ({})(t){}",
+                app_state.app_config_fields.temperature_type.unit_symbol(),
+                temp_arrow
+            );
+            let temp_headers = [sensor_header.as_str(), temp_header.as_str()];
+            let temp_headers_lens = temp_headers
+                .iter()
+                .map(|entry| max(FORCE_MIN_THRESHOLD, entry.len()))
+                .collect::<Vec<_>>();
 
             // Calculate widths
             let width = f64::from(draw_loc.width);
             let width_ratios = [0.5, 0.5];
             let variable_intrinsic_results =
-                get_variable_intrinsic_widths(width as u16, &width_ratios, &TEMP_HEADERS_LENS);
+                get_variable_intrinsic_widths(width as u16, &width_ratios, &temp_headers_lens);
             let intrinsic_widths = &(variable_intrinsic_results.0)[0..variable_intrinsic_results.1];
 
             let (border_and_title_style, highlight_style) = if is_on_widget {
@@ -121,22 +152,28 @@ impl TempTableWidget for Painter {
                 .split(draw_loc)[0];
 
             // Draw
-            f.render_stateful_widget(
-                Table::new(TEMP_HEADERS.iter(), temperature_rows)
-                    .block(temp_block)
-                    .header_style(self.colours.table_header_style)
-                    .highlight_style(highlight_style)
-                    .style(self.colours.text_style)
-                    .widths(
-                        &(intrinsic_widths
-                            .iter()
-                            .map(|calculated_width| Constraint::Length(*calculated_width as u16))
-                            .collect::<Vec<_>>()),
-                    )
-                    .header_gap(table_gap),
-                margined_draw_loc,
-                temp_table_state,
-            );
+            if !app_state.used_widgets.use_temp {
+                self.draw_disabled_widget(f, temp_block, margined_draw_loc);
+            } else {
+                f.render_stateful_widget(
+                    Table::new(temp_headers.iter(), temperature_rows)
+                        .block(temp_block)
+                        .header_style(self.colours.table_header_style)
+                        .highlight_style(highlight_style)
+                        .style(self.colours.text_style)
+                        .widths(
+                            &(intrinsic_widths
+                                .iter()
+                                .map(|calculated_width| {
+                                    Constraint::Length(*calculated_width as u16)
+                                })
+                                .collect::<Vec<_>>()),
+                        )
+                        .header_gap(table_gap),
+                    margined_draw_loc,
+                    temp_table_state,
+                );
+            }
 
             if app_state.should_get_widget_bounds() {
                 // Update draw loc in widget map
@@ -45,6 +45,14 @@ Sets the temperature type to Fahrenheit.\n\n",
 Sets the temperature type to Celsius.  This is the default
 option.\n\n",
         );
+    let raw_temperature = Arg::with_name("RAW_TEMPERATURE")
+        .long("raw_temperature")
+        .help("Shows raw temperature sensor values.")
+        .long_help(
+            "\
+Shows the raw value of temperature sensors, in millidegrees
+Celsius.  Useful for debugging sensor calibration.\n\n",
+        );
 
     // All flags.  These are in alphabetical order
     let autohide_time = Arg::with_name("AUTOHIDE_TIME")
@@ -81,6 +89,57 @@ custom layouts.\n\n",
             "\
 When searching for a process, enables case sensitivity by default.\n\n",
         );
+    let collect = Arg::with_name("COLLECT")
+        .long("collect")
+        .conflicts_with_all(&["REMOTE", "REPLAY", "STREAM_JSON"])
+        .help("Prints data frames for --remote instead of the UI.")
+        .long_help(
+            "\
+Instead of showing the UI, prints one line of JSON per update to
+stdout in the same format as --record, until stdout is closed.
+This is what --remote runs on the remote host.\n\n",
+        );
+    let color = Arg::with_name("COLOR")
+        .long("color")
+        .takes_value(true)
+        .value_name("DEPTH")
+        .help("Sets how many colours the terminal can show.")
+        .long_help(
+            "\
+Sets how many colours the terminal can show, which colours that it
+can't show are changed to the closest of.  By default, this is
+guessed from the COLORTERM and TERM environment variables.
+
+Supported depths:
++-----------+
+|    auto   |
++-----------+
+|     16    |
++-----------+
+|    256    |
++-----------+
+| truecolor |
++-----------+
+\n\n",
+        );
+    let crash_report = Arg::with_name("CRASH_REPORT")
+        .long("crash_report")
+        .help("Writes a crash report file if bottom crashes.")
+        .long_help(
+            "\
+If bottom crashes, writes a crash report with the panic message,
+a backtrace, the version, the OS, and the config in use to
+bottom/crash_report.txt in the data directory, such as
+~/.local/share on Linux, to attach to an issue.\n\n",
+        );
+    let disable_battery = Arg::with_name("DISABLE_BATTERY")
+        .long("disable_battery")
+        .help("Disables collecting battery data.")
+        .long_help(
+            "\
+Disables collecting battery data, even if a battery widget is
+in the layout.  The widget will instead show that it is disabled.\n\n",
+        );
     let disable_click = Arg::with_name("DISABLE_CLICK")
         .long("disable_click")
         .help("Disables mouse clicks.")
@@ -88,6 +147,41 @@ When searching for a process, enables case sensitivity by default.\n\n",
             "\
 Disables mouse clicks from interacting with the program.\n\n",
         );
+    // These are set in the config file, and are only flags so that --remote can pass them on to
+    // --collect, which harvests on the remote host like this one would.
+    let disable_cpu_breakdown = Arg::with_name("DISABLE_CPU_BREAKDOWN")
+        .long("disable_cpu_breakdown")
+        .hidden(true);
+    let disable_process_io = Arg::with_name("DISABLE_PROCESS_IO")
+        .long("disable_process_io")
+        .hidden(true);
+    let show_scheduler_activity = Arg::with_name("SHOW_SCHEDULER_ACTIVITY")
+        .long("show_scheduler_activity")
+        .hidden(true);
+    let disable_disk = Arg::with_name("DISABLE_DISK")
+        .long("disable_disk")
+        .help("Disables collecting disk data.")
+        .long_help(
+            "\
+Disables collecting disk usage and IO data, even if a disk widget
+is in the layout.  The widget will instead show that it is disabled.\n\n",
+        );
+    let disable_network = Arg::with_name("DISABLE_NETWORK")
+        .long("disable_network")
+        .help("Disables collecting network data.")
+        .long_help(
+            "\
+Disables collecting network data, even if a network widget is
+in the layout.  The widget will instead show that it is disabled.\n\n",
+        );
+    let disable_temperature = Arg::with_name("DISABLE_TEMPERATURE")
+        .long("disable_temperature")
+        .help("Disables collecting temperature data.")
+        .long_help(
+            "\
+Disables collecting temperature data, even if a temperature widget
+is in the layout.  The widget will instead show that it is disabled.\n\n",
+        );
     let dot_marker = Arg::with_name("DOT_MARKER")
         .short("m")
         .long("dot_marker")
@@ -97,6 +191,17 @@ Disables mouse clicks from interacting with the program.\n\n",
 Uses a dot marker for graphs as opposed to the default braille
 marker.\n\n",
         );
+    let duration = Arg::with_name("DURATION")
+        .long("duration")
+        .takes_value(true)
+        .value_name("SECONDS")
+        .help("Quits after running for the given number of seconds.")
+        .long_help(
+            "\
+Runs as usual, then quits after the given number of seconds,
+restoring the terminal as if quit normally.  Useful for taking
+screenshots or for smoke tests.  The minimum is 1 second.\n\n\n",
+        );
     let group = Arg::with_name("GROUP_PROCESSES")
         .short("g")
         .long("group")
@@ -120,6 +225,14 @@ Hides the average CPU usage from being shown.\n\n",
             "\
 Hides the spacing between table headers and entries.\n\n",
         );
+    let hide_temperature_unit = Arg::with_name("HIDE_TEMPERATURE_UNIT")
+        .long("hide_temperature_unit")
+        .help("Hides the unit after temperatures.")
+        .long_help(
+            "\
+Hides the unit after each temperature in the temperature
+widget.  The unit is still shown in the column header.\n\n",
+        );
     let hide_time = Arg::with_name("HIDE_TIME")
         .long("hide_time")
         .help("Completely hides the time scaling.")
@@ -143,6 +256,39 @@ Puts the CPU chart legend to the left side rather than the right side.\n\n",
             "\
 When searching for a process, enables regex by default.\n\n",
         );
+    let remote = Arg::with_name("REMOTE")
+        .long("remote")
+        .takes_value(true)
+        .value_name("HOST")
+        .conflicts_with("REPLAY")
+        .help("Shows data collected on another host over SSH.")
+        .long_help(
+            "\
+Shows data collected on the given host, such as user@host, rather
+than this system.  This runs `btm --collect` over SSH, so bottom
+must be installed on the host, and SSH must be able to log in
+without a password prompt.  If the connection drops, bottom keeps
+trying to reconnect.  Processes cannot be killed while monitoring
+a remote host.\n\n",
+        );
+    let show_self_usage = Arg::with_name("SHOW_SELF_USAGE")
+        .long("show_self_usage")
+        .help("Shows bottom's own CPU and memory usage.")
+        .long_help(
+            "\
+Shows the CPU and memory usage of bottom itself in the top right
+corner.  This requires process data, so processes are collected
+even if there is no process widget.\n\n",
+        );
+    let stream_json = Arg::with_name("STREAM_JSON")
+        .long("stream_json")
+        .help("Prints a JSON snapshot per update instead of the UI.")
+        .long_help(
+            "\
+Instead of showing the UI, prints one line of JSON per update to
+stdout containing a timestamped snapshot of all collected data,
+until interrupted.  Useful for feeding other tools like jq.\n\n",
+        );
     let current_usage = Arg::with_name("USE_CURR_USAGE")
         .short("u")
         .long("current_usage")
@@ -193,6 +339,20 @@ file in the TOML format. If it doesn't exist, one is created.\n\n\n",
 Default time value for graphs in milliseconds.  The minimum
 time is 30s (30000), and the default is 60s (60000).\n\n\n",
         );
+    let default_widget = Arg::with_name("DEFAULT_WIDGET")
+        .long("default_widget")
+        .alias("default-widget")
+        .takes_value(true)
+        .value_name("WIDGET TYPE")
+        .help("Sets which widget is selected on startup.")
+        .long_help(
+            "\
+Sets which widget is selected on startup, using the first widget
+of that type in the layout.  Supports the same widget names as
+'default_widget_type', which takes precedence if it is also set.
+If there is no widget of that type in the layout, the first widget
+is selected instead.\n\n",
+        );
     let default_widget_count = Arg::with_name("DEFAULT_WIDGET_COUNT")
         .long("default_widget_count")
         .takes_value(true)
@@ -255,6 +415,60 @@ Supported widget names:
 +--------------------------+
 |       batt, battery      |
 +--------------------------+
+\n\n",
+        );
+    let label = Arg::with_name("LABEL")
+        .long("label")
+        .takes_value(true)
+        .value_name("NAME")
+        .help("Shows a label for this instance in the top border.")
+        .long_help(
+            "\
+Shows the given label in the middle of the top border, to tell
+several instances apart, such as ones with different filters or
+remote hosts in separate panes.\n\n\n",
+        );
+    let lite = Arg::with_name("LITE")
+        .long("lite")
+        .help("Uses cheaper defaults for low-power devices.")
+        .long_help(
+            "\
+Uses defaults that lower bottom's own overhead, for devices like
+the Raspberry Pi.  This refreshes every 4s, keeps fewer data points,
+skips reading each process' disk IO, turns off the CPU usage
+breakdown, keeps the swap, OOM, and affinity process columns and
+showing only your own processes off, and uses a dot marker.  Any of
+these set in the config file or by another flag is used instead.\n\n",
+        );
+    let per_core_cpu = Arg::with_name("PER_CORE_CPU")
+        .long("per_core_cpu")
+        .help("Sets process CPU% to be relative to a single core.")
+        .long_help(
+            "\
+Sets process CPU% usage to be relative to a single core rather than
+all cores, like top and htop do.  A process keeping four cores busy
+shows around 400%.  This takes precedence over --current_usage.\n\n",
+        );
+    let preset = Arg::with_name("PRESET")
+        .long("preset")
+        .takes_value(true)
+        .value_name("PRESET")
+        .help("Sets which built-in layout to use.")
+        .long_help(
+            "\
+Sets which built-in layout to use.  A layout defined in the config
+file is always used over a preset.
+
+Supported presets:
++--------------+
+|    default   |
++--------------+
+| proc-focused |
++--------------+
+|  graphs-only |
++--------------+
+|    minimal   |
++--------------+
 \n\n",
         );
     let rate = Arg::with_name("RATE_MILLIS")
@@ -268,6 +482,30 @@ Supported widget names:
 Sets a refresh rate in milliseconds.  The minimum is 250ms,
 and defaults to 1000ms.  Smaller values may take more resources.\n\n\n",
         );
+    let record = Arg::with_name("RECORD")
+        .long("record")
+        .takes_value(true)
+        .value_name("PATH")
+        .help("Records collected data to a file for later replay.")
+        .long_help(
+            "\
+Records all collected data to the given file while running, so
+it can later be replayed with --replay.  Useful for attaching to
+bug reports.\n\n\n",
+        );
+    let replay = Arg::with_name("REPLAY")
+        .long("replay")
+        .takes_value(true)
+        .value_name("PATH")
+        .conflicts_with("RECORD")
+        .help("Replays data from a recording instead of collecting it.")
+        .long_help(
+            "\
+Replays data from a file made with --record instead of collecting
+live data, at the same pace it was recorded at.  The recording
+loops once it reaches the end.  Processes cannot be killed while
+replaying.\n\n\n",
+        );
     let time_delta = Arg::with_name("TIME_DELTA")
         .short("d")
         .long("time_delta")
@@ -292,24 +530,52 @@ The minimum is 1s (1000), and defaults to 15s (15000).\n\n\n",
         .arg(kelvin)
         .arg(fahrenheit)
         .arg(celsius)
-        .group(ArgGroup::with_name("TEMPERATURE_TYPE").args(&["KELVIN", "FAHRENHEIT", "CELSIUS"]))
+        .arg(raw_temperature)
+        .group(ArgGroup::with_name("TEMPERATURE_TYPE").args(&[
+            "KELVIN",
+            "FAHRENHEIT",
+            "CELSIUS",
+            "RAW_TEMPERATURE",
+        ]))
         .arg(autohide_time)
         .arg(basic)
         .arg(battery)
         .arg(case_sensitive)
+        .arg(collect)
+        .arg(color)
         .arg(config)
+        .arg(crash_report)
         .arg(default_time_value)
+        .arg(default_widget)
         .arg(default_widget_count)
         .arg(default_widget_type)
+        .arg(disable_battery)
         .arg(disable_click)
+        .arg(disable_cpu_breakdown)
+        .arg(disable_disk)
+        .arg(disable_network)
+        .arg(disable_process_io)
+        .arg(disable_temperature)
         .arg(dot_marker)
+        .arg(duration)
         .arg(group)
         .arg(hide_avg_cpu)
         .arg(hide_table_gap)
+        .arg(hide_temperature_unit)
         .arg(hide_time)
+        .arg(label)
         .arg(left_legend)
+        .arg(lite)
+        .arg(per_core_cpu)
+        .arg(preset)
         .arg(rate)
+        .arg(record)
         .arg(regex)
+        .arg(remote)
+        .arg(replay)
+        .arg(show_scheduler_activity)
+        .arg(show_self_usage)
+        .arg(stream_json)
         .arg(time_delta)
         .arg(current_usage)
         .arg(use_old_network_legend)
@@ -11,13 +11,61 @@ pub const DEFAULT_TIME_MILLISECONDS: u64 = 60 * 1000; // Defaults to 1 min.
 pub const STALE_MIN_MILLISECONDS: u64 = 30 * 1000; // Lowest is 30 seconds
 pub const TIME_CHANGE_MILLISECONDS: u64 = 15 * 1000; // How much to increment each time
 pub const AUTOHIDE_TIMEOUT_MILLISECONDS: u64 = 5000; // 5 seconds to autohide
+pub const MESSAGE_TIMEOUT_MILLISECONDS: u64 = 3000; // How long brief messages are shown
+pub const QUIT_DOUBLE_PRESS_MILLISECONDS: u64 = 1000; // How quickly q must be pressed again to quit
+pub const EXITED_PROCESS_DISPLAY_MILLISECONDS: u64 = 5000; // How long exited processes are shown against the baseline
+pub const SPAWNED_PROCESS_HIGHLIGHT_UPDATES: u8 = 2; // How many updates newly spawned processes are highlighted for
+pub const DATA_DELAYED_RATE_MULTIPLIER: u64 = 3; // How many update intervals can pass before data is shown as delayed
+pub const REMOTE_RECONNECT_MILLISECONDS: u64 = 5000; // How long to wait before reconnecting to a remote host
+
+// The most decimal places that percentages and rates can be shown with
+pub const MAX_DISPLAY_PRECISION: usize = 3;
+
+// Where the system summary is saved if there is no clipboard to copy it to
+pub const SNAPSHOT_SUMMARY_FILE_NAME: &str = "bottom_summary.txt";
+// Where a captured chart is saved if there is no clipboard to copy it to
+pub const GRAPH_CAPTURE_FILE_NAME: &str = "bottom_chart.txt";
+// Where the data of a chart is exported to as CSV
+pub const GRAPH_CSV_FILE_NAME: &str = "bottom_chart.csv";
 
 pub const TICK_RATE_IN_MILLISECONDS: u64 = 200;
+// More zombie processes than this shows a warning, unless the config sets another threshold
+pub const DEFAULT_ZOMBIE_WARN_THRESHOLD: u64 = 10;
+// Shown in a process widget when processes are running but none match its search or filters
+pub const DEFAULT_PROCESS_EMPTY_MESSAGE: &str = "No matching processes";
+// Harvests and redraws right away, unless the config sets another key
+pub const DEFAULT_REFRESH_KEY: char = 'U';
+// Keys already bound outside of search, including the sort widget's mnemonics, which the refresh
+// key would shadow
+pub const BOUND_KEYS: [char; 52] = [
+    'q', '/', '?', 'd', 'g', 'G', 'k', 'j', 'f', 'c', 'm', 'p', 'P', 'n', 'H', 'A', 'L', 'D', 'K',
+    'W', 'J', 'S', 'F', 'w', 'N', 'B', 'u', 'o', 'r', 'b', 'v', 'T', 'x', '1', '2', 'a', 'y', 'Y',
+    'E', 'V', 't', '+', '-', '=', 'e', 's', 'I', '%', 'C', 'M', 'R', 'O',
+];
+// How long the input thread waits for an event before checking whether input is paused
+pub const INPUT_POLL_MILLISECONDS: u64 = 20;
+// How long to wait between checks while idle redraws are paused
+pub const IDLE_TICK_RATE_IN_MILLISECONDS: u64 = 1000;
 // How fast the screen refreshes
 pub const DEFAULT_REFRESH_RATE_IN_MILLISECONDS: u64 = 1000;
+// Defaults used by --lite, for devices where collecting data is comparatively costly
+pub const LITE_REFRESH_RATE_IN_MILLISECONDS: u64 = 4000;
+pub const LITE_MAX_DATA_POINTS: u64 = 150; // 10 minutes at the lite refresh rate
+                                           // Defaults for slowing down harvesting while the CPU is busy, if enabled
+pub const DEFAULT_RATE_RAMP_CPU_THRESHOLD_PERCENT: f64 = 90.0;
+pub const DEFAULT_RATE_RAMP_TICKS: u64 = 5;
+pub const DEFAULT_RATE_RAMP_MAX_RATE_MULTIPLIER: u64 = 4;
 pub const MAX_KEY_TIMEOUT_IN_MILLISECONDS: u64 = 1000;
 // Number of colours to generate for the CPU chart/table
 pub const NUM_COLOURS: usize = 256;
+// Categories of the CPU usage breakdown, in the order they are stacked
+pub const CPU_BREAKDOWN_LABELS: [&str; 6] = ["user", "system", "iowait", "irq", "softirq", "steal"];
+// Categories of the memory breakdown, in the order they are stacked on top of used memory
+pub const MEM_BREAKDOWN_LABELS: [&str; 2] = ["Buffers", "Cached"];
+// Battery time estimates use the charge readings from up to this far back, and need readings
+// spanning at least the minimum before one is shown
+pub const BATTERY_ESTIMATE_WINDOW_IN_SECONDS: u64 = 300;
+pub const BATTERY_ESTIMATE_MIN_SPAN_IN_SECONDS: u64 = 30;
 
 // Canvas stuff
 // The minimum threshold when resizing tables
@@ -26,6 +74,7 @@ pub const FORCE_MIN_THRESHOLD: usize = 5;
 // Limits for when we should stop showing table gaps/labels (anything less means not shown)
 pub const TABLE_GAP_HEIGHT_LIMIT: u16 = 7;
 pub const TIME_LABEL_HEIGHT_LIMIT: u16 = 7;
+pub const MEM_PRESSURE_GAUGE_HEIGHT_LIMIT: u16 = 8;
 
 // Side borders
 lazy_static! {
@@ -42,23 +91,28 @@ lazy_static! {
 }
 
 // Help text
-pub const HELP_CONTENTS_TEXT: [&str; 8] = [
-    "Press the corresponding numbers to jump to the section, or scroll:\n",
+pub const HELP_CONTENTS_TEXT: [&str; 11] = [
+    "Press the corresponding numbers to jump to the section, / to search, or scroll:\n",
     "1 - General\n",
     "2 - CPU widget\n",
     "3 - Process widget\n",
     "4 - Process search widget\n",
     "5 - Process sort widget\n",
     "6 - Battery widget\n",
-    "7 - Basic memory widget",
+    "7 - Basic memory widget\n",
+    "8 - Network widget\n",
+    "9 - Disk widget\n",
+    "0 - Temperature widget",
 ];
 
-pub const GENERAL_HELP_TEXT: [&str; 29] = [
+pub const GENERAL_HELP_TEXT: [&str; 38] = [
     "1 - General\n",
     "q, Ctrl-c        Quit\n",
+    "Alt-q            Quit from anywhere, if quit_from_search is enabled\n",
     "Esc              Close dialog windows, search, widgets, or exit expanded mode\n",
     "Ctrl-r           Reset display and any collected data\n",
     "f                Freeze/unfreeze updating with new data\n",
+    "U                Refresh right away, unless refresh_key is set to another key\n",
     "Ctrl-Left,       \n",
     "Shift-Left,      Move widget selection left\n",
     "H, A             \n",
@@ -82,19 +136,30 @@ pub const GENERAL_HELP_TEXT: [&str; 29] = [
     "+                Zoom in on chart (decrease time range)\n",
     "-                Zoom out on chart (increase time range)\n",
     "=                Reset zoom\n",
+    "Left, Right      Pan chart back or forward in time\n",
+    "G                Jump chart back to live data\n",
+    "y                Copy a summary of the current system state to the clipboard\n",
+    "Y                Copy the selected chart as text to the clipboard\n",
+    "E                Export the selected chart's data as CSV to a file\n",
+    "b                Toggle showing buffers and cached memory in the memory chart (Linux only)\n",
+    "%                Cycle the memory chart legend between percentages, values, or both\n",
     "Mouse scroll     Scroll through the tables or zoom in/out of charts by scrolling up/down",
 ];
 
-pub const CPU_HELP_TEXT: [&str; 2] = [
+pub const CPU_HELP_TEXT: [&str; 5] = [
     "2 - CPU widget\n",
+    "b                Toggle showing the usage breakdown of the selected entry (Linux only)\n",
+    "v                Toggle showing a heatmap of each core's usage over time\n",
+    "Space            Toggle drawing the selected entry, or show all entries on \"All\"\n",
     "Mouse scroll     Scrolling over an CPU core/average shows only that entry on the chart",
 ];
 
 // TODO [Help]: Search in help?
 // TODO [Help]: Move to using tables for easier formatting?
-pub const PROCESS_HELP_TEXT: [&str; 13] = [
+pub const PROCESS_HELP_TEXT: [&str; 22] = [
     "3 - Process widget\n",
     "dd               Kill the selected process\n",
+    "r                Resume the selected process if it is stopped\n",
     "c                Sort by CPU usage, press again to reverse sorting order\n",
     "m                Sort by memory usage, press again to reverse sorting order\n",
     "p                Sort by PID name, press again to reverse sorting order\n",
@@ -105,10 +170,18 @@ pub const PROCESS_HELP_TEXT: [&str; 13] = [
     "s, F6            Open process sort widget\n",
     "I                Invert current sort\n",
     "%                Toggle between values and percentages for memory usage\n",
-    "t, F5            Toggle tree mode",
+    "F                Pin or unpin the selected process to the top of the list\n",
+    "w                Watch or unwatch the selected process\n",
+    "N                Search for the selected process' name, press again to clear\n",
+    "B                Mark or clear a baseline to show process changes against\n",
+    "u                Show only your own processes, press again to show all\n",
+    "t, F5            Toggle tree mode\n",
+    "Enter            Show or hide details of the selected process\n",
+    "o                Show or hide the whole command of the selected process\n",
+    "V                Show the process table in a pager, such as less",
 ];
 
-pub const SEARCH_HELP_TEXT: [&str; 46] = [
+pub const SEARCH_HELP_TEXT: [&str; 48] = [
     "4 - Process search widget\n",
     "Tab              Toggle between searching for PID and name\n",
     "Esc              Close the search widget (retains the filter)\n",
@@ -120,6 +193,7 @@ pub const SEARCH_HELP_TEXT: [&str; 46] = [
     "Alt-c, F1        Toggle matching case\n",
     "Alt-w, F2        Toggle matching the entire word\n",
     "Alt-r, F3        Toggle using regex\n",
+    "Alt-i, F4        Toggle inverting the search\n",
     "Left, Alt-h      Move cursor left\n",
     "Right, Alt-l     Move cursor right\n",
     "\n",
@@ -134,6 +208,7 @@ pub const SEARCH_HELP_TEXT: [&str; 46] = [
     "tread, t.read    ex: tread = 1\n",
     "twrite, t.write  ex: twrite = 1\n",
     "state            ex: state = running\n",
+    "env:             ex: env:NODE_ENV=production\n",
     "\n",
     "Comparison operators:\n",
     "=                ex: cpu = 1\n",
@@ -157,13 +232,14 @@ pub const SEARCH_HELP_TEXT: [&str; 46] = [
     "TiB              ex: read > 1 tib",
 ];
 
-pub const SORT_HELP_TEXT: [&str; 6] = [
+pub const SORT_HELP_TEXT: [&str; 7] = [
     "5 - Sort widget\n",
     "Down, 'j'        Scroll down in list\n",
     "Up, 'k'          Scroll up in list\n",
     "Mouse scroll     Scroll through sort widget\n",
     "Esc              Close the sort widget\n",
-    "Enter            Sort by current selected column",
+    "Enter            Sort by current selected column\n",
+    "C/M/P/N/R/T/O    Sort by CPU, memory, PID, name, R/s, threads, or OOM score",
 ];
 
 pub const BATTERY_HELP_TEXT: [&str; 3] = [
@@ -177,6 +253,27 @@ pub const BASIC_MEM_HELP_TEXT: [&str; 2] = [
     "%                Toggle between values and percentages for memory usage",
 ];
 
+pub const NETWORK_HELP_TEXT: [&str; 4] = [
+    "8 - Network widget\n",
+    "T                Toggle between totals since boot and totals since bottom started\n",
+    "x                Toggle between a shared y-axis and separate y-axes for RX and TX\n",
+    "1/2              Toggle drawing RX or TX on the graph",
+];
+
+pub const DISK_HELP_TEXT: [&str; 4] = [
+    "9 - Disk widget\n",
+    "a                Toggle showing disks hidden by file system or mount point\n",
+    "s                Toggle grouping disks by file system\n",
+    "P                Toggle between full disk and mount paths or just their last part",
+];
+
+pub const TEMP_HELP_TEXT: [&str; 4] = [
+    "0 - Temperature widget\n",
+    "t                Sort by temperature, press again to reverse sorting order\n",
+    "n                Sort by sensor name, press again to reverse sorting order\n",
+    "I                Invert current sort",
+];
+
 lazy_static! {
     pub static ref HELP_TEXT: Vec<Vec<&'static str>> = vec![
         HELP_CONTENTS_TEXT.to_vec(),
@@ -187,6 +284,9 @@ lazy_static! {
         SORT_HELP_TEXT.to_vec(),
         BATTERY_HELP_TEXT.to_vec(),
         BASIC_MEM_HELP_TEXT.to_vec(),
+        NETWORK_HELP_TEXT.to_vec(),
+        DISK_HELP_TEXT.to_vec(),
+        TEMP_HELP_TEXT.to_vec(),
     ];
 }
 
@@ -245,8 +345,52 @@ pub const DEFAULT_BATTERY_LAYOUT: &str = r##"
     default=true
 "##;
 
+pub const PROC_FOCUSED_LAYOUT: &str = r##"
+[[row]]
+  ratio=25
+  [[row.child]]
+    ratio=2
+    type="cpu"
+  [[row.child]]
+    ratio=1
+    type="mem"
+[[row]]
+  ratio=75
+  [[row.child]]
+    type="proc"
+    default=true
+"##;
+
+pub const GRAPHS_ONLY_LAYOUT: &str = r##"
+[[row]]
+  ratio=50
+  [[row.child]]
+    type="cpu"
+    default=true
+[[row]]
+  ratio=50
+  [[row.child]]
+    type="mem"
+  [[row.child]]
+    type="net"
+"##;
+
+pub const MINIMAL_LAYOUT: &str = r##"
+[[row]]
+  ratio=30
+  [[row.child]]
+    type="cpu"
+[[row]]
+  ratio=70
+  [[row.child]]
+    type="proc"
+    default=true
+"##;
+
 // Config and flags
 pub const DEFAULT_CONFIG_FILE_PATH: &str = "bottom/bottom.toml";
+pub const DEFAULT_STATE_FILE_PATH: &str = "bottom/state.toml";
+pub const DEFAULT_CRASH_REPORT_PATH: &str = "bottom/crash_report.txt";
 
 // Default config file
 // FIXME: Update the default config
@@ -266,6 +410,25 @@ pub const DEFAULT_CONFIG_CONTENT: &str = r##"
 # Whether to use dot markers rather than braille.
 #dot_marker = false
 
+# What to draw graphs with, which takes priority over dot_marker.  Half blocks have a lower
+# resolution than braille, but work in more fonts.  Pixels draws graphs as images in terminals
+# with sixel or kitty graphics support, needs the pixel-graphs build feature, and otherwise
+# falls back to braille.
+#graph_marker = "braille"
+#graph_marker = "dot"
+#graph_marker = "half_block"
+#graph_marker = "pixels"
+
+# Whether graphs plot every data point.  By default, when there are more points than the graph
+# has columns, only the first, last, lowest, and highest points of each column are plotted, so
+# spikes still show while drawing less.
+#raw_graphs = false
+
+# How many colours the terminal can show, one of "auto", "16", "256", or "truecolor".  Colours
+# the terminal can't show are changed to the closest one it can.  Auto guesses from the COLORTERM
+# and TERM environment variables.
+#color = "auto"
+
 # The update rate of the application.
 #rate = 1000
 
@@ -275,6 +438,9 @@ pub const DEFAULT_CONFIG_CONTENT: &str = r##"
 # Whether to set CPU% on a process to be based on the total CPU or just current usage.
 #current_usage = false
 
+# Whether to set CPU% on a process to be relative to a single core, so it can go over 100%.
+#per_core_cpu = false
+
 # Whether to group processes with the same name together by default.
 #group_processes = false
 
@@ -294,6 +460,10 @@ pub const DEFAULT_CONFIG_CONTENT: &str = r##"
 #temperature_type = "kelvin"
 #temperature_type = "fahrenheit"
 #temperature_type = "celsius"
+#temperature_type = "raw"
+
+# Hide the unit after each temperature
+#hide_temperature_unit = false
 
 # The default time interval (in milliseconds).
 #default_time_value = 60000
@@ -301,7 +471,14 @@ pub const DEFAULT_CONFIG_CONTENT: &str = r##"
 # The time delta on each zoom in/out action (in milliseconds).
 #time_delta = 15000
 
+# Use a built-in layout.  Ignored if a layout is defined below.
+#preset = "default"
+#preset = "proc-focused"
+#preset = "graphs-only"
+#preset = "minimal"
+
 # Override layout default widget
+#default_widget = "proc"
 #default_widget_type = "proc"
 #default_widget_count = 1
 
@@ -314,6 +491,116 @@ pub const DEFAULT_CONFIG_CONTENT: &str = r##"
 # Remove space in tables
 #hide_table_gap = false
 
+# Force-disable collecting data for specific widgets, even if they are in the layout
+#disable_battery = false
+#disable_disk = false
+#disable_network = false
+#disable_temperature = false
+
+# How to show process commands when displaying commands instead of names - the full command,
+# just the executable's basename, or the executable and its first argument
+#command_display = "full"
+#command_display = "basename"
+#command_display = "first-arg"
+
+# How the selected process is emphasized - a highlighted background across the whole row,
+# a leading arrow, or bold text
+#selection_style = "full_row"
+#selection_style = "arrow"
+#selection_style = "bold"
+
+# Colour each CPU entry in the legend and basic mode bars by its usage, from green to red,
+# rather than with the core colours
+#cpu_usage_gradient = false
+
+# Below this terminal size, only a note asking for a larger terminal is shown instead of the
+# widgets.  Both default to 0, which never hides anything.
+#min_terminal_width = 80
+#min_terminal_height = 24
+
+# Whether to write a crash report to bottom/crash_report.txt in the data directory if bottom
+# crashes, with the panic, a backtrace, the version, the OS, and the config in use.
+#crash_report = false
+
+# Shown in the middle of the top border, to tell several instances of bottom apart
+#label = "Build server"
+
+# Harvests new data and redraws right away when pressed, without waiting for the next update or
+# moving when it happens.  This can't be a key that is already bound, such as q or e, or the
+# lowercase of a bound uppercase key, as it is also caught with shift.
+#refresh_key = "U"
+
+# Whether quitting with q happens immediately, needs q to be pressed twice in quick
+# succession, or needs to be confirmed in a prompt
+#quit_confirmation = "none"
+#quit_confirmation = "double"
+#quit_confirmation = "prompt"
+
+# Whether Alt-q also quits, even from the process search widget where q is typed into the search.
+#quit_from_search = false
+
+# Shows the CPU and memory usage of bottom itself in the top right corner.
+#show_self_usage = false
+
+# Whether scrolling down from the last entry of a table goes to the first, and scrolling up from
+# the first entry goes to the last.
+#scroll_wrap = false
+
+# Whether every other row of the process, disk, and temperature tables gets a different
+# background, set by alt_row_color, to make wide tables easier to follow.
+#zebra_stripes = false
+
+# How many entries each tick of the mouse wheel scrolls a table by.
+#scroll_lines_per_tick = 1
+
+# Whether the network graph starts with RX and TX graphed separately, each with their own y-axis,
+# rather than sharing one.  This can also be toggled with "x".
+#split_network_axes = false
+
+# The most data points to keep for graphs, which bounds memory usage when running for a long time.
+# Data is also always dropped after 10 minutes, so whichever limit is stricter applies.  If this
+# is reached, graphs show a shorter span of time than the 10 minutes they can normally show.
+#max_data_points = 3000
+
+# Whether to skip reading each process' disk IO, which is costly on slow devices.  The process IO
+# columns then show no usage.  Only affects Linux.
+#disable_process_io = false
+
+# Whether to stop the CPU usage breakdown from being shown with b, which is read from /proc/stat
+# while it is shown.  Only affects Linux.
+#disable_cpu_breakdown = false
+
+# Shows how many context switches and interrupts happen per second in the CPU graph's title, where
+# high context switch rates point to contention for the CPU.  Only available on Linux.
+#show_scheduler_activity = false
+
+# The strftime-style format of timestamps in copied summaries and the JSON stream.  If unset,
+# timestamps are in RFC 3339 (ISO 8601) format, such as 2020-09-01T13:45:00+02:00.
+#time_format = "%Y-%m-%d %H:%M:%S"
+
+# How many decimal places (0 to 3) to show for CPU and memory percentages, and for network and
+# process IO rates.  If unset, each widget keeps its usual number of decimal places.
+#percent_precision = 1
+#rate_precision = 1
+
+# Smooths the current network and disk IO rates with an exponentially weighted moving average.
+# Lower values (above 0) are smoother, and 1 shows each new rate as-is.  Graphs are not smoothed.
+#rate_smoothing_alpha = 1.0
+
+# How many of a unit a byte value must reach before it is shown in that unit.  For example, 1
+# shows 1023 KiB as 1023KiB and 1024 KiB as 1MiB, while 10 keeps values in KiB until 10 MiB.
+# Values below 1 switch sooner, such as 0.9 showing 1000 KiB in MiB.  If unset, values switch
+# at 1 of each unit, except that TiB is only used from a trillion bytes on.
+#byte_unit_threshold = 1.0
+
+# How many significant figures byte values are rounded to.  Decimal places are still set by
+# each widget and the precision options.  If unset, values aren't rounded.
+#byte_significant_figures = 3
+
+# Saves power by not redrawing when nothing shown has changed and there is no input, and by
+# checking for changes less often while idle.  Graphs may not scroll while their values stay the same.
+#pause_idle_redraws = false
+
 ##########################################################
 
 # These are all the components that support custom theming.  Note that colour support
@@ -330,6 +617,9 @@ pub const DEFAULT_CONFIG_CONTENT: &str = r##"
 # Represents the average CPU color.
 #avg_cpu_color="Red"
 
+# Represents the colours of the CPU usage breakdown, in the order user, system, iowait, irq, softirq, steal
+#cpu_breakdown_colors=["LightGreen", "LightRed", "LightYellow", "LightMagenta", "LightCyan", "LightBlue"]
+
 # Represents the colour the core will use in the CPU legend and graph.
 #cpu_core_colors=["LightMagenta", "LightYellow", "LightCyan", "LightGreen", "LightBlue", "LightRed", "Cyan", "Green", "Blue", "Red"]
 
@@ -339,6 +629,10 @@ pub const DEFAULT_CONFIG_CONTENT: &str = r##"
 # Represents the colour SWAP will use in the memory legend and graph.
 #swap_color="LightYellow"
 
+# Represents the colours buffers and cached memory will use in the memory breakdown.
+#buffers_color="LightCyan"
+#cached_color="LightGreen"
+
 # Represents the colour rx will use in the network legend and graph.
 #rx_color="LightCyan"
 
@@ -366,6 +660,150 @@ pub const DEFAULT_CONFIG_CONTENT: &str = r##"
 # Represents the colours of the battery based on charge
 #battery_colors = ["red", "yellow", "yellow", "green", "green", "green"]
 
+# Represents the colour of pinned processes in the process widget.
+#pinned_process_color="LightCyan"
+
+# Represents the background colour of watched processes in the process widget.
+#watched_process_bg_color="DarkGray"
+
+# Represents the colour of the memory widget's title and border when over the warning threshold.
+#mem_alarm_color="Red"
+
+# Represents the colour of disk table rows that are over their warning threshold.
+#disk_alarm_color="Red"
+
+# Represents the colour of processes in tree mode that don't match the search, which are only
+# shown to keep the tree intact.
+#disabled_entry_color="DarkGray"
+
+# Represents the background colour of every other table row when zebra_stripes is on.
+#alt_row_color="#303030"
+
+##########################################################
+
+# Process names that are always shown at the top of the process widget, regardless of sorting
+# or searching.  Processes can also be pinned/unpinned at runtime with 'F'.
+#[processes]
+#pinned = ["postgres", "nginx"]
+
+# Whether to show pinned processes that are not currently running.
+#show_pinned_not_running = false
+
+# Processes with an OOM score at or above this are drawn with the memory alarm colour, to show
+# which processes the kernel is likeliest to kill when out of memory (Linux only).
+#oom_score_warn_threshold = 800
+
+# With more zombie processes than this, the process widget's title warns how many there are and
+# which parent has the most of them, as it is likely failing to reap its children.
+#zombie_warn_threshold = 10
+
+# Breaks ties when sorting processes, for example ordering processes with the same CPU usage by
+# memory.  One of "cpu", "mem", "mem%", "swap", "pid", "ppid", "name", "command", "read",
+# "write", "tread", "twrite", "state", "count", "threads", "oom", or "affinity".
+#secondary_sort = "mem"
+
+# Whether process widgets start out showing only processes owned by the user running bottom.
+# This can be toggled with u.  Processes whose owner can't be read are always shown.
+#only_own = false
+
+# Whether process widgets show a PPID column with the PID of each process' parent.  It is hidden
+# while processes are grouped.
+#show_ppid = false
+
+# Whether process widgets show the Swap, Threads, OOM, and Affinity columns, which are hidden by
+# default.  Swap usage and CPU affinity are only read while their columns are shown, and OOM
+# scores while their column is shown or oom_score_warn_threshold is set.
+#show_swap = false
+#show_threads = false
+#show_oom_score = false
+#show_affinity = false
+
+# Adds how many processes are running, sleeping, stopped, or zombies to the process widget's
+# title, counting either "all" processes or only those "matched" by the search and filters.
+#state_counts = "all"
+
+# Shown in the middle of a process widget when none of the running processes match its search
+# or filters, along with a hint on how to show them again.
+#empty_message = "No matching processes"
+
+##########################################################
+
+# Memory usage warnings.  When RAM usage is at or above this percentage, the memory widget's
+# title and border are drawn with the alarm colour.
+#[mem]
+#warn_threshold_percent = 90.0
+
+# Whether to also ring the terminal bell once each time the threshold is crossed.
+#warn_bell = false
+
+# The memory pressure gauge at the bottom of the memory graph is a score from 0 to 100, which
+# is the weighted average of the memory used %, the swap used %, and the pages swapped in and
+# out per second as a % of swap_activity_max_pages.  Swap parts are left out without swap.
+#[mem.pressure]
+#memory_weight = 0.5
+#swap_weight = 0.2
+#swap_activity_weight = 0.3
+#swap_activity_max_pages = 1000.0
+
+##########################################################
+
+# Disk usage warnings.  Rows in the disk table whose used percentage is at or above the threshold
+# are drawn with the disk alarm colour.
+#[disk]
+#warn_threshold_percent = 90.0
+
+# Thresholds for specific mount points, which take priority over the one above.
+#[disk.mount_warn_threshold_percent]
+#"/boot" = 75.0
+
+# Disks to hide from the disk table by file system type, or by mount point using globs such as
+# "/snap/*".  Hidden disks can be shown again at runtime with 'a'.
+#hide_filesystems = ["tmpfs", "squashfs"]
+#hide_mounts = ["/snap/*"]
+
+# Optional columns to show in the disk table: the file system "type", and the key mount
+# "options", such as ro and noatime.
+#columns = ["type", "options"]
+
+##########################################################
+
+# Colour stops for the CPU and memory graphs.  Once an entry's current usage is at or above a
+# stop's percentage, its graph line and legend entry use that stop's colour.
+#[thresholds]
+#cpu = [{ percent = 70.0, color = "Yellow" }, { percent = 90.0, color = "Red" }]
+#mem = [{ percent = 90.0, color = "Red" }]
+
+##########################################################
+
+# Colours for process rows by their state code, such as R for running, D for uninterruptible
+# sleep, Z for zombies, and T for stopped.  These are the defaults, and states that aren't set
+# keep them.  Processes in other states use the usual text colour.
+#[state_colors]
+#R = "Green"
+#D = "Yellow"
+#Z = "Red"
+#T = "DarkGray"
+
+##########################################################
+
+# Slows down updates while the CPU is busy, to add less load to a struggling system.  Once the
+# average CPU usage is above the threshold for this many updates in a row, the update rate is
+# doubled, up to max_rate in milliseconds (4 times the rate by default).  It goes back to the
+# usual rate once the usage is below the threshold for as many updates.
+#[rate_ramp]
+#cpu_threshold_percent = 90.0
+#ticks = 5
+#max_rate = 4000
+
+##########################################################
+
+# Headers to show in place of the usual names of process columns, using the same keys as
+# secondary_sort.  Sorting is unaffected.
+#[columns.cpu]
+#header = "Proc%"
+#[columns.pid]
+#header = "ID"
+
 ##########################################################
 
 # Layout - layouts follow a pattern like this:
@@ -2,12 +2,14 @@
 //! can actually handle.
 use crate::Pid;
 use crate::{
-    app::{data_farmer, data_harvester, App, Filter},
+    app::{data_farmer, data_harvester, App, DiskColumns, Filter, HiddenDisks, TempSorting},
+    constants::{CPU_BREAKDOWN_LABELS, MEM_BREAKDOWN_LABELS},
     utils::{self, gen_util::*},
 };
-use data_harvester::processes::ProcessSorting;
+use data_harvester::{battery_harvester::BatteryState, processes::ProcessSorting};
 use indexmap::IndexSet;
-use std::collections::{HashMap, VecDeque};
+use std::collections::{hash_map::DefaultHasher, HashMap, HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
 
 /// Point is of time, data
 type Point = (f64, f64);
@@ -15,6 +17,7 @@ type Point = (f64, f64);
 #[derive(Default, Debug)]
 pub struct ConvertedBatteryData {
     pub battery_name: String,
+    pub state: String,
     pub charge_percentage: f64,
     pub watt_consumption: String,
     pub duration_until_full: Option<String>,
@@ -22,6 +25,16 @@ pub struct ConvertedBatteryData {
     pub health: String,
 }
 
+#[derive(Default, Debug, Hash)]
+pub struct ConvertedTempData {
+    pub sensor: String,
+    pub value: String,
+    /// The rounded temperature used for sorting, or [`None`] if the sensor has no reading.
+    pub temperature: Option<i64>,
+    /// Fans are listed after all temperatures, whichever way the table is sorted.
+    pub is_fan: bool,
+}
+
 #[derive(Default, Debug)]
 pub struct ConvertedNetworkData {
     pub rx: Vec<Point>,
@@ -51,6 +64,7 @@ pub struct ConvertedProcessData {
     pub mem_percent_usage: f64,
     pub mem_usage_bytes: u64,
     pub mem_usage_str: (f64, String),
+    pub swap_bytes: Option<u64>,
     pub group_pids: Vec<Pid>,
     pub read_per_sec: String,
     pub write_per_sec: String,
@@ -62,10 +76,90 @@ pub struct ConvertedProcessData {
     pub tw_f64: f64,
     pub process_state: String,
     pub process_char: char,
+    pub thread_count: Option<u64>,
+    pub oom_score: Option<u64>,
+    pub cpu_affinity: Option<Vec<usize>>,
+    /// The user ID that owns this process.  Groups can hold processes of several users, so they
+    /// have none.
+    pub uid: Option<u32>,
+    /// When the process started, as harvested.  Groups have none.
+    pub start_time: Option<u64>,
     /// Prefix printed before the process when displayed.
     pub process_description_prefix: Option<String>,
     /// Whether to mark this process entry as disabled (mostly for tree mode).
     pub is_disabled_entry: bool,
+    /// Whether this process entry is pinned to the top of the process widget.
+    pub is_pinned: bool,
+    /// How this process changed since the process baseline was marked, if one is marked.
+    pub diff: Option<ProcessDiff>,
+}
+
+/// The state character of a process that has been stopped, such as by SIGSTOP or Ctrl-z.
+const STOPPED_PROCESS_CHAR: char = 'T';
+
+impl ConvertedProcessData {
+    pub fn is_stopped(&self) -> bool {
+        self.process_char == STOPPED_PROCESS_CHAR
+    }
+
+    /// Whether this entry is for running processes, which can be acted on by PID, unlike the
+    /// entry of a pinned process that isn't running or of a process that exited since the
+    /// baseline was marked.
+    pub fn is_running(&self) -> bool {
+        let has_exited = self
+            .diff
+            .map_or(false, |diff| diff.status == ProcessDiffStatus::Exited);
+        !(self.is_pinned && self.is_disabled_entry) && !has_exited
+    }
+}
+
+/// How a process compares to the process baseline.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ProcessDiffStatus {
+    /// The process was running both when the baseline was marked and now.
+    Existing,
+    /// The process started after the baseline was marked.
+    New,
+    /// The process was running when the baseline was marked, but has since exited.
+    Exited,
+}
+
+/// The change in a process' usage since the process baseline was marked.  New processes are
+/// compared against zero usage, and exited processes against their usage in the baseline.
+#[derive(Clone, Copy, Debug)]
+pub struct ProcessDiff {
+    pub status: ProcessDiffStatus,
+    pub cpu_percent_delta: f64,
+    pub mem_percent_delta: f64,
+    pub mem_bytes_delta: i64,
+}
+
+impl ProcessDiff {
+    pub fn new(
+        status: ProcessDiffStatus, current: Option<&ConvertedProcessData>,
+        baseline: Option<&ConvertedProcessData>,
+    ) -> Self {
+        let usage = |process: Option<&ConvertedProcessData>| -> (f64, f64, i64) {
+            process
+                .map(|process| {
+                    (
+                        process.cpu_percent_usage,
+                        process.mem_percent_usage,
+                        process.mem_usage_bytes as i64,
+                    )
+                })
+                .unwrap_or((0.0, 0.0, 0))
+        };
+        let (current_cpu, current_mem, current_mem_bytes) = usage(current);
+        let (baseline_cpu, baseline_mem, baseline_mem_bytes) = usage(baseline);
+
+        ProcessDiff {
+            status,
+            cpu_percent_delta: current_cpu - baseline_cpu,
+            mem_percent_delta: current_mem - baseline_mem,
+            mem_bytes_delta: current_mem_bytes - baseline_mem_bytes,
+        }
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -75,14 +169,34 @@ pub struct ConvertedCpuData {
     pub cpu_data: Vec<Point>,
     /// Represents the value displayed on the legend.
     pub legend_value: String,
+    /// Stacked usage breakdown, one series per category in [`CPU_BREAKDOWN_LABELS`] order.
+    /// Each series is the running total up to and including that category.  Empty if
+    /// the breakdown is not available on this platform.
+    pub breakdown_data: Vec<Vec<Point>>,
 }
 
-pub fn convert_temp_row(app: &App) -> Vec<Vec<String>> {
+pub fn convert_temp_row(app: &App) -> Vec<ConvertedTempData> {
     let current_data = &app.data_collection;
     let temp_type = &app.app_config_fields.temperature_type;
+    let hide_temperature_unit = app.app_config_fields.hide_temperature_unit;
     let temp_filter = &app.filters.temp_filter;
 
-    let mut sensor_vector: Vec<Vec<String>> = current_data
+    let to_keep = |name: &str| -> bool {
+        if let Some(temp_filter) = temp_filter {
+            let mut ret = temp_filter.is_list_ignored;
+            for r in &temp_filter.list {
+                if r.is_match(name) {
+                    ret = !temp_filter.is_list_ignored;
+                    break;
+                }
+            }
+            ret
+        } else {
+            true
+        }
+    };
+
+    let mut sensor_vector: Vec<ConvertedTempData> = current_data
         .temp_harvest
         .iter()
         .filter_map(|temp_harvest| {
@@ -93,51 +207,189 @@ pub fn convert_temp_row(app: &App) -> Vec<Vec<String>> {
                 (None, None) => String::default(),
             };
 
-            let to_keep = if let Some(temp_filter) = temp_filter {
-                let mut ret = temp_filter.is_list_ignored;
-                for r in &temp_filter.list {
-                    if r.is_match(&name) {
-                        ret = !temp_filter.is_list_ignored;
-                        break;
-                    }
-                }
-                ret
-            } else {
-                true
-            };
-
-            if to_keep {
-                Some(vec![
-                    name,
-                    (temp_harvest.temperature.ceil() as u64).to_string()
-                        + match temp_type {
-                            data_harvester::temperature::TemperatureType::Celsius => "C",
-                            data_harvester::temperature::TemperatureType::Kelvin => "K",
-                            data_harvester::temperature::TemperatureType::Fahrenheit => "F",
-                        },
-                ])
+            if to_keep(&name) {
+                let temperature = if temp_harvest.temperature.is_finite() {
+                    Some(temp_harvest.temperature.ceil() as i64)
+                } else {
+                    None
+                };
+                Some(ConvertedTempData {
+                    sensor: name,
+                    value: match temperature {
+                        Some(temperature) => {
+                            temperature.to_string()
+                                + if hide_temperature_unit {
+                                    ""
+                                } else {
+                                    temp_type.unit_symbol()
+                                }
+                        }
+                        None => "N/A".to_string(),
+                    },
+                    temperature,
+                    is_fan: false,
+                })
             } else {
                 None
             }
         })
         .collect();
 
+    // Fans are listed after the temperatures, and are filtered the same way.
+    sensor_vector.extend(current_data.fan_harvest.iter().filter_map(|fan_harvest| {
+        let name = match &fan_harvest.component_name {
+            Some(component_name) => format!("{}: {}", component_name, fan_harvest.fan_label),
+            None => fan_harvest.fan_label.clone(),
+        };
+
+        if to_keep(&name) {
+            Some(ConvertedTempData {
+                sensor: name,
+                value: format!("{} RPM", fan_harvest.rpm),
+                temperature: None,
+                is_fan: true,
+            })
+        } else {
+            None
+        }
+    }));
+
     if sensor_vector.is_empty() {
-        sensor_vector.push(vec!["No Sensors Found".to_string(), "".to_string()]);
+        sensor_vector.push(ConvertedTempData {
+            sensor: "No Sensors Found".to_string(),
+            ..ConvertedTempData::default()
+        });
     }
 
     sensor_vector
 }
 
+/// Sorts the temperature table rows.  Sensors without a reading are sorted after those with
+/// one, and fans are always listed last, whichever way the rows are sorted.
+pub fn sort_temp_data(
+    temp_data: &mut [&ConvertedTempData], sorting_type: TempSorting, is_descending: bool,
+) {
+    temp_data.sort_by(|a, b| {
+        a.is_fan
+            .cmp(&b.is_fan)
+            .then_with(|| b.temperature.is_some().cmp(&a.temperature.is_some()))
+            .then_with(|| match sorting_type {
+                TempSorting::Temperature => {
+                    get_ordering(a.temperature, b.temperature, is_descending)
+                }
+                TempSorting::SensorName => get_ordering(
+                    a.sensor.to_lowercase(),
+                    b.sensor.to_lowercase(),
+                    is_descending,
+                ),
+            })
+            .then_with(|| a.sensor.cmp(&b.sensor))
+    });
+}
+
+pub fn convert_pressure_row(current_data: &data_farmer::DataCollection) -> Vec<Vec<String>> {
+    let to_percent_string =
+        |value: Option<f64>| value.map_or_else(|| "N/A".to_string(), |v| format!("{:.2}%", v));
+
+    if let Some(pressure_harvest) = &current_data.pressure_harvest {
+        [
+            ("CPU", &pressure_harvest.cpu),
+            ("Memory", &pressure_harvest.memory),
+            ("IO", &pressure_harvest.io),
+        ]
+        .iter()
+        .filter_map(|(name, stats)| {
+            stats.as_ref().map(|stats| {
+                vec![
+                    name.to_string(),
+                    to_percent_string(Some(stats.some_avg10)),
+                    to_percent_string(Some(stats.some_avg60)),
+                    to_percent_string(stats.full_avg10),
+                    to_percent_string(stats.full_avg60),
+                ]
+            })
+        })
+        .collect()
+    } else {
+        vec![vec![
+            "PSI Unavailable".to_string(),
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+        ]]
+    }
+}
+
+/// Returns the system info as rows of a property and its value, with "N/A" for anything that
+/// couldn't be read.
+pub fn convert_system_info_row(
+    current_data: &data_farmer::DataCollection, byte_format: &ByteFormat,
+) -> Vec<Vec<String>> {
+    let system_info = match &current_data.system_info_harvest {
+        Some(system_info) => system_info,
+        None => return Vec::new(),
+    };
+
+    let or_unavailable = |value: Option<String>| value.unwrap_or_else(|| "N/A".to_string());
+    let (total_memory, total_memory_unit) =
+        get_exact_byte_values(system_info.total_memory_bytes, false, byte_format);
+
+    vec![
+        ("Hostname", or_unavailable(system_info.hostname.clone())),
+        ("Kernel", or_unavailable(system_info.kernel_version.clone())),
+        ("CPU model", or_unavailable(system_info.cpu_model.clone())),
+        ("Cores", system_info.core_count.to_string()),
+        (
+            "Total memory",
+            format!("{:.1}{}", total_memory, total_memory_unit),
+        ),
+        (
+            "Swappiness",
+            or_unavailable(system_info.swappiness.map(|value| value.to_string())),
+        ),
+        (
+            "Overcommit",
+            or_unavailable(system_info.overcommit_memory.map(|value| match value {
+                0 => "0 (heuristic)".to_string(),
+                1 => "1 (always)".to_string(),
+                2 => "2 (never)".to_string(),
+                _ => value.to_string(),
+            })),
+        ),
+        (
+            "Dirty ratio",
+            or_unavailable(system_info.dirty_ratio.map(|value| format!("{}%", value))),
+        ),
+    ]
+    .into_iter()
+    .map(|(property, value)| vec![property.to_string(), value])
+    .collect()
+}
+
+/// Returns the disk table rows, with the optional columns after the mount point, along with the
+/// used percentage of each row.  Disks are harvested in order of name, so grouping them by file
+/// system keeps them in order of name within each group.
 pub fn convert_disk_row(
     current_data: &data_farmer::DataCollection, disk_filter: &Option<Filter>,
-) -> Vec<Vec<String>> {
+    hidden_disks: Option<&HiddenDisks>, disk_columns: DiskColumns, is_sorted_by_file_system: bool,
+    byte_format: &ByteFormat,
+) -> (Vec<Vec<String>>, Vec<Option<f64>>) {
     let mut disk_vector: Vec<Vec<String>> = Vec::new();
+    let mut used_percent_vector: Vec<Option<f64>> = Vec::new();
 
-    current_data
+    // The I/O labels line up with the harvested disks, so they are paired before filtering.
+    let mut disks = current_data
         .disk_harvest
         .iter()
-        .filter(|disk_harvest| {
+        .zip(&current_data.io_labels)
+        .filter(|(disk_harvest, _)| {
+            if let Some(hidden_disks) = hidden_disks {
+                if hidden_disks.is_hidden(&disk_harvest.file_system, &disk_harvest.mount_point) {
+                    return false;
+                }
+            }
+
             if let Some(disk_filter) = disk_filter {
                 for r in &disk_filter.list {
                     if r.is_match(&disk_harvest.name) {
@@ -149,32 +401,235 @@ pub fn convert_disk_row(
                 true
             }
         })
-        .zip(&current_data.io_labels)
-        .for_each(|(disk, (io_read, io_write))| {
-            let converted_free_space = get_simple_byte_values(disk.free_space, false);
-            let converted_total_space = get_simple_byte_values(disk.total_space, false);
-            disk_vector.push(vec![
-                disk.name.to_string(),
-                disk.mount_point.to_string(),
-                format!(
-                    "{:.0}%",
-                    disk.used_space as f64 / disk.total_space as f64 * 100_f64
-                ),
-                format!("{:.*}{}", 0, converted_free_space.0, converted_free_space.1),
-                format!(
-                    "{:.*}{}",
-                    0, converted_total_space.0, converted_total_space.1
-                ),
-                io_read.to_string(),
-                io_write.to_string(),
-            ]);
+        .collect::<Vec<_>>();
+    if is_sorted_by_file_system {
+        disks.sort_by_cached_key(|(disk, _)| disk.file_system.to_lowercase());
+    }
+
+    disks.into_iter().for_each(|(disk, (io_read, io_write))| {
+        let converted_free_space = get_simple_byte_values(disk.free_space, false, byte_format);
+        let converted_total_space = get_simple_byte_values(disk.total_space, false, byte_format);
+        used_percent_vector.push(if disk.total_space > 0 {
+            Some(disk.used_space as f64 / disk.total_space as f64 * 100_f64)
+        } else {
+            None
         });
+        let mut row = vec![disk.name.to_string(), disk.mount_point.to_string()];
+        if disk_columns.file_system {
+            row.push(if disk.file_system.is_empty() {
+                "-".to_string()
+            } else {
+                disk.file_system.to_string()
+            });
+        }
+        if disk_columns.mount_options {
+            row.push(
+                disk.mount_options
+                    .clone()
+                    .unwrap_or_else(|| "-".to_string()),
+            );
+        }
+        row.extend(vec![
+            format!(
+                "{:.0}%",
+                disk.used_space as f64 / disk.total_space as f64 * 100_f64
+            ),
+            format!("{:.*}{}", 0, converted_free_space.0, converted_free_space.1),
+            format!(
+                "{:.*}{}",
+                0, converted_total_space.0, converted_total_space.1
+            ),
+            io_read.to_string(),
+            io_write.to_string(),
+        ]);
+        disk_vector.push(row);
+    });
 
-    disk_vector
+    (disk_vector, used_percent_vector)
+}
+
+/// Returns the CPU and memory usage of bottom itself, if its process was harvested.
+pub fn convert_self_usage(
+    current_data: &data_farmer::DataCollection, byte_format: &ByteFormat,
+) -> Option<String> {
+    let self_pid = std::process::id() as Pid;
+    current_data
+        .process_harvest
+        .iter()
+        .find(|process| process.pid == self_pid)
+        .map(|process| {
+            let (mem_value, mem_unit) =
+                get_exact_byte_values(process.mem_usage_bytes, false, byte_format);
+            format!(
+                " bottom: {:.1}% CPU, {:.1}{} ",
+                process.cpu_usage_percent, mem_value, mem_unit
+            )
+        })
+}
+
+/// How many processes are zombies, which is only reported once it goes over the threshold.
+#[derive(Clone, Debug, Default, Hash)]
+pub struct ZombieWarning {
+    pub zombie_count: usize,
+    /// The parent of the most zombies, which is likely failing to reap its children.
+    pub likely_parent_pid: Option<Pid>,
+}
+
+/// The state character of a process that has exited but not yet been reaped by its parent.
+const ZOMBIE_PROCESS_CHAR: char = 'Z';
+
+/// Returns a warning if more processes than the threshold are zombies.
+pub fn convert_zombie_warning(
+    current_data: &data_farmer::DataCollection, zombie_warn_threshold: u64,
+) -> Option<ZombieWarning> {
+    let zombies = current_data
+        .process_harvest
+        .iter()
+        .filter(|process| process.process_state_char == ZOMBIE_PROCESS_CHAR)
+        .collect::<Vec<_>>();
+    if zombies.len() as u64 <= zombie_warn_threshold {
+        return None;
+    }
+
+    let mut parent_counts: HashMap<Pid, usize> = HashMap::new();
+    for parent_pid in zombies.iter().filter_map(|process| process.parent_pid) {
+        *parent_counts.entry(parent_pid).or_insert(0) += 1;
+    }
+
+    Some(ZombieWarning {
+        zombie_count: zombies.len(),
+        // Ties go to the lowest PID, so the shown parent doesn't flicker between updates.
+        likely_parent_pid: parent_counts
+            .into_iter()
+            .max_by(|(a_pid, a_count), (b_pid, b_count)| {
+                a_count.cmp(b_count).then_with(|| b_pid.cmp(a_pid))
+            })
+            .map(|(parent_pid, _count)| parent_pid),
+    })
+}
+
+/// Returns how many processes and threads there are for the process widget's title, such as
+/// "312 (1204 threads)".  If grouped, the number of groups is included, and if a search is
+/// filtering the processes, the matched count is shown against the total as "20 / 312".
+pub fn convert_process_summary(
+    all_process_data: &[ConvertedProcessData],
+    matched_process_data: Option<&[&ConvertedProcessData]>, is_grouped: bool,
+    is_using_command: bool,
+) -> String {
+    let count_summary = match (is_grouped, matched_process_data) {
+        (false, None) => all_process_data.len().to_string(),
+        (false, Some(matched_process_data)) => format!(
+            "{} / {}",
+            matched_process_data.len(),
+            all_process_data.len()
+        ),
+        (true, None) => format!(
+            "{} in {} groups",
+            all_process_data.len(),
+            get_group_count(all_process_data.iter(), is_using_command)
+        ),
+        (true, Some(matched_process_data)) => format!(
+            "{} in {} / {} groups",
+            all_process_data.len(),
+            get_group_count(matched_process_data.iter().copied(), is_using_command),
+            get_group_count(all_process_data.iter(), is_using_command)
+        ),
+    };
+
+    // Thread counts aren't collected on every platform.
+    let thread_counts = all_process_data
+        .iter()
+        .filter_map(|process| process.thread_count)
+        .collect::<Vec<_>>();
+    if thread_counts.is_empty() {
+        count_summary
+    } else {
+        format!(
+            "{} ({} threads)",
+            count_summary,
+            thread_counts.iter().sum::<u64>()
+        )
+    }
+}
+
+/// Which processes have their states counted in the process widget's title.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProcessStateCountScope {
+    /// Every process, whatever the widget is showing.
+    All,
+    /// Only the processes matching the widget's search and filters.
+    Matched,
+}
+
+/// Returns how many processes are in each state, such as "2 running, 305 sleeping".  Idle and
+/// uninterruptible processes count as sleeping, stopped and zombie processes are only listed if
+/// there are any, and threads and states that fit none of these aren't counted.
+pub fn convert_process_state_counts<'a>(
+    processes: impl Iterator<Item = &'a ConvertedProcessData>,
+) -> String {
+    let (mut running, mut sleeping, mut stopped, mut zombie) = (0, 0, 0, 0);
+    for process in processes.filter(|process| process.is_thread != Some(true)) {
+        match process.process_char {
+            'R' => running += 1,
+            'S' | 'D' | 'I' => sleeping += 1,
+            STOPPED_PROCESS_CHAR | 't' => stopped += 1,
+            ZOMBIE_PROCESS_CHAR => zombie += 1,
+            _ => {}
+        }
+    }
+
+    let mut state_counts = format!("{} running, {} sleeping", running, sleeping);
+    if stopped > 0 {
+        state_counts.push_str(&format!(", {} stopped", stopped));
+    }
+    if zombie > 0 {
+        state_counts.push_str(&format!(", {} zombie", zombie));
+    }
+    state_counts
+}
+
+/// Returns how many groups the processes would be grouped into.
+fn get_group_count<'a>(
+    processes: impl Iterator<Item = &'a ConvertedProcessData>, is_using_command: bool,
+) -> usize {
+    processes
+        .map(|process| {
+            if is_using_command {
+                &process.command
+            } else {
+                &process.name
+            }
+        })
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+/// Returns the context switch and interrupt rates for the CPU graph's title, or "N/A" for
+/// both if they aren't available yet or on this platform.
+pub fn convert_scheduler_activity(current_data: &data_farmer::DataCollection) -> String {
+    match &current_data.scheduler_activity {
+        Some(activity) => format!(
+            "Ctx {}/s ─ Intr {}/s",
+            get_compact_count(activity.context_switches_per_sec),
+            get_compact_count(activity.interrupts_per_sec)
+        ),
+        None => "Ctx N/A ─ Intr N/A".to_string(),
+    }
+}
+
+/// Shortens large counts with a metric suffix, such as 12.3K.
+fn get_compact_count(count: f64) -> String {
+    if count >= 1_000_000.0 {
+        format!("{:.1}M", count / 1_000_000.0)
+    } else if count >= 1_000.0 {
+        format!("{:.1}K", count / 1_000.0)
+    } else {
+        format!("{:.0}", count)
+    }
 }
 
 pub fn convert_cpu_data_points(
-    current_data: &data_farmer::DataCollection, is_frozen: bool,
+    current_data: &data_farmer::DataCollection, is_frozen: bool, percent_precision: Option<usize>,
 ) -> Vec<ConvertedCpuData> {
     let mut cpu_data_vector: Vec<ConvertedCpuData> = Vec::new();
     let current_time = if is_frozen {
@@ -204,8 +659,20 @@ pub fn convert_cpu_data_points(
             }
 
             if let Some(cpu_data) = cpu_data_vector.get_mut(itx) {
-                cpu_data.legend_value = format!("{:.0}%", cpu.round());
+                cpu_data.legend_value = format!("{:.*}%", percent_precision.unwrap_or(0), cpu);
                 cpu_data.cpu_data.push((-time_from_start, *cpu));
+
+                if let Some(Some(breakdown)) = data.cpu_breakdown_data.get(itx) {
+                    if cpu_data.breakdown_data.is_empty() {
+                        cpu_data.breakdown_data = vec![Vec::new(); CPU_BREAKDOWN_LABELS.len()];
+                    }
+
+                    let mut stacked_value = 0.0;
+                    for (category, value) in breakdown.to_array().iter().enumerate() {
+                        stacked_value += value;
+                        cpu_data.breakdown_data[category].push((-time_from_start, stacked_value));
+                    }
+                }
             }
         }
 
@@ -218,15 +685,20 @@ pub fn convert_cpu_data_points(
         cpu_name: "All".to_string(),
         cpu_data: vec![],
         legend_value: String::new(),
+        breakdown_data: vec![],
     }];
     extended_vec.extend(cpu_data_vector);
     extended_vec
 }
 
+/// Returns the used memory points, along with the stacked breakdown of buffers and cached
+/// memory on top of it, one series per category in [`MEM_BREAKDOWN_LABELS`] order.  The
+/// breakdown is empty if it is not available on this platform.
 pub fn convert_mem_data_points(
     current_data: &data_farmer::DataCollection, is_frozen: bool,
-) -> Vec<Point> {
+) -> (Vec<Point>, Vec<Vec<Point>>) {
     let mut result: Vec<Point> = Vec::new();
+    let mut breakdown_result: Vec<Vec<Point>> = Vec::new();
     let current_time = if is_frozen {
         if let Some(frozen_instant) = current_data.frozen_instant {
             frozen_instant
@@ -240,12 +712,21 @@ pub fn convert_mem_data_points(
     for (time, data) in &current_data.timed_data_vec {
         let time_from_start: f64 = (current_time.duration_since(*time).as_millis() as f64).floor();
         result.push((-time_from_start, data.mem_data));
+
+        if let (Some(buffers), Some(cached)) = (data.mem_buffers_data, data.mem_cached_data) {
+            if breakdown_result.is_empty() {
+                breakdown_result = vec![Vec::new(); MEM_BREAKDOWN_LABELS.len()];
+            }
+            breakdown_result[0].push((-time_from_start, data.mem_data + buffers));
+            breakdown_result[1].push((-time_from_start, data.mem_data + buffers + cached));
+        }
+
         if *time == current_time {
             break;
         }
     }
 
-    result
+    (result, breakdown_result)
 }
 
 pub fn convert_swap_data_points(
@@ -274,11 +755,13 @@ pub fn convert_swap_data_points(
 }
 
 pub fn convert_mem_labels(
-    current_data: &data_farmer::DataCollection,
+    current_data: &data_farmer::DataCollection, percent_precision: Option<usize>,
 ) -> (String, String, String, String) {
+    let percent_precision = percent_precision.unwrap_or(0);
     (
         format!(
-            "{:3.0}%",
+            "{:3.*}%",
+            percent_precision,
             match current_data.memory_harvest.mem_total_in_mb {
                 0 => 0.0,
                 _ =>
@@ -292,7 +775,8 @@ pub fn convert_mem_labels(
             (current_data.memory_harvest.mem_total_in_mb as f64 / 1024.0)
         ),
         format!(
-            "{:3.0}%",
+            "{:3.*}%",
+            percent_precision,
             match current_data.swap_harvest.mem_total_in_mb {
                 0 => 0.0,
                 _ =>
@@ -308,6 +792,76 @@ pub fn convert_mem_labels(
     )
 }
 
+/// The weights of each part of the memory pressure score, see [`convert_mem_pressure`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemPressureWeights {
+    pub memory: f64,
+    pub swap: f64,
+    pub swap_activity: f64,
+    /// The pages swapped in and out per second at which swap activity counts as 100%.
+    pub swap_activity_max_pages: f64,
+}
+
+impl Default for MemPressureWeights {
+    fn default() -> Self {
+        MemPressureWeights {
+            memory: 0.5,
+            swap: 0.2,
+            swap_activity: 0.3,
+            swap_activity_max_pages: 1000.0,
+        }
+    }
+}
+
+/// Returns a memory pressure score from 0 to 100, which is the weighted average of:
+///
+/// - the percentage of memory used,
+/// - the percentage of swap used, and
+/// - the pages swapped in and out per second, as a percentage of `swap_activity_max_pages`
+///   (capped at 100%).
+///
+/// Parts that are not available, such as swap on systems without any swap, are left out of
+/// the average.  Returns [`None`] if nothing with a weight is available.
+pub fn convert_mem_pressure(
+    current_data: &data_farmer::DataCollection, weights: &MemPressureWeights,
+) -> Option<f64> {
+    let get_percent = |used: u64, total: u64| used as f64 / total as f64 * 100.0;
+
+    let mut parts = Vec::new();
+    if current_data.memory_harvest.mem_total_in_mb > 0 {
+        parts.push((
+            weights.memory,
+            get_percent(
+                current_data.memory_harvest.mem_used_in_mb,
+                current_data.memory_harvest.mem_total_in_mb,
+            ),
+        ));
+    }
+    if current_data.swap_harvest.mem_total_in_mb > 0 {
+        parts.push((
+            weights.swap,
+            get_percent(
+                current_data.swap_harvest.mem_used_in_mb,
+                current_data.swap_harvest.mem_total_in_mb,
+            ),
+        ));
+        if let Some(swap_activity_rate) = current_data.swap_activity_rate {
+            parts.push((
+                weights.swap_activity,
+                (swap_activity_rate / weights.swap_activity_max_pages * 100.0).min(100.0),
+            ));
+        }
+    }
+
+    let total_weight: f64 = parts.iter().map(|(weight, _)| weight).sum();
+    if total_weight > 0.0 {
+        let weighted_sum: f64 = parts.iter().map(|(weight, percent)| weight * percent).sum();
+        Some((weighted_sum / total_weight).max(0.0).min(100.0))
+    } else {
+        None
+    }
+}
+
 pub fn get_rx_tx_data_points(
     current_data: &data_farmer::DataCollection, is_frozen: bool,
 ) -> (Vec<Point>, Vec<Point>) {
@@ -338,30 +892,55 @@ pub fn get_rx_tx_data_points(
 
 pub fn convert_network_data_points(
     current_data: &data_farmer::DataCollection, is_frozen: bool, need_four_points: bool,
+    rate_precision: Option<usize>, use_session_total: bool, byte_format: &ByteFormat,
 ) -> ConvertedNetworkData {
+    let rate_precision = rate_precision.unwrap_or(1);
     let (rx, tx) = get_rx_tx_data_points(current_data, is_frozen);
+    let (total_rx, total_tx) = if use_session_total {
+        current_data.get_network_session_totals()
+    } else {
+        (
+            current_data.network_harvest.total_rx,
+            current_data.network_harvest.total_tx,
+        )
+    };
+    let total_label = if use_session_total { "Session" } else { "Boot" };
 
     let total_rx_converted_result: (f64, String);
     let rx_converted_result: (f64, String);
     let total_tx_converted_result: (f64, String);
     let tx_converted_result: (f64, String);
 
-    rx_converted_result = get_exact_byte_values(current_data.network_harvest.rx, false);
-    total_rx_converted_result = get_exact_byte_values(current_data.network_harvest.total_rx, false);
+    rx_converted_result = get_exact_byte_values(
+        current_data.network_rate_averages.0.round() as u64,
+        false,
+        byte_format,
+    );
+    total_rx_converted_result = get_exact_byte_values(total_rx, false, byte_format);
 
-    tx_converted_result = get_exact_byte_values(current_data.network_harvest.tx, false);
-    total_tx_converted_result = get_exact_byte_values(current_data.network_harvest.total_tx, false);
+    tx_converted_result = get_exact_byte_values(
+        current_data.network_rate_averages.1.round() as u64,
+        false,
+        byte_format,
+    );
+    total_tx_converted_result = get_exact_byte_values(total_tx, false, byte_format);
 
     if need_four_points {
-        let rx_display = format!("{:.*}{}", 1, rx_converted_result.0, rx_converted_result.1);
+        let rx_display = format!(
+            "{:.*}{}",
+            rate_precision, rx_converted_result.0, rx_converted_result.1
+        );
         let total_rx_display = Some(format!(
             "{:.*}{}",
-            1, total_rx_converted_result.0, total_rx_converted_result.1
+            rate_precision, total_rx_converted_result.0, total_rx_converted_result.1
         ));
-        let tx_display = format!("{:.*}{}", 1, tx_converted_result.0, tx_converted_result.1);
+        let tx_display = format!(
+            "{:.*}{}",
+            rate_precision, tx_converted_result.0, tx_converted_result.1
+        );
         let total_tx_display = Some(format!(
             "{:.*}{}",
-            1, total_tx_converted_result.0, total_tx_converted_result.1
+            rate_precision, total_tx_converted_result.0, total_tx_converted_result.1
         ));
         ConvertedNetworkData {
             rx,
@@ -373,19 +952,27 @@ pub fn convert_network_data_points(
         }
     } else {
         let rx_display = format!(
-            "RX: {:<9} All: {:<9}",
-            format!("{:.1}{:3}", rx_converted_result.0, rx_converted_result.1),
+            "RX: {:<9} {}: {:<9}",
+            format!(
+                "{:.*}{:3}",
+                rate_precision, rx_converted_result.0, rx_converted_result.1
+            ),
+            total_label,
             format!(
-                "{:.1}{:3}",
-                total_rx_converted_result.0, total_rx_converted_result.1
+                "{:.*}{:3}",
+                rate_precision, total_rx_converted_result.0, total_rx_converted_result.1
             )
         );
         let tx_display = format!(
-            "TX: {:<9} All: {:<9}",
-            format!("{:.1}{:3}", tx_converted_result.0, tx_converted_result.1),
+            "TX: {:<9} {}: {:<9}",
             format!(
-                "{:.1}{:3}",
-                total_tx_converted_result.0, total_tx_converted_result.1
+                "{:.*}{:3}",
+                rate_precision, tx_converted_result.0, tx_converted_result.1
+            ),
+            total_label,
+            format!(
+                "{:.*}{:3}",
+                rate_precision, total_tx_converted_result.0, total_tx_converted_result.1
             )
         );
 
@@ -410,9 +997,60 @@ pub enum ProcessNamingType {
     Path,
 }
 
+/// How the command of a process is shown when displaying commands rather than names.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommandDisplay {
+    /// The full command line.
+    Full,
+    /// Only the basename of the executable.
+    Basename,
+    /// The executable followed by its first argument.
+    FirstArg,
+}
+
+impl Default for CommandDisplay {
+    fn default() -> Self {
+        CommandDisplay::Full
+    }
+}
+
+/// Returns how a command is shown in the process table.  Everything else, such as searching,
+/// grouping, and sorting, uses the full command.
+pub fn get_displayed_command(command: &str, command_display: &CommandDisplay) -> String {
+    match command_display {
+        CommandDisplay::Full => command.to_string(),
+        CommandDisplay::Basename => {
+            let executable = command.split_whitespace().next().unwrap_or(command);
+            std::path::Path::new(executable)
+                .file_name()
+                .map(|basename| basename.to_string_lossy().to_string())
+                .unwrap_or_else(|| executable.to_string())
+        }
+        CommandDisplay::FirstArg => command
+            .split_whitespace()
+            .take(2)
+            .collect::<Vec<_>>()
+            .join(" "),
+    }
+}
+
+/// If `per_core_cpu` is set, CPU% is scaled from being relative to all cores to being relative
+/// to a single core.
 pub fn convert_process_data(
-    current_data: &data_farmer::DataCollection,
+    current_data: &data_farmer::DataCollection, rate_precision: Option<usize>, per_core_cpu: bool,
+    byte_format: &ByteFormat,
 ) -> Vec<ConvertedProcessData> {
+    let rate_precision = rate_precision.unwrap_or(0);
+    let cpu_scale = if per_core_cpu {
+        current_data
+            .cpu_harvest
+            .iter()
+            .filter(|cpu| cpu.cpu_name != "AVG")
+            .count()
+            .max(1) as f64
+    } else {
+        1.0
+    };
     // FIXME: Thread highlighting and hiding support
     // For macOS see https://github.com/hishamhm/htop/pull/848/files
 
@@ -420,13 +1058,23 @@ pub fn convert_process_data(
         .process_harvest
         .iter()
         .map(|process| {
-            let converted_rps = get_exact_byte_values(process.read_bytes_per_sec, false);
-            let converted_wps = get_exact_byte_values(process.write_bytes_per_sec, false);
-            let converted_total_read = get_exact_byte_values(process.total_read_bytes, false);
-            let converted_total_write = get_exact_byte_values(process.total_write_bytes, false);
-
-            let read_per_sec = format!("{:.*}{}/s", 0, converted_rps.0, converted_rps.1);
-            let write_per_sec = format!("{:.*}{}/s", 0, converted_wps.0, converted_wps.1);
+            let converted_rps =
+                get_exact_byte_values(process.read_bytes_per_sec, false, byte_format);
+            let converted_wps =
+                get_exact_byte_values(process.write_bytes_per_sec, false, byte_format);
+            let converted_total_read =
+                get_exact_byte_values(process.total_read_bytes, false, byte_format);
+            let converted_total_write =
+                get_exact_byte_values(process.total_write_bytes, false, byte_format);
+
+            let read_per_sec = format!(
+                "{:.*}{}/s",
+                rate_precision, converted_rps.0, converted_rps.1
+            );
+            let write_per_sec = format!(
+                "{:.*}{}/s",
+                rate_precision, converted_wps.0, converted_wps.1
+            );
             let total_read = format!("{:.*}{}", 0, converted_total_read.0, converted_total_read.1);
             let total_write = format!(
                 "{:.*}{}",
@@ -439,10 +1087,11 @@ pub fn convert_process_data(
                 is_thread: None,
                 name: process.name.to_string(),
                 command: process.command.to_string(),
-                cpu_percent_usage: process.cpu_usage_percent,
+                cpu_percent_usage: process.cpu_usage_percent * cpu_scale,
                 mem_percent_usage: process.mem_usage_percent,
                 mem_usage_bytes: process.mem_usage_bytes,
-                mem_usage_str: get_exact_byte_values(process.mem_usage_bytes, false),
+                mem_usage_str: get_exact_byte_values(process.mem_usage_bytes, false, byte_format),
+                swap_bytes: process.swap_bytes,
                 group_pids: vec![process.pid],
                 read_per_sec,
                 write_per_sec,
@@ -454,8 +1103,15 @@ pub fn convert_process_data(
                 tw_f64: process.total_write_bytes as f64,
                 process_state: process.process_state.to_owned(),
                 process_char: process.process_state_char,
+                thread_count: process.thread_count,
+                oom_score: process.oom_score,
+                cpu_affinity: process.cpu_affinity.clone(),
+                uid: process.uid,
+                start_time: process.start_time,
                 process_description_prefix: None,
                 is_disabled_entry: false,
+                is_pinned: false,
+                diff: None,
             }
         })
         .collect::<Vec<_>>()
@@ -468,7 +1124,7 @@ const BRANCH_HORIZONTAL: char = '─';
 
 pub fn tree_process_data(
     single_process_data: &[ConvertedProcessData], is_using_command: bool,
-    sort_type: &ProcessSorting, is_sort_descending: bool,
+    command_display: &CommandDisplay, sort_type: &ProcessSorting, is_sort_descending: bool,
 ) -> Vec<ConvertedProcessData> {
     // TODO: [TREE] Allow for collapsing entries.
 
@@ -667,6 +1323,11 @@ pub fn tree_process_data(
                     });
                 }
             }
+            ProcessSorting::ParentPid => {
+                to_sort_vec.sort_by(|a, b| {
+                    utils::gen_util::get_ordering(a.1.ppid, b.1.ppid, is_sort_descending)
+                });
+            }
             ProcessSorting::ReadPerSecond => {
                 to_sort_vec.sort_by(|a, b| {
                     utils::gen_util::get_ordering(a.1.rps_f64, b.1.rps_f64, is_sort_descending)
@@ -694,6 +1355,26 @@ pub fn tree_process_data(
                     is_sort_descending,
                 )
             }),
+            ProcessSorting::Threads => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(
+                    a.1.thread_count,
+                    b.1.thread_count,
+                    is_sort_descending,
+                )
+            }),
+            ProcessSorting::OomScore => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(a.1.oom_score, b.1.oom_score, is_sort_descending)
+            }),
+            ProcessSorting::Swap => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(a.1.swap_bytes, b.1.swap_bytes, is_sort_descending)
+            }),
+            ProcessSorting::CpuAffinity => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(
+                    a.1.cpu_affinity.as_ref().map(Vec::len),
+                    b.1.cpu_affinity.as_ref().map(Vec::len),
+                    is_sort_descending,
+                )
+            }),
             ProcessSorting::Count => {
                 // Should never occur in this case.
             }
@@ -789,9 +1470,9 @@ pub fn tree_process_data(
                     "{}{}",
                     prefix,
                     if is_using_command {
-                        &p.command
+                        get_displayed_command(&p.command, command_display)
                     } else {
-                        &p.name
+                        p.name.clone()
                     }
                 ));
                 Some(p)
@@ -803,19 +1484,26 @@ pub fn tree_process_data(
 
 pub fn group_process_data(
     single_process_data: &[ConvertedProcessData], is_using_command: bool,
+    rate_precision: Option<usize>, byte_format: &ByteFormat,
 ) -> Vec<ConvertedProcessData> {
+    let rate_precision = rate_precision.unwrap_or(0);
     #[derive(Clone, Default, Debug)]
     struct SingleProcessData {
         pub pid: Pid,
         pub cpu_percent_usage: f64,
         pub mem_percent_usage: f64,
         pub mem_usage_bytes: u64,
+        pub swap_bytes: Option<u64>,
         pub group_pids: Vec<Pid>,
         pub read_per_sec: f64,
         pub write_per_sec: f64,
         pub total_read: f64,
         pub total_write: f64,
         pub process_state: String,
+        pub thread_count: Option<u64>,
+        pub oom_score: Option<u64>,
+        pub cpu_affinity: Option<Vec<usize>>,
+        pub stopped_count: usize,
     }
 
     let mut grouped_hashmap: HashMap<String, SingleProcessData> = std::collections::HashMap::new();
@@ -835,24 +1523,52 @@ pub fn group_process_data(
         (*entry).cpu_percent_usage += process.cpu_percent_usage;
         (*entry).mem_percent_usage += process.mem_percent_usage;
         (*entry).mem_usage_bytes += process.mem_usage_bytes;
+        if let Some(swap_bytes) = process.swap_bytes {
+            *(*entry).swap_bytes.get_or_insert(0) += swap_bytes;
+        }
         (*entry).group_pids.push(process.pid);
         (*entry).read_per_sec += process.rps_f64;
         (*entry).write_per_sec += process.wps_f64;
         (*entry).total_read += process.tr_f64;
         (*entry).total_write += process.tw_f64;
+        if let Some(thread_count) = process.thread_count {
+            *(*entry).thread_count.get_or_insert(0) += thread_count;
+        }
+        if process.is_stopped() {
+            (*entry).stopped_count += 1;
+        }
+        // A group is as likely to be killed as its likeliest process.
+        if process.oom_score > (*entry).oom_score {
+            (*entry).oom_score = process.oom_score;
+        }
+        // A group may run on any CPU that one of its processes may run on.
+        if let Some(cpu_affinity) = &process.cpu_affinity {
+            let group_affinity = (*entry).cpu_affinity.get_or_insert_with(Vec::new);
+            group_affinity.extend(cpu_affinity);
+            group_affinity.sort_unstable();
+            group_affinity.dedup();
+        }
     });
 
     grouped_hashmap
         .iter()
         .map(|(identifier, process_details)| {
             let p = process_details.clone();
-            let converted_rps = get_exact_byte_values(p.read_per_sec as u64, false);
-            let converted_wps = get_exact_byte_values(p.write_per_sec as u64, false);
-            let converted_total_read = get_exact_byte_values(p.total_read as u64, false);
-            let converted_total_write = get_exact_byte_values(p.total_write as u64, false);
-
-            let read_per_sec = format!("{:.*}{}/s", 0, converted_rps.0, converted_rps.1);
-            let write_per_sec = format!("{:.*}{}/s", 0, converted_wps.0, converted_wps.1);
+            let converted_rps = get_exact_byte_values(p.read_per_sec as u64, false, byte_format);
+            let converted_wps = get_exact_byte_values(p.write_per_sec as u64, false, byte_format);
+            let converted_total_read =
+                get_exact_byte_values(p.total_read as u64, false, byte_format);
+            let converted_total_write =
+                get_exact_byte_values(p.total_write as u64, false, byte_format);
+
+            let read_per_sec = format!(
+                "{:.*}{}/s",
+                rate_precision, converted_rps.0, converted_rps.1
+            );
+            let write_per_sec = format!(
+                "{:.*}{}/s",
+                rate_precision, converted_wps.0, converted_wps.1
+            );
             let total_read = format!("{:.*}{}", 0, converted_total_read.0, converted_total_read.1);
             let total_write = format!(
                 "{:.*}{}",
@@ -868,7 +1584,8 @@ pub fn group_process_data(
                 cpu_percent_usage: p.cpu_percent_usage,
                 mem_percent_usage: p.mem_percent_usage,
                 mem_usage_bytes: p.mem_usage_bytes,
-                mem_usage_str: get_exact_byte_values(p.mem_usage_bytes, false),
+                mem_usage_str: get_exact_byte_values(p.mem_usage_bytes, false, byte_format),
+                swap_bytes: p.swap_bytes,
                 group_pids: p.group_pids,
                 read_per_sec,
                 write_per_sec,
@@ -880,13 +1597,37 @@ pub fn group_process_data(
                 tw_f64: p.total_write,
                 process_state: p.process_state, // TODO: What the heck
                 process_description_prefix: None,
-                process_char: char::default(), // TODO: What the heck
+                // A group only counts as stopped if all of its processes are.
+                process_char: if p.stopped_count == p.group_pids.len() {
+                    STOPPED_PROCESS_CHAR
+                } else {
+                    char::default()
+                },
+                thread_count: p.thread_count,
+                oom_score: p.oom_score,
+                cpu_affinity: p.cpu_affinity,
+                uid: None,
+                start_time: None,
                 is_disabled_entry: false,
+                is_pinned: false,
+                diff: None,
             }
         })
         .collect::<Vec<_>>()
 }
 
+/// Formats a battery's time until full or empty like "2h15m".
+fn format_battery_duration(secs: i64) -> String {
+    let time = chrono::Duration::seconds(secs);
+    format!(
+        "{}h{}m",
+        time.num_hours(),
+        time.num_minutes() - time.num_hours() * 60
+    )
+}
+
+/// Converts the batteries for display.  If the OS doesn't report how long a battery will take
+/// to fill or empty, an estimate from its recent charge readings is shown instead if possible.
 pub fn convert_battery_harvest(
     current_data: &data_farmer::DataCollection,
 ) -> Vec<ConvertedBatteryData> {
@@ -894,43 +1635,81 @@ pub fn convert_battery_harvest(
         .battery_harvest
         .iter()
         .enumerate()
-        .map(|(itx, battery_harvest)| ConvertedBatteryData {
-            battery_name: format!("Battery {}", itx),
-            charge_percentage: battery_harvest.charge_percent,
-            watt_consumption: format!("{:.2}W", battery_harvest.power_consumption_rate_watts),
-            duration_until_empty: if let Some(secs_till_empty) = battery_harvest.secs_until_empty {
-                let time = chrono::Duration::seconds(secs_till_empty);
-                let num_minutes = time.num_minutes() - time.num_hours() * 60;
-                let num_seconds = time.num_seconds() - time.num_minutes() * 60;
-                Some(format!(
-                    "{} hour{}, {} minute{}, {} second{}",
-                    time.num_hours(),
-                    if time.num_hours() == 1 { "" } else { "s" },
-                    num_minutes,
-                    if num_minutes == 1 { "" } else { "s" },
-                    num_seconds,
-                    if num_seconds == 1 { "" } else { "s" },
-                ))
-            } else {
-                None
-            },
-            duration_until_full: if let Some(secs_till_full) = battery_harvest.secs_until_full {
-                let time = chrono::Duration::seconds(secs_till_full);
-                let num_minutes = time.num_minutes() - time.num_hours() * 60;
-                let num_seconds = time.num_seconds() - time.num_minutes() * 60;
-                Some(format!(
-                    "{} hour{}, {} minute{}, {} second{}",
-                    time.num_hours(),
-                    if time.num_hours() == 1 { "" } else { "s" },
-                    num_minutes,
-                    if num_minutes == 1 { "" } else { "s" },
-                    num_seconds,
-                    if num_seconds == 1 { "" } else { "s" },
-                ))
-            } else {
-                None
-            },
-            health: format!("{:.2}%", battery_harvest.health_percent),
+        .map(|(itx, battery_harvest)| {
+            let estimate = current_data
+                .battery_charge_histories
+                .get(itx)
+                .and_then(data_farmer::BatteryChargeHistory::estimate_secs_remaining);
+
+            ConvertedBatteryData {
+                battery_name: battery_harvest.name.clone(),
+                state: battery_harvest.state.to_string(),
+                charge_percentage: battery_harvest.charge_percent,
+                watt_consumption: format!("{:.2}W", battery_harvest.power_consumption_rate_watts),
+                duration_until_empty: match (battery_harvest.secs_until_empty, estimate) {
+                    (Some(secs_until_empty), _) => Some(format!(
+                        "{} remaining",
+                        format_battery_duration(secs_until_empty)
+                    )),
+                    (None, Some(secs_until_empty))
+                        if battery_harvest.state == BatteryState::Discharging =>
+                    {
+                        Some(format!(
+                            "~{} remaining (est.)",
+                            format_battery_duration(secs_until_empty)
+                        ))
+                    }
+                    _ => None,
+                },
+                duration_until_full: match (battery_harvest.secs_until_full, estimate) {
+                    (Some(secs_until_full), _) => Some(format_battery_duration(secs_until_full)),
+                    (None, Some(secs_until_full))
+                        if battery_harvest.state == BatteryState::Charging =>
+                    {
+                        Some(format!(
+                            "~{} (est.)",
+                            format_battery_duration(secs_until_full)
+                        ))
+                    }
+                    _ => None,
+                },
+                health: format!("{:.2}%", battery_harvest.health_percent),
+            }
         })
         .collect()
 }
+
+/// Returns a cheap fingerprint of finalized process data, used to tell whether anything shown
+/// in the process widget changed since it was last drawn.
+pub fn get_process_data_fingerprint(process_data: &[ConvertedProcessData]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for process in process_data {
+        process.pid.hash(&mut hasher);
+        process.ppid.hash(&mut hasher);
+        process.name.hash(&mut hasher);
+        process.command.hash(&mut hasher);
+        process.cpu_percent_usage.to_bits().hash(&mut hasher);
+        process.mem_percent_usage.to_bits().hash(&mut hasher);
+        process.mem_usage_bytes.hash(&mut hasher);
+        process.swap_bytes.hash(&mut hasher);
+        process.group_pids.len().hash(&mut hasher);
+        process.read_per_sec.hash(&mut hasher);
+        process.write_per_sec.hash(&mut hasher);
+        process.total_read.hash(&mut hasher);
+        process.total_write.hash(&mut hasher);
+        process.process_state.hash(&mut hasher);
+        process.thread_count.hash(&mut hasher);
+        process.oom_score.hash(&mut hasher);
+        process.cpu_affinity.hash(&mut hasher);
+        process.process_description_prefix.hash(&mut hasher);
+        process.is_disabled_entry.hash(&mut hasher);
+        process.is_pinned.hash(&mut hasher);
+        if let Some(diff) = &process.diff {
+            diff.status.hash(&mut hasher);
+            diff.cpu_percent_delta.to_bits().hash(&mut hasher);
+            diff.mem_percent_delta.to_bits().hash(&mut hasher);
+            diff.mem_bytes_delta.hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
@@ -5,45 +5,59 @@ extern crate log;
 
 use std::{
     boxed::Box,
+    collections::{HashMap, HashSet},
     fs,
-    io::{stdout, Write},
+    io::{stdout, BufRead, BufReader, Write},
     panic::PanicInfo,
     path::PathBuf,
+    process::{Command, Stdio},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::RecvTimeoutError,
+        Arc,
+    },
     thread,
     time::{Duration, Instant},
 };
 
 use crossterm::{
-    event::{poll, read, DisableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers, MouseEvent},
+    event::{
+        poll, read, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent,
+        KeyModifiers, MouseEvent,
+    },
     execute,
     style::Print,
-    terminal::{disable_raw_mode, LeaveAlternateScreen},
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 
 use anyhow::Context;
 
 use app::{
-    data_harvester::{self, processes::ProcessSorting},
+    data_harvester::{self, processes::ProcessSorting, recording},
     layout_manager::{UsedWidgets, WidgetDirection},
-    App,
+    App, QuitConfirmation,
 };
 use constants::*;
 use data_conversion::*;
 use options::*;
-use utils::error;
+use utils::{crash_report::CrashReport, error, gen_util::ByteFormat};
 
 pub mod app;
 
 pub mod utils {
+    pub mod clipboard;
+    pub mod crash_report;
     pub mod error;
     pub mod gen_util;
     pub mod logging;
+    pub mod pager;
 }
 
 pub mod canvas;
 pub mod constants;
 pub mod data_conversion;
 pub mod options;
+pub mod snapshot;
 
 pub mod clap;
 
@@ -57,11 +71,21 @@ pub enum BottomEvent<I, J> {
     KeyInput(I),
     MouseInput(J),
     Update(Box<data_harvester::Data>),
+    /// The connection to the remote host was lost, and will be retried.
+    RemoteDisconnected,
     Clean,
 }
 
-pub enum ResetEvent {
+pub enum ThreadControlEvent {
     Reset,
+    /// Changes how often data is harvested, in milliseconds.
+    UpdateUpdateRate(u64),
+    /// Harvests once right away, without moving when the next regular harvest happens.
+    RefreshNow,
+    /// Changes which of the optional process fields are read.
+    UpdateProcessFields(data_harvester::processes::ProcessFields),
+    /// Changes whether the CPU usage breakdown is read.
+    UpdateCpuBreakdown(bool),
 }
 
 pub fn handle_mouse_event(event: MouseEvent, app: &mut App) {
@@ -86,8 +110,82 @@ pub fn handle_mouse_event(event: MouseEvent, app: &mut App) {
     };
 }
 
+/// Handles a press of a quit key, returning whether to quit now or wait for confirmation.
+fn handle_quit_key(app: &mut App, key_name: &str) -> bool {
+    match app.app_config_fields.quit_confirmation {
+        QuitConfirmation::Immediate => return true,
+        QuitConfirmation::Double => {
+            let now = Instant::now();
+            if let Some(quit_pressed_at) = app.quit_pressed_at {
+                if now.duration_since(quit_pressed_at).as_millis()
+                    <= QUIT_DOUBLE_PRESS_MILLISECONDS as u128
+                {
+                    return true;
+                }
+            }
+            app.quit_pressed_at = Some(now);
+            app.show_message(format!("Press {} again to quit", key_name));
+        }
+        QuitConfirmation::Prompt => {
+            app.is_showing_quit_prompt = true;
+        }
+    }
+    false
+}
+
+/// Tells the harvester which optional process fields to read, if the process widgets now need
+/// different ones than it was last told.
+pub fn send_process_fields_if_changed(
+    app: &mut App, thread_control_sender: &std::sync::mpsc::Sender<ThreadControlEvent>,
+) {
+    let process_fields = app.get_process_fields();
+    if process_fields != app.harvested_process_fields {
+        app.harvested_process_fields = process_fields;
+        thread_control_sender
+            .send(ThreadControlEvent::UpdateProcessFields(process_fields))
+            .ok();
+    }
+}
+
+/// Tells the harvester whether to read the CPU usage breakdown, if a CPU widget has started or
+/// stopped showing it.
+pub fn send_cpu_breakdown_if_changed(
+    app: &mut App, thread_control_sender: &std::sync::mpsc::Sender<ThreadControlEvent>,
+) {
+    let is_showing_cpu_breakdown = app.is_showing_cpu_breakdown();
+    if is_showing_cpu_breakdown != app.is_harvesting_cpu_breakdown {
+        app.is_harvesting_cpu_breakdown = is_showing_cpu_breakdown;
+        thread_control_sender
+            .send(ThreadControlEvent::UpdateCpuBreakdown(
+                is_showing_cpu_breakdown,
+            ))
+            .ok();
+    }
+}
+
+/// Asks the harvester to refresh right away if the event is the refresh key, which may be given
+/// with shift as terminals report uppercase letters with it.  Returns whether the request was
+/// sent, or [`None`] if the event isn't the refresh key.
+pub fn send_refresh_if_refresh_key(
+    event: &KeyEvent, refresh_key: char,
+    thread_control_sender: &std::sync::mpsc::Sender<ThreadControlEvent>,
+) -> Option<bool> {
+    if event.code == KeyCode::Char(refresh_key)
+        && (event.modifiers.is_empty() || event.modifiers == KeyModifiers::SHIFT)
+    {
+        Some(
+            thread_control_sender
+                .send(ThreadControlEvent::RefreshNow)
+                .is_ok(),
+        )
+    } else {
+        None
+    }
+}
+
 pub fn handle_key_event_or_break(
-    event: KeyEvent, app: &mut App, reset_sender: &std::sync::mpsc::Sender<ResetEvent>,
+    event: KeyEvent, app: &mut App,
+    thread_control_sender: &std::sync::mpsc::Sender<ThreadControlEvent>,
 ) -> bool {
     // debug!("KeyEvent: {:?}", event);
 
@@ -96,10 +194,36 @@ pub fn handle_key_event_or_break(
     // We can solve this (when we do paste probably) while keeping the throttle (mainly meant for movement)
     // by throttling after *bulk+singular* actions, not just singular ones.
 
+    if app.is_showing_quit_prompt {
+        // Any key other than a confirmation closes the prompt without doing anything else.
+        app.is_showing_quit_prompt = false;
+        return match event.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Char('q') | KeyCode::Enter => true,
+            KeyCode::Char('c') => event.modifiers == KeyModifiers::CONTROL,
+            _ => false,
+        };
+    }
+
+    if !app.is_in_search_widget() && !app.is_in_dialog() {
+        if let Some(is_sent) = send_refresh_if_refresh_key(
+            &event,
+            app.app_config_fields.refresh_key,
+            thread_control_sender,
+        ) {
+            if is_sent {
+                app.show_message("Refreshing".to_string());
+            }
+            return false;
+        }
+    }
+
     if event.modifiers.is_empty() {
         // Required catch for searching - otherwise you couldn't search with q.
-        if event.code == KeyCode::Char('q') && !app.is_in_search_widget() {
-            return true;
+        if event.code == KeyCode::Char('q')
+            && !app.is_in_search_widget()
+            && !app.help_dialog_state.is_searching
+        {
+            return handle_quit_key(app, "q");
         }
         match event.code {
             KeyCode::End => app.skip_to_last(),
@@ -117,6 +241,7 @@ pub fn handle_key_event_or_break(
             KeyCode::F(1) => app.toggle_ignore_case(),
             KeyCode::F(2) => app.toggle_search_whole_word(),
             KeyCode::F(3) => app.toggle_search_regex(),
+            KeyCode::F(4) => app.toggle_search_invert(),
             KeyCode::F(5) => app.toggle_tree_mode(),
             KeyCode::F(6) => app.toggle_sort(),
             _ => {}
@@ -128,8 +253,12 @@ pub fn handle_key_event_or_break(
                 KeyCode::Char('c') | KeyCode::Char('C') => app.toggle_ignore_case(),
                 KeyCode::Char('w') | KeyCode::Char('W') => app.toggle_search_whole_word(),
                 KeyCode::Char('r') | KeyCode::Char('R') => app.toggle_search_regex(),
+                KeyCode::Char('i') | KeyCode::Char('I') => app.toggle_search_invert(),
                 KeyCode::Char('h') => app.on_left_key(),
                 KeyCode::Char('l') => app.on_right_key(),
+                KeyCode::Char('q') if app.app_config_fields.quit_from_search => {
+                    return handle_quit_key(app, "Alt-q");
+                }
                 _ => {}
             }
         } else if let KeyModifiers::CONTROL = event.modifiers {
@@ -144,7 +273,10 @@ pub fn handle_key_event_or_break(
                 KeyCode::Up => app.move_widget_selection(&WidgetDirection::Up),
                 KeyCode::Down => app.move_widget_selection(&WidgetDirection::Down),
                 KeyCode::Char('r') => {
-                    if reset_sender.send(ResetEvent::Reset).is_ok() {
+                    if thread_control_sender
+                        .send(ThreadControlEvent::Reset)
+                        .is_ok()
+                    {
                         app.reset();
                     }
                 }
@@ -285,6 +417,13 @@ pub fn generate_config_colours(
                 .context("Update 'cpu_core_colors' in your config file..")?;
         }
 
+        if let Some(cpu_breakdown_colors) = &colours.cpu_breakdown_colors {
+            painter
+                .colours
+                .set_cpu_breakdown_colours(cpu_breakdown_colors)
+                .context("Update 'cpu_breakdown_colors' in your config file..")?;
+        }
+
         if let Some(ram_color) = &colours.ram_color {
             painter
                 .colours
@@ -292,6 +431,20 @@ pub fn generate_config_colours(
                 .context("Update 'ram_color' in your config file..")?;
         }
 
+        if let Some(buffers_color) = &colours.buffers_color {
+            painter
+                .colours
+                .set_buffers_colour(buffers_color)
+                .context("Update 'buffers_color' in your config file..")?;
+        }
+
+        if let Some(cached_color) = &colours.cached_color {
+            painter
+                .colours
+                .set_cached_colour(cached_color)
+                .context("Update 'cached_color' in your config file..")?;
+        }
+
         if let Some(swap_color) = &colours.swap_color {
             painter
                 .colours
@@ -356,6 +509,48 @@ pub fn generate_config_colours(
                 .context("Update 'graph_color' in your config file..")?;
         }
 
+        if let Some(pinned_process_color) = &colours.pinned_process_color {
+            painter
+                .colours
+                .set_pinned_process_colour(pinned_process_color)
+                .context("Update 'pinned_process_color' in your config file..")?;
+        }
+
+        if let Some(watched_process_bg_color) = &colours.watched_process_bg_color {
+            painter
+                .colours
+                .set_watched_process_bg_colour(watched_process_bg_color)
+                .context("Update 'watched_process_bg_color' in your config file..")?;
+        }
+
+        if let Some(mem_alarm_color) = &colours.mem_alarm_color {
+            painter
+                .colours
+                .set_mem_alarm_colour(mem_alarm_color)
+                .context("Update 'mem_alarm_color' in your config file..")?;
+        }
+
+        if let Some(disk_alarm_color) = &colours.disk_alarm_color {
+            painter
+                .colours
+                .set_disk_alarm_colour(disk_alarm_color)
+                .context("Update 'disk_alarm_color' in your config file..")?;
+        }
+
+        if let Some(disabled_entry_color) = &colours.disabled_entry_color {
+            painter
+                .colours
+                .set_disabled_entry_colour(disabled_entry_color)
+                .context("Update 'disabled_entry_color' in your config file..")?;
+        }
+
+        if let Some(alt_row_color) = &colours.alt_row_color {
+            painter
+                .colours
+                .set_alt_row_colour(alt_row_color)
+                .context("Update 'alt_row_color' in your config file..")?;
+        }
+
         if let Some(battery_colors) = &colours.battery_colors {
             painter
                 .colours
@@ -364,6 +559,35 @@ pub fn generate_config_colours(
         }
     }
 
+    if let Some(thresholds) = &config.thresholds {
+        if let Some(cpu) = &thresholds.cpu {
+            painter
+                .colours
+                .set_cpu_threshold_colours(cpu)
+                .context("Update 'cpu' in the thresholds section of your config file.")?;
+        }
+
+        if let Some(mem) = &thresholds.mem {
+            painter
+                .colours
+                .set_mem_threshold_colours(mem)
+                .context("Update 'mem' in the thresholds section of your config file.")?;
+        }
+    }
+
+    if let Some(state_colors) = &config.state_colors {
+        painter
+            .colours
+            .set_process_state_colours(state_colors)
+            .context("Update the state_colors section of your config file.")?;
+    }
+
+    Ok(())
+}
+
+/// Rings the terminal bell.
+pub fn ring_terminal_bell() -> error::Result<()> {
+    execute!(stdout(), Print("\x07"))?;
     Ok(())
 }
 
@@ -381,14 +605,50 @@ pub fn cleanup_terminal(
     Ok(())
 }
 
+/// Suspends the UI to show the requested text in the user's pager, and restores the UI once
+/// the pager exits.  Input is paused meanwhile so that keypresses go to the pager.
+pub fn show_pager_text(
+    terminal: &mut tui::terminal::Terminal<tui::backend::CrosstermBackend<std::io::Stdout>>,
+    app: &mut App, is_input_paused: &AtomicBool,
+) -> error::Result<()> {
+    if let Some(pager_text) = app.pager_text.take() {
+        is_input_paused.store(true, Ordering::SeqCst);
+        // Give the input thread time to finish its current poll before the pager reads keys.
+        thread::sleep(Duration::from_millis(INPUT_POLL_MILLISECONDS * 2));
+        cleanup_terminal(terminal)?;
+
+        let pager_result = utils::pager::show_in_pager(&pager_text);
+
+        execute!(
+            terminal.backend_mut(),
+            EnterAlternateScreen,
+            EnableMouseCapture
+        )?;
+        enable_raw_mode()?;
+        terminal.hide_cursor()?;
+        // The pager drew over everything, so the next draw must redraw the whole screen.
+        terminal.clear()?;
+        is_input_paused.store(false, Ordering::SeqCst);
+
+        if let Err(err) = pager_result {
+            app.show_message(format!("Unable to show the processes in a pager: {}", err));
+        }
+    }
+
+    Ok(())
+}
+
 pub fn termination_hook() {
     let mut stdout = stdout();
     disable_raw_mode().unwrap();
     execute!(stdout, DisableMouseCapture, LeaveAlternateScreen).unwrap();
 }
 
+/// Restores the terminal and prints the panic along with a stack trace, then writes a crash
+/// report if one was set up.
+///
 /// Based on https://github.com/Rigellute/spotify-tui/blob/master/src/main.rs
-pub fn panic_hook(panic_info: &PanicInfo<'_>) {
+pub fn panic_hook(panic_info: &PanicInfo<'_>, crash_report: Option<&CrashReport>) {
     let mut stdout = stdout();
 
     let msg = match panic_info.payload().downcast_ref::<&'static str>() {
@@ -415,36 +675,101 @@ pub fn panic_hook(panic_info: &PanicInfo<'_>) {
         )),
     )
     .unwrap();
+
+    if let Some(crash_report) = crash_report {
+        let _ = match crash_report.write(msg, panic_info.location(), &stacktrace) {
+            Ok(_) => writeln!(
+                stdout,
+                "\n\rA crash report was written to {}.\r",
+                crash_report.path().display()
+            ),
+            Err(err) => writeln!(stdout, "\n\rUnable to write a crash report: {}\r", err),
+        };
+    }
 }
 
 pub fn handle_force_redraws(app: &mut App) {
     // Currently we use an Option... because we might want to future-proof this
     // if we eventually get widget-specific redrawing!
+    // A forced update comes from a change to the search, sort, or columns, which may change
+    // how the rows look even if the data doesn't, so the rows are always rebuilt.
     if app.proc_state.force_update_all {
         update_all_process_lists(app);
+        for proc_widget_state in app.proc_state.widget_states.values_mut() {
+            proc_widget_state.is_process_data_dirty = true;
+        }
         app.proc_state.force_update_all = false;
     } else if let Some(widget_id) = app.proc_state.force_update {
         update_final_process_list(app, widget_id);
+        if let Some(proc_widget_state) = app.proc_state.get_mut_widget_state(widget_id) {
+            proc_widget_state.is_process_data_dirty = true;
+        }
         app.proc_state.force_update = None;
     }
 
     if app.cpu_state.force_update.is_some() {
-        app.canvas_data.cpu_data = convert_cpu_data_points(&app.data_collection, app.is_frozen);
+        app.canvas_data.cpu_data = convert_cpu_data_points(
+            &app.data_collection,
+            app.is_frozen,
+            app.app_config_fields.percent_precision,
+        );
         app.cpu_state.force_update = None;
     }
 
     if app.mem_state.force_update.is_some() {
-        app.canvas_data.mem_data = convert_mem_data_points(&app.data_collection, app.is_frozen);
+        let (mem_data, mem_breakdown_data) =
+            convert_mem_data_points(&app.data_collection, app.is_frozen);
+        app.canvas_data.mem_data = mem_data;
+        app.canvas_data.mem_breakdown_data = mem_breakdown_data;
         app.canvas_data.swap_data = convert_swap_data_points(&app.data_collection, app.is_frozen);
         app.mem_state.force_update = None;
     }
 
     if app.net_state.force_update.is_some() {
-        let (rx, tx) = get_rx_tx_data_points(&app.data_collection, app.is_frozen);
-        app.canvas_data.network_data_rx = rx;
-        app.canvas_data.network_data_tx = tx;
+        let network_data = convert_network_data_points(
+            &app.data_collection,
+            app.is_frozen,
+            app.app_config_fields.use_basic_mode || app.app_config_fields.use_old_network_legend,
+            app.app_config_fields.rate_precision,
+            app.net_state.is_showing_session_total,
+            &app.app_config_fields.byte_format,
+        );
+        app.canvas_data.network_data_rx = network_data.rx;
+        app.canvas_data.network_data_tx = network_data.tx;
+        app.canvas_data.rx_display = network_data.rx_display;
+        app.canvas_data.tx_display = network_data.tx_display;
+        if let Some(total_rx_display) = network_data.total_rx_display {
+            app.canvas_data.total_rx_display = total_rx_display;
+        }
+        if let Some(total_tx_display) = network_data.total_tx_display {
+            app.canvas_data.total_tx_display = total_tx_display;
+        }
         app.net_state.force_update = None;
     }
+
+    if app.disk_state.force_update.is_some() {
+        let (disk_data, disk_used_percent) = convert_disk_row(
+            &app.data_collection,
+            &app.filters.disk_filter,
+            app.get_hidden_disks(),
+            app.app_config_fields.disk_columns,
+            app.disk_state.is_sorted_by_file_system,
+            &app.app_config_fields.byte_format,
+        );
+        app.canvas_data.disk_data = disk_data;
+        app.canvas_data.disk_used_percent = disk_used_percent;
+
+        // Fewer disks may be shown now, so keep the selection within the table.
+        let disk_count = app.canvas_data.disk_data.len();
+        for disk_widget_state in app.disk_state.widget_states.values_mut() {
+            let scroll_state = &mut disk_widget_state.scroll_state;
+            scroll_state.current_scroll_position = std::cmp::min(
+                scroll_state.current_scroll_position,
+                disk_count.saturating_sub(1),
+            );
+        }
+        app.disk_state.force_update = None;
+    }
 }
 
 #[allow(clippy::needless_collect)]
@@ -475,28 +800,104 @@ fn update_final_process_list(app: &mut App, widget_id: u64) {
             process_state.is_using_command,
             process_state.is_grouped,
             process_state.is_tree_mode,
+            process_state.process_search_state.is_inverted,
+            process_state.is_showing_only_own,
         )),
         None => None,
     };
 
-    if let Some((is_invalid_or_blank, is_using_command, is_grouped, is_tree)) = process_states {
+    if let Some((
+        is_invalid_or_blank,
+        is_using_command,
+        is_grouped,
+        is_tree,
+        is_inverted,
+        is_showing_only_own,
+    )) = process_states
+    {
+        let pinned_names = app.proc_state.pinned_names.clone();
+        let show_pinned_not_running = app.proc_state.show_pinned_not_running;
+        let rate_precision = app.app_config_fields.rate_precision;
+        let byte_format = app.app_config_fields.byte_format;
+        let secondary_sort = app.app_config_fields.secondary_sort.clone();
+
         if !app.is_frozen {
-            app.canvas_data.single_process_data = convert_process_data(&app.data_collection);
+            app.canvas_data.single_process_data = convert_process_data(
+                &app.data_collection,
+                rate_precision,
+                app.app_config_fields.per_core_cpu,
+                &byte_format,
+            );
+
+            if let Some(baseline) = &app.canvas_data.process_diff_baseline {
+                let single_process_data = &app.canvas_data.single_process_data;
+                let exit_times = &mut app.canvas_data.process_exit_times;
+                for process in baseline {
+                    if !single_process_data
+                        .iter()
+                        .any(|current| current.pid == process.pid)
+                    {
+                        exit_times.entry(process.pid).or_insert_with(Instant::now);
+                    }
+                }
+            }
+        }
+
+        if !app.environment_cache.is_empty() {
+            let running_processes = app
+                .canvas_data
+                .single_process_data
+                .iter()
+                .map(|process| (process.pid, process.start_time))
+                .collect::<HashSet<_>>();
+            app.environment_cache
+                .retain_running(|pid, start_time| running_processes.contains(&(pid, start_time)));
         }
 
+        // Environments are read locally, which says nothing about processes from a recording
+        // or a remote host.
+        let mut environment_cache = std::mem::take(&mut app.environment_cache);
+        let can_read_environment = app.get_foreign_data_source().is_none();
         let process_filter = app.get_process_filter(widget_id);
+        // Processes whose owner can't be read are kept, as are all processes if there is no
+        // current user to compare with.
+        let own_uid = if is_showing_only_own {
+            data_harvester::processes::get_current_uid()
+        } else {
+            None
+        };
+        let mut matches_filter = |process: &ConvertedProcessData| -> bool {
+            if let (Some(own_uid), Some(uid)) = (own_uid, process.uid) {
+                if uid != own_uid {
+                    return false;
+                }
+            }
+
+            if !is_invalid_or_blank {
+                if let Some(process_filter) = process_filter {
+                    process_filter.check(
+                        process,
+                        is_using_command,
+                        if can_read_environment {
+                            Some(&mut environment_cache)
+                        } else {
+                            None
+                        },
+                    ) != is_inverted
+                } else {
+                    true
+                }
+            } else {
+                true
+            }
+        };
         let filtered_process_data: Vec<ConvertedProcessData> = if is_tree {
             app.canvas_data
                 .single_process_data
                 .iter()
                 .map(|process| {
                     let mut process_clone = process.clone();
-                    if !is_invalid_or_blank {
-                        if let Some(process_filter) = process_filter {
-                            process_clone.is_disabled_entry =
-                                !process_filter.check(&process_clone, is_using_command);
-                        }
-                    }
+                    process_clone.is_disabled_entry = !matches_filter(&process_clone);
                     process_clone
                 })
                 .collect::<Vec<_>>()
@@ -504,38 +905,156 @@ fn update_final_process_list(app: &mut App, widget_id: u64) {
             app.canvas_data
                 .single_process_data
                 .iter()
-                .filter(|process| {
-                    if !is_invalid_or_blank {
-                        if let Some(process_filter) = process_filter {
-                            process_filter.check(&process, is_using_command)
-                        } else {
-                            true
-                        }
-                    } else {
-                        true
-                    }
-                })
+                .filter(|process| matches_filter(process))
                 .cloned()
                 .collect::<Vec<_>>()
         };
 
+        // In tree mode, processes that don't match are kept as disabled entries.
+        let matched_process_data = if is_invalid_or_blank && own_uid.is_none() {
+            None
+        } else {
+            Some(
+                filtered_process_data
+                    .iter()
+                    .filter(|process| !process.is_disabled_entry)
+                    .collect::<Vec<_>>(),
+            )
+        };
+        let process_summary = convert_process_summary(
+            &app.canvas_data.single_process_data,
+            matched_process_data.as_deref(),
+            is_grouped,
+            is_using_command,
+        );
+        let process_summary = match (
+            app.app_config_fields.process_state_counts,
+            &matched_process_data,
+        ) {
+            (Some(ProcessStateCountScope::Matched), Some(matched_process_data)) => format!(
+                "{} ─ {}",
+                process_summary,
+                convert_process_state_counts(matched_process_data.iter().copied())
+            ),
+            (Some(_), _) => format!(
+                "{} ─ {}",
+                process_summary,
+                convert_process_state_counts(app.canvas_data.single_process_data.iter())
+            ),
+            (None, _) => process_summary,
+        };
+        app.canvas_data
+            .process_summary_map
+            .insert(widget_id, process_summary);
+
+        // Exited processes are not shown in tree mode, as they no longer have a place in the tree.
+        let exited_process_data = match &app.canvas_data.process_diff_baseline {
+            Some(baseline) if !is_tree => get_exited_process_data(
+                baseline,
+                &app.canvas_data.single_process_data,
+                &app.canvas_data.process_exit_times,
+                &mut matches_filter,
+                is_grouped,
+                is_using_command,
+                rate_precision,
+                &byte_format,
+            ),
+            _ => Vec::new(),
+        };
+        app.environment_cache = environment_cache;
+
         if let Some(proc_widget_state) = app.proc_state.get_mut_widget_state(widget_id) {
+            proc_widget_state.spawn_tracker.update(
+                app.data_collection.current_instant,
+                app.canvas_data
+                    .single_process_data
+                    .iter()
+                    .map(|process| process.pid)
+                    .collect(),
+            );
+
             let mut finalized_process_data = if is_tree {
                 tree_process_data(
                     &filtered_process_data,
                     is_using_command,
+                    &app.app_config_fields.command_display,
                     &proc_widget_state.process_sorting_type,
                     proc_widget_state.is_process_sort_descending,
                 )
             } else if is_grouped {
-                group_process_data(&filtered_process_data, is_using_command)
+                group_process_data(
+                    &filtered_process_data,
+                    is_using_command,
+                    rate_precision,
+                    &byte_format,
+                )
             } else {
                 filtered_process_data
             };
 
             // Note tree mode is sorted well before this, as it's special.
             if !is_tree {
-                sort_process_data(&mut finalized_process_data, proc_widget_state);
+                sort_process_data(
+                    &mut finalized_process_data,
+                    &proc_widget_state.process_sorting_type,
+                    proc_widget_state.is_process_sort_descending,
+                    proc_widget_state.is_grouped,
+                    &secondary_sort,
+                );
+            }
+
+            // Pinned processes ignore the current filter and sort, so they're added back here.
+            if !is_tree && !pinned_names.is_empty() {
+                finalized_process_data = pin_process_data(
+                    finalized_process_data,
+                    &app.canvas_data.single_process_data,
+                    &pinned_names,
+                    show_pinned_not_running,
+                    is_grouped,
+                    is_using_command,
+                    rate_precision,
+                    &byte_format,
+                );
+            }
+
+            if let Some(baseline) = &app.canvas_data.process_diff_baseline {
+                if is_grouped {
+                    diff_process_data(
+                        &mut finalized_process_data,
+                        &group_process_data(
+                            baseline,
+                            is_using_command,
+                            rate_precision,
+                            &byte_format,
+                        ),
+                        exited_process_data,
+                        is_grouped,
+                    );
+                } else {
+                    diff_process_data(
+                        &mut finalized_process_data,
+                        baseline,
+                        exited_process_data,
+                        is_grouped,
+                    );
+                }
+            }
+
+            // After the sort changes, follow the process that was selected to its new row.
+            if let Some(pid) = proc_widget_state.pid_to_reselect.take() {
+                if let Some(position) = finalized_process_data
+                    .iter()
+                    .position(|process| process.pid == pid)
+                {
+                    let scroll_state = &mut proc_widget_state.scroll_state;
+                    scroll_state.scroll_direction =
+                        if position >= scroll_state.current_scroll_position {
+                            app::ScrollDirection::Down
+                        } else {
+                            app::ScrollDirection::Up
+                        };
+                    scroll_state.current_scroll_position = position;
+                }
             }
 
             if proc_widget_state.scroll_state.current_scroll_position
@@ -547,6 +1066,12 @@ fn update_final_process_list(app: &mut App, widget_id: u64) {
                 proc_widget_state.scroll_state.scroll_direction = app::ScrollDirection::Down;
             }
 
+            let fingerprint = get_process_data_fingerprint(&finalized_process_data);
+            if fingerprint != proc_widget_state.process_data_fingerprint {
+                proc_widget_state.process_data_fingerprint = fingerprint;
+                proc_widget_state.is_process_data_dirty = true;
+            }
+
             app.canvas_data
                 .finalized_process_data_map
                 .insert(widget_id, finalized_process_data);
@@ -554,123 +1079,280 @@ fn update_final_process_list(app: &mut App, widget_id: u64) {
     }
 }
 
-fn sort_process_data(
-    to_sort_vec: &mut Vec<ConvertedProcessData>, proc_widget_state: &app::ProcWidgetState,
+/// Moves any processes matching a pinned name to the top of the list, in the order they were
+/// pinned.  If `show_not_running` is set, pinned names with no running process get a disabled
+/// placeholder entry.
+fn pin_process_data(
+    process_data: Vec<ConvertedProcessData>, all_process_data: &[ConvertedProcessData],
+    pinned_names: &[String], show_not_running: bool, is_grouped: bool, is_using_command: bool,
+    rate_precision: Option<usize>, byte_format: &ByteFormat,
+) -> Vec<ConvertedProcessData> {
+    let running_pinned_data = all_process_data
+        .iter()
+        .filter(|process| pinned_names.contains(&process.name))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let mut pinned_data = if is_grouped {
+        group_process_data(
+            &running_pinned_data,
+            is_using_command,
+            rate_precision,
+            byte_format,
+        )
+    } else {
+        running_pinned_data
+    };
+    pinned_data.sort_by_key(|process| {
+        pinned_names
+            .iter()
+            .position(|name| *name == process.name)
+            .unwrap_or_else(|| pinned_names.len())
+    });
+
+    if show_not_running {
+        for name in pinned_names {
+            if !all_process_data.iter().any(|process| process.name == *name) {
+                pinned_data.push(ConvertedProcessData {
+                    name: name.clone(),
+                    command: name.clone(),
+                    process_state: "Not running".to_string(),
+                    is_disabled_entry: true,
+                    ..ConvertedProcessData::default()
+                });
+            }
+        }
+    }
+
+    let unpinned_data = process_data
+        .into_iter()
+        .filter(|process| {
+            !pinned_data.iter().any(|pinned| {
+                if is_grouped {
+                    pinned.name == process.name
+                } else {
+                    pinned.pid == process.pid
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    pinned_data
+        .iter_mut()
+        .for_each(|process| process.is_pinned = true);
+    pinned_data.extend(unpinned_data);
+
+    pinned_data
+}
+
+/// Returns the processes in the baseline that have exited recently enough to still be shown,
+/// marked as exited.  While grouped, a group has only exited once none of its processes run.
+fn get_exited_process_data(
+    baseline: &[ConvertedProcessData], all_process_data: &[ConvertedProcessData],
+    exit_times: &HashMap<Pid, Instant>,
+    matches_filter: &mut dyn FnMut(&ConvertedProcessData) -> bool, is_grouped: bool,
+    is_using_command: bool, rate_precision: Option<usize>, byte_format: &ByteFormat,
+) -> Vec<ConvertedProcessData> {
+    let exited_process_data = baseline
+        .iter()
+        .filter(|process| exit_times.contains_key(&process.pid) && matches_filter(process))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let exited_process_data = if is_grouped {
+        group_process_data(
+            &exited_process_data,
+            is_using_command,
+            rate_precision,
+            byte_format,
+        )
+        .into_iter()
+        .filter(|group| {
+            !all_process_data.iter().any(|process| {
+                if is_using_command {
+                    process.command == group.name
+                } else {
+                    process.name == group.name
+                }
+            })
+        })
+        .collect::<Vec<_>>()
+    } else {
+        exited_process_data
+    };
+
+    exited_process_data
+        .into_iter()
+        .filter(|process| {
+            process
+                .group_pids
+                .iter()
+                .filter_map(|pid| exit_times.get(pid))
+                .max()
+                .map(|exit_time| {
+                    Instant::now().duration_since(*exit_time).as_millis()
+                        < EXITED_PROCESS_DISPLAY_MILLISECONDS as u128
+                })
+                .unwrap_or(false)
+        })
+        .map(|mut process| {
+            let diff = ProcessDiff::new(ProcessDiffStatus::Exited, None, Some(&process));
+            process.diff = Some(diff);
+            process.process_state = "Exited".to_string();
+            process
+        })
+        .collect()
+}
+
+/// Marks how each process changed since the process baseline was marked, then appends the
+/// exited processes.  Processes are matched by PID, or by name if grouped.
+fn diff_process_data(
+    process_data: &mut Vec<ConvertedProcessData>, baseline: &[ConvertedProcessData],
+    exited_process_data: Vec<ConvertedProcessData>, is_grouped: bool,
+) {
+    process_data
+        .iter_mut()
+        .filter(|process| !process.is_disabled_entry)
+        .for_each(|process| {
+            let baseline_process = baseline.iter().find(|baseline_process| {
+                if is_grouped {
+                    baseline_process.name == process.name
+                } else {
+                    baseline_process.pid == process.pid
+                }
+            });
+
+            let diff = ProcessDiff::new(
+                if baseline_process.is_some() {
+                    ProcessDiffStatus::Existing
+                } else {
+                    ProcessDiffStatus::New
+                },
+                Some(&*process),
+                baseline_process,
+            );
+            process.diff = Some(diff);
+        });
+
+    process_data.extend(exited_process_data);
+}
+
+/// Sorts processes by the given column, in a single pass.  Ties are broken by the secondary sort
+/// column if there is one, then by name (ascending and case-insensitive), and then by PID
+/// (ascending), so processes that are equal in every sorted column still keep the same order
+/// between updates.
+pub fn sort_process_data(
+    to_sort_vec: &mut Vec<ConvertedProcessData>, sorting_type: &ProcessSorting,
+    is_descending: bool, is_grouped: bool, secondary_sort: &Option<ProcessSorting>,
 ) {
+    // The secondary column is sorted by the direction that makes the most sense for it, which is
+    // ascending for text and descending for anything measured.
+    let secondary_sort = secondary_sort
+        .as_ref()
+        .filter(|secondary_sort| *secondary_sort != sorting_type)
+        .map(|secondary_sort| {
+            let is_secondary_descending = !matches!(
+                secondary_sort,
+                ProcessSorting::ProcessName
+                    | ProcessSorting::Command
+                    | ProcessSorting::Pid
+                    | ProcessSorting::ParentPid
+                    | ProcessSorting::State
+            );
+            (secondary_sort, is_secondary_descending)
+        });
+
     to_sort_vec.sort_by(|a, b| {
-        utils::gen_util::get_ordering(&a.name.to_lowercase(), &b.name.to_lowercase(), false)
+        compare_process_data(a, b, sorting_type, is_descending, is_grouped)
+            .then_with(|| match secondary_sort {
+                Some((secondary_sort, is_secondary_descending)) => {
+                    compare_process_data(a, b, secondary_sort, is_secondary_descending, is_grouped)
+                }
+                None => std::cmp::Ordering::Equal,
+            })
+            .then_with(|| {
+                utils::gen_util::get_ordering(&a.name.to_lowercase(), &b.name.to_lowercase(), false)
+            })
+            .then_with(|| a.pid.cmp(&b.pid))
     });
+}
 
-    match &proc_widget_state.process_sorting_type {
+/// Compares two processes by a single column.  Columns that don't apply, such as the PID or PPID
+/// of a group or the count of a single process, treat every process as equal.
+fn compare_process_data(
+    a: &ConvertedProcessData, b: &ConvertedProcessData, sorting_type: &ProcessSorting,
+    is_descending: bool, is_grouped: bool,
+) -> std::cmp::Ordering {
+    match sorting_type {
         ProcessSorting::CpuPercent => {
-            to_sort_vec.sort_by(|a, b| {
-                utils::gen_util::get_ordering(
-                    a.cpu_percent_usage,
-                    b.cpu_percent_usage,
-                    proc_widget_state.is_process_sort_descending,
-                )
-            });
+            utils::gen_util::get_ordering(a.cpu_percent_usage, b.cpu_percent_usage, is_descending)
         }
         ProcessSorting::Mem => {
-            to_sort_vec.sort_by(|a, b| {
-                utils::gen_util::get_ordering(
-                    a.mem_usage_bytes,
-                    b.mem_usage_bytes,
-                    proc_widget_state.is_process_sort_descending,
-                )
-            });
+            utils::gen_util::get_ordering(a.mem_usage_bytes, b.mem_usage_bytes, is_descending)
         }
         ProcessSorting::MemPercent => {
-            to_sort_vec.sort_by(|a, b| {
-                utils::gen_util::get_ordering(
-                    a.mem_percent_usage,
-                    b.mem_percent_usage,
-                    proc_widget_state.is_process_sort_descending,
-                )
-            });
+            utils::gen_util::get_ordering(a.mem_percent_usage, b.mem_percent_usage, is_descending)
         }
-        ProcessSorting::ProcessName => {
-            // Don't repeat if false... it sorts by name by default anyways.
-            if proc_widget_state.is_process_sort_descending {
-                to_sort_vec.sort_by(|a, b| {
-                    utils::gen_util::get_ordering(
-                        &a.name.to_lowercase(),
-                        &b.name.to_lowercase(),
-                        proc_widget_state.is_process_sort_descending,
-                    )
-                })
+        ProcessSorting::ProcessName => utils::gen_util::get_ordering(
+            &a.name.to_lowercase(),
+            &b.name.to_lowercase(),
+            is_descending,
+        ),
+        ProcessSorting::Command => utils::gen_util::get_ordering(
+            &a.command.to_lowercase(),
+            &b.command.to_lowercase(),
+            is_descending,
+        ),
+        ProcessSorting::Pid => {
+            if is_grouped {
+                std::cmp::Ordering::Equal
+            } else {
+                utils::gen_util::get_ordering(a.pid, b.pid, is_descending)
             }
         }
-        ProcessSorting::Command => to_sort_vec.sort_by(|a, b| {
-            utils::gen_util::get_ordering(
-                &a.command.to_lowercase(),
-                &b.command.to_lowercase(),
-                proc_widget_state.is_process_sort_descending,
-            )
-        }),
-        ProcessSorting::Pid => {
-            if !proc_widget_state.is_grouped {
-                to_sort_vec.sort_by(|a, b| {
-                    utils::gen_util::get_ordering(
-                        a.pid,
-                        b.pid,
-                        proc_widget_state.is_process_sort_descending,
-                    )
-                });
+        ProcessSorting::ParentPid => {
+            if is_grouped {
+                std::cmp::Ordering::Equal
+            } else {
+                utils::gen_util::get_ordering(a.ppid, b.ppid, is_descending)
             }
         }
         ProcessSorting::ReadPerSecond => {
-            to_sort_vec.sort_by(|a, b| {
-                utils::gen_util::get_ordering(
-                    a.rps_f64,
-                    b.rps_f64,
-                    proc_widget_state.is_process_sort_descending,
-                )
-            });
+            utils::gen_util::get_ordering(a.rps_f64, b.rps_f64, is_descending)
         }
         ProcessSorting::WritePerSecond => {
-            to_sort_vec.sort_by(|a, b| {
-                utils::gen_util::get_ordering(
-                    a.wps_f64,
-                    b.wps_f64,
-                    proc_widget_state.is_process_sort_descending,
-                )
-            });
+            utils::gen_util::get_ordering(a.wps_f64, b.wps_f64, is_descending)
         }
         ProcessSorting::TotalRead => {
-            to_sort_vec.sort_by(|a, b| {
-                utils::gen_util::get_ordering(
-                    a.tr_f64,
-                    b.tr_f64,
-                    proc_widget_state.is_process_sort_descending,
-                )
-            });
+            utils::gen_util::get_ordering(a.tr_f64, b.tr_f64, is_descending)
         }
         ProcessSorting::TotalWrite => {
-            to_sort_vec.sort_by(|a, b| {
-                utils::gen_util::get_ordering(
-                    a.tw_f64,
-                    b.tw_f64,
-                    proc_widget_state.is_process_sort_descending,
-                )
-            });
+            utils::gen_util::get_ordering(a.tw_f64, b.tw_f64, is_descending)
         }
-        ProcessSorting::State => to_sort_vec.sort_by(|a, b| {
-            utils::gen_util::get_ordering(
-                &a.process_state.to_lowercase(),
-                &b.process_state.to_lowercase(),
-                proc_widget_state.is_process_sort_descending,
-            )
-        }),
+        ProcessSorting::State => utils::gen_util::get_ordering(
+            &a.process_state.to_lowercase(),
+            &b.process_state.to_lowercase(),
+            is_descending,
+        ),
+        ProcessSorting::Threads => {
+            utils::gen_util::get_ordering(a.thread_count, b.thread_count, is_descending)
+        }
+        ProcessSorting::OomScore => {
+            utils::gen_util::get_ordering(a.oom_score, b.oom_score, is_descending)
+        }
+        ProcessSorting::Swap => {
+            utils::gen_util::get_ordering(a.swap_bytes, b.swap_bytes, is_descending)
+        }
+        ProcessSorting::CpuAffinity => utils::gen_util::get_ordering(
+            a.cpu_affinity.as_ref().map(Vec::len),
+            b.cpu_affinity.as_ref().map(Vec::len),
+            is_descending,
+        ),
         ProcessSorting::Count => {
-            if proc_widget_state.is_grouped {
-                to_sort_vec.sort_by(|a, b| {
-                    utils::gen_util::get_ordering(
-                        a.group_pids.len(),
-                        b.group_pids.len(),
-                        proc_widget_state.is_process_sort_descending,
-                    )
-                });
+            if is_grouped {
+                utils::gen_util::get_ordering(a.group_pids.len(), b.group_pids.len(), is_descending)
+            } else {
+                std::cmp::Ordering::Equal
             }
         }
     }
@@ -680,13 +1362,20 @@ pub fn create_input_thread(
     sender: std::sync::mpsc::Sender<
         BottomEvent<crossterm::event::KeyEvent, crossterm::event::MouseEvent>,
     >,
+    is_input_paused: Arc<AtomicBool>,
 ) {
     thread::spawn(move || {
         let mut mouse_timer = Instant::now();
         let mut keyboard_timer = Instant::now();
 
         loop {
-            if poll(Duration::from_millis(20)).is_ok() {
+            // Leave input alone while another program, such as a pager, is using the terminal.
+            if is_input_paused.load(Ordering::SeqCst) {
+                thread::sleep(Duration::from_millis(INPUT_POLL_MILLISECONDS));
+                continue;
+            }
+
+            if poll(Duration::from_millis(INPUT_POLL_MILLISECONDS)).is_ok() {
                 if let Ok(event) = read() {
                     if let Event::Key(key) = event {
                         if Instant::now().duration_since(keyboard_timer).as_millis() >= 20 {
@@ -709,41 +1398,341 @@ pub fn create_input_thread(
     });
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_event_thread(
     sender: std::sync::mpsc::Sender<
         BottomEvent<crossterm::event::KeyEvent, crossterm::event::MouseEvent>,
     >,
-    reset_receiver: std::sync::mpsc::Receiver<ResetEvent>,
+    thread_control_receiver: std::sync::mpsc::Receiver<ThreadControlEvent>,
     app_config_fields: &app::AppConfigFields, used_widget_set: UsedWidgets,
+    recorder: Option<recording::Recorder>, replay_frames: Option<Vec<recording::RecordedFrame>>,
+    remote_host: Option<String>, process_fields: data_harvester::processes::ProcessFields,
 ) {
+    let collector_args = get_collector_args(app_config_fields);
     let temp_type = app_config_fields.temperature_type.clone();
     let use_current_cpu_total = app_config_fields.use_current_cpu_total;
     let show_average_cpu = app_config_fields.show_average_cpu;
-    let update_rate_in_milliseconds = app_config_fields.update_rate_in_milliseconds;
+    let disable_process_io = app_config_fields.disable_process_io;
+    let show_scheduler_activity = app_config_fields.show_scheduler_activity;
+    let mut update_rate_in_milliseconds = app_config_fields.update_rate_in_milliseconds;
 
     thread::spawn(move || {
+        if let Some(replay_frames) = replay_frames {
+            replay_recording(
+                &sender,
+                &thread_control_receiver,
+                &replay_frames,
+                update_rate_in_milliseconds,
+            );
+            return;
+        }
+
+        let mut recorder = recorder;
+        if let Some(remote_host) = remote_host {
+            stream_remote(&sender, &remote_host, &collector_args, &mut recorder);
+            return;
+        }
+
         let mut data_state = data_harvester::DataCollector::default();
         data_state.set_collected_data(used_widget_set);
         data_state.set_temperature_type(temp_type);
         data_state.set_use_current_cpu_total(use_current_cpu_total);
         data_state.set_show_average_cpu(show_average_cpu);
+        data_state.set_disable_process_io(disable_process_io);
+        data_state.set_show_scheduler_activity(show_scheduler_activity);
+        data_state.set_process_fields(process_fields);
 
         data_state.init();
+        let mut next_update_time = Instant::now();
         loop {
-            if let Ok(message) = reset_receiver.try_recv() {
-                match message {
-                    ResetEvent::Reset => {
+            // Wait for the next regular harvest, unless a refresh is asked for before then.
+            let mut is_refreshing_now = false;
+            while let Some(wait_duration) = next_update_time.checked_duration_since(Instant::now())
+            {
+                match thread_control_receiver.recv_timeout(wait_duration) {
+                    Ok(ThreadControlEvent::Reset) => {
                         data_state.data.first_run_cleanup();
                     }
+                    Ok(ThreadControlEvent::UpdateUpdateRate(new_update_rate_in_milliseconds)) => {
+                        update_rate_in_milliseconds = new_update_rate_in_milliseconds;
+                    }
+                    Ok(ThreadControlEvent::RefreshNow) => {
+                        is_refreshing_now = true;
+                        break;
+                    }
+                    Ok(ThreadControlEvent::UpdateProcessFields(process_fields)) => {
+                        // Harvest right away, so newly needed fields, such as the owners of
+                        // processes after pressing `u`, are there without a delay.
+                        data_state.set_process_fields(process_fields);
+                        is_refreshing_now = true;
+                        break;
+                    }
+                    Ok(ThreadControlEvent::UpdateCpuBreakdown(show_cpu_breakdown)) => {
+                        data_state.set_show_cpu_breakdown(show_cpu_breakdown);
+                    }
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => {
+                        thread::sleep(wait_duration);
+                        break;
+                    }
                 }
             }
             futures::executor::block_on(data_state.update_data());
+
+            let has_recording_failed = match &mut recorder {
+                Some(recorder) => recorder.record(&data_state.data).is_err(),
+                None => false,
+            };
+            if has_recording_failed {
+                // Stop recording rather than repeatedly failing, such as if the disk is full.
+                recorder = None;
+            }
+
             let event = BottomEvent::Update(Box::from(data_state.data));
             data_state.data = data_harvester::Data::default();
             if sender.send(event).is_err() {
                 break;
             }
-            thread::sleep(Duration::from_millis(update_rate_in_milliseconds));
+
+            // Refreshes are extra harvests, so the regular ones keep their usual times.
+            if !is_refreshing_now {
+                next_update_time =
+                    Instant::now() + Duration::from_millis(update_rate_in_milliseconds);
+            }
         }
     });
 }
+
+/// Returns the arguments for `btm --collect` on a remote host, so it harvests the same way as
+/// bottom would locally.
+fn get_collector_args(app_config_fields: &app::AppConfigFields) -> Vec<String> {
+    let mut collector_args = vec![
+        "--collect".to_string(),
+        "--rate".to_string(),
+        app_config_fields.update_rate_in_milliseconds.to_string(),
+        match app_config_fields.temperature_type {
+            data_harvester::temperature::TemperatureType::Celsius => "--celsius",
+            data_harvester::temperature::TemperatureType::Kelvin => "--kelvin",
+            data_harvester::temperature::TemperatureType::Fahrenheit => "--fahrenheit",
+            data_harvester::temperature::TemperatureType::Raw => "--raw_temperature",
+        }
+        .to_string(),
+    ];
+    if app_config_fields.use_current_cpu_total {
+        collector_args.push("--current_usage".to_string());
+    }
+    if !app_config_fields.show_average_cpu {
+        collector_args.push("--hide_avg_cpu".to_string());
+    }
+    if app_config_fields.disable_process_io {
+        collector_args.push("--disable_process_io".to_string());
+    }
+    if app_config_fields.disable_cpu_breakdown {
+        collector_args.push("--disable_cpu_breakdown".to_string());
+    }
+    if app_config_fields.show_scheduler_activity {
+        collector_args.push("--show_scheduler_activity".to_string());
+    }
+
+    collector_args
+}
+
+/// Runs `btm --collect` on the remote host over SSH and sends each frame it prints, as if it
+/// had been harvested locally.  If the connection drops, a [`BottomEvent::RemoteDisconnected`]
+/// is sent and the connection is retried after a while.
+fn stream_remote(
+    sender: &std::sync::mpsc::Sender<
+        BottomEvent<crossterm::event::KeyEvent, crossterm::event::MouseEvent>,
+    >,
+    remote_host: &str, collector_args: &[String], recorder: &mut Option<recording::Recorder>,
+) {
+    loop {
+        // Batch mode stops SSH from prompting for a password, which would garble the UI, and
+        // the keepalives notice a dropped connection rather than waiting on it forever.
+        let child = Command::new("ssh")
+            .args(&[
+                "-T",
+                "-o",
+                "BatchMode=yes",
+                "-o",
+                "ServerAliveInterval=5",
+                "-o",
+                "ServerAliveCountMax=2",
+                // Stops a host starting with "-" from being read as another option.
+                "--",
+                remote_host,
+                "btm",
+            ])
+            .args(collector_args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn();
+
+        if let Ok(mut child) = child {
+            if let Some(child_stdout) = child.stdout.take() {
+                for line in BufReader::new(child_stdout).lines() {
+                    let line = match line {
+                        Ok(line) => line,
+                        Err(_) => break,
+                    };
+                    let mut data = match recording::parse_frame(&line) {
+                        Ok(frame) => frame.data,
+                        Err(_) => continue,
+                    };
+
+                    // The harvest time isn't sent, so treat the frame as being harvested now.
+                    data.last_collection_time = Instant::now();
+                    let has_recording_failed = match recorder {
+                        Some(recorder) => recorder.record(&data).is_err(),
+                        None => false,
+                    };
+                    if has_recording_failed {
+                        *recorder = None;
+                    }
+
+                    if sender.send(BottomEvent::Update(Box::from(data))).is_err() {
+                        let _ = child.kill();
+                        let _ = child.wait();
+                        return;
+                    }
+                }
+            }
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+
+        if sender.send(BottomEvent::RemoteDisconnected).is_err() {
+            return;
+        }
+        thread::sleep(Duration::from_millis(REMOTE_RECONNECT_MILLISECONDS));
+    }
+}
+
+/// Sends recorded frames at the same cadence they were recorded at, looping back to the
+/// start once the end of the recording is reached.  Refreshing steps to the next frame right
+/// away, and resetting starts the replay over.
+fn replay_recording(
+    sender: &std::sync::mpsc::Sender<
+        BottomEvent<crossterm::event::KeyEvent, crossterm::event::MouseEvent>,
+    >,
+    thread_control_receiver: &std::sync::mpsc::Receiver<ThreadControlEvent>,
+    replay_frames: &[recording::RecordedFrame], mut update_rate_in_milliseconds: u64,
+) {
+    // Frames keep their recorded spacing until the update rate is changed, and are then spaced
+    // by the new rate instead.
+    let mut frame_interval_ms: Option<u64> = None;
+    // The first frame is sent right away, and after that, the replay pauses for a regular update
+    // before starting over.
+    let mut first_frame_delay_ms = 0;
+    let mut prev_frame_time = Instant::now();
+
+    'replay: loop {
+        let mut prev_elapsed_ms = replay_frames
+            .first()
+            .map(|frame| frame.elapsed_ms)
+            .unwrap_or(0);
+
+        for (itx, frame) in replay_frames.iter().enumerate() {
+            let recorded_delay_ms = frame.elapsed_ms.saturating_sub(prev_elapsed_ms);
+            prev_elapsed_ms = frame.elapsed_ms;
+
+            // Like the harvester, wait for the frame while handling requests from the UI.
+            loop {
+                let delay_ms = if itx == 0 {
+                    first_frame_delay_ms
+                } else {
+                    frame_interval_ms.unwrap_or(recorded_delay_ms)
+                };
+                let wait_duration = match (prev_frame_time + Duration::from_millis(delay_ms))
+                    .checked_duration_since(Instant::now())
+                {
+                    Some(wait_duration) => wait_duration,
+                    None => break,
+                };
+
+                match thread_control_receiver.recv_timeout(wait_duration) {
+                    Ok(ThreadControlEvent::Reset) => {
+                        // Start the replay over from the first frame.
+                        first_frame_delay_ms = 0;
+                        continue 'replay;
+                    }
+                    Ok(ThreadControlEvent::UpdateUpdateRate(new_update_rate_in_milliseconds)) => {
+                        update_rate_in_milliseconds = new_update_rate_in_milliseconds;
+                        frame_interval_ms = Some(new_update_rate_in_milliseconds);
+                    }
+                    Ok(ThreadControlEvent::RefreshNow) => {
+                        // Step forward to this frame right away.
+                        break;
+                    }
+                    // The recording already has whatever was harvested.
+                    Ok(ThreadControlEvent::UpdateProcessFields(_))
+                    | Ok(ThreadControlEvent::UpdateCpuBreakdown(_)) => {}
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => {
+                        thread::sleep(wait_duration);
+                        break;
+                    }
+                }
+            }
+
+            // The harvest time isn't recorded, so treat the frame as being harvested now.
+            prev_frame_time = Instant::now();
+            let mut data = frame.data.clone();
+            data.last_collection_time = prev_frame_time;
+            if sender.send(BottomEvent::Update(Box::from(data))).is_err() {
+                return;
+            }
+        }
+
+        first_frame_delay_ms = update_rate_in_milliseconds;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collector_args_forward_harvest_options() {
+        let matches = clap::build_app().get_matches_from(vec![
+            "btm",
+            "--rate",
+            "2000",
+            "--fahrenheit",
+            "--current_usage",
+            "--hide_avg_cpu",
+            "--show_scheduler_activity",
+        ]);
+
+        // Lite mode turns off process I/O and the CPU breakdown, which must reach the collector.
+        let mut config = Config::default();
+        apply_lite_defaults(&mut config);
+
+        let (widget_layout, default_widget_id, default_widget_type_option) =
+            get_widget_layout(&matches, &config).unwrap();
+        let app = build_app(
+            &matches,
+            &config,
+            &widget_layout,
+            default_widget_id,
+            &default_widget_type_option,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_collector_args(&app.app_config_fields),
+            vec![
+                "--collect",
+                "--rate",
+                "2000",
+                "--fahrenheit",
+                "--current_usage",
+                "--hide_avg_cpu",
+                "--disable_process_io",
+                "--disable_cpu_breakdown",
+                "--show_scheduler_activity",
+            ]
+        );
+    }
+}
@@ -4,9 +4,14 @@ use std::collections::{HashMap, HashSet};
 use std::time::Instant;
 
 use crate::{
-    app::{layout_manager::*, *},
+    app::{data_harvester::processes::ProcessSorting, layout_manager::*, rate_ramp::RateRamp, *},
+    canvas::{detect_colour_depth, ColourDepth, GraphMarker, SelectionStyle},
     constants::*,
-    utils::error::{self, BottomError},
+    data_conversion::{CommandDisplay, MemPressureWeights, ProcessStateCountScope},
+    utils::{
+        error::{self, BottomError},
+        gen_util::ByteFormat,
+    },
 };
 
 use layout_options::*;
@@ -22,16 +27,28 @@ pub struct Config {
     pub row: Option<Vec<Row>>,
     pub disk_filter: Option<IgnoreList>,
     pub temp_filter: Option<IgnoreList>,
+    pub processes: Option<ConfigProcesses>,
+    pub mem: Option<ConfigMem>,
+    pub disk: Option<ConfigDisk>,
+    pub thresholds: Option<ConfigThresholds>,
+    pub columns: Option<HashMap<String, ConfigColumn>>,
+    pub rate_ramp: Option<ConfigRateRamp>,
+    /// Process row colours by state code.
+    pub state_colors: Option<HashMap<String, String>>,
 }
 
 #[derive(Default, Deserialize)]
 pub struct ConfigFlags {
     pub hide_avg_cpu: Option<bool>,
     pub dot_marker: Option<bool>,
+    pub graph_marker: Option<String>,
+    pub raw_graphs: Option<bool>,
+    pub color: Option<String>,
     pub temperature_type: Option<String>,
     pub rate: Option<u64>,
     pub left_legend: Option<bool>,
     pub current_usage: Option<bool>,
+    pub per_core_cpu: Option<bool>,
     pub group_processes: Option<bool>,
     pub case_sensitive: Option<bool>,
     pub whole_word: Option<bool>,
@@ -46,8 +63,40 @@ pub struct ConfigFlags {
     pub default_widget_count: Option<u64>,
     pub use_old_network_legend: Option<bool>,
     pub hide_table_gap: Option<bool>,
+    pub hide_temperature_unit: Option<bool>,
     pub battery: Option<bool>,
     pub disable_click: Option<bool>,
+    pub disable_battery: Option<bool>,
+    pub disable_disk: Option<bool>,
+    pub disable_network: Option<bool>,
+    pub disable_temperature: Option<bool>,
+    pub command_display: Option<String>,
+    pub selection_style: Option<String>,
+    pub cpu_usage_gradient: Option<bool>,
+    pub min_terminal_width: Option<u16>,
+    pub min_terminal_height: Option<u16>,
+    pub quit_confirmation: Option<String>,
+    pub quit_from_search: Option<bool>,
+    pub percent_precision: Option<usize>,
+    pub rate_precision: Option<usize>,
+    pub rate_smoothing_alpha: Option<f64>,
+    pub byte_unit_threshold: Option<f64>,
+    pub byte_significant_figures: Option<usize>,
+    pub preset: Option<String>,
+    pub pause_idle_redraws: Option<bool>,
+    pub show_self_usage: Option<bool>,
+    pub scroll_wrap: Option<bool>,
+    pub zebra_stripes: Option<bool>,
+    pub scroll_lines_per_tick: Option<u64>,
+    pub max_data_points: Option<u64>,
+    pub split_network_axes: Option<bool>,
+    pub disable_process_io: Option<bool>,
+    pub disable_cpu_breakdown: Option<bool>,
+    pub show_scheduler_activity: Option<bool>,
+    pub crash_report: Option<bool>,
+    pub time_format: Option<String>,
+    pub label: Option<String>,
+    pub refresh_key: Option<String>,
 }
 
 #[derive(Default, Deserialize)]
@@ -56,8 +105,11 @@ pub struct ConfigColours {
     pub all_cpu_color: Option<String>,
     pub avg_cpu_color: Option<String>,
     pub cpu_core_colors: Option<Vec<String>>,
+    pub cpu_breakdown_colors: Option<Vec<String>>,
     pub ram_color: Option<String>,
     pub swap_color: Option<String>,
+    pub buffers_color: Option<String>,
+    pub cached_color: Option<String>,
     pub rx_color: Option<String>,
     pub tx_color: Option<String>,
     pub rx_total_color: Option<String>,
@@ -70,6 +122,83 @@ pub struct ConfigColours {
     pub widget_title_color: Option<String>,
     pub graph_color: Option<String>,
     pub battery_colors: Option<Vec<String>>,
+    pub pinned_process_color: Option<String>,
+    pub watched_process_bg_color: Option<String>,
+    pub mem_alarm_color: Option<String>,
+    pub disk_alarm_color: Option<String>,
+    pub disabled_entry_color: Option<String>,
+    pub alt_row_color: Option<String>,
+}
+
+#[derive(Default, Deserialize)]
+pub struct ConfigProcesses {
+    pub pinned: Option<Vec<String>>,
+    pub show_pinned_not_running: Option<bool>,
+    pub oom_score_warn_threshold: Option<u64>,
+    pub zombie_warn_threshold: Option<u64>,
+    pub secondary_sort: Option<String>,
+    pub only_own: Option<bool>,
+    pub show_ppid: Option<bool>,
+    pub show_swap: Option<bool>,
+    pub show_threads: Option<bool>,
+    pub show_oom_score: Option<bool>,
+    pub show_affinity: Option<bool>,
+    pub state_counts: Option<String>,
+    pub empty_message: Option<String>,
+}
+
+#[derive(Default, Deserialize)]
+pub struct ConfigMem {
+    pub warn_threshold_percent: Option<f64>,
+    pub warn_bell: Option<bool>,
+    pub pressure: Option<ConfigMemPressure>,
+}
+
+#[derive(Default, Deserialize)]
+pub struct ConfigMemPressure {
+    pub memory_weight: Option<f64>,
+    pub swap_weight: Option<f64>,
+    pub swap_activity_weight: Option<f64>,
+    pub swap_activity_max_pages: Option<f64>,
+}
+
+#[derive(Default, Deserialize)]
+pub struct ConfigDisk {
+    pub warn_threshold_percent: Option<f64>,
+    /// Thresholds for specific mount points, which take priority over the default.
+    pub mount_warn_threshold_percent: Option<HashMap<String, f64>>,
+    pub hide_filesystems: Option<Vec<String>>,
+    /// Globs of mount points to hide, such as `/snap/*`.
+    pub hide_mounts: Option<Vec<String>>,
+    /// Optional columns to show, out of `type` and `options`.
+    pub columns: Option<Vec<String>>,
+}
+
+#[derive(Default, Deserialize)]
+pub struct ConfigColumn {
+    /// Shown in place of the column's usual name.
+    pub header: Option<String>,
+}
+
+/// Slowing down harvesting while the CPU is busy, which is enabled by having this section.
+#[derive(Default, Deserialize)]
+pub struct ConfigRateRamp {
+    pub cpu_threshold_percent: Option<f64>,
+    pub ticks: Option<u64>,
+    pub max_rate: Option<u64>,
+}
+
+#[derive(Default, Deserialize)]
+pub struct ConfigThresholds {
+    pub cpu: Option<Vec<ConfigColourStop>>,
+    pub mem: Option<Vec<ConfigColourStop>>,
+}
+
+/// A colour to use once a value is at or above the given percentage.
+#[derive(Deserialize)]
+pub struct ConfigColourStop {
+    pub percent: f64,
+    pub color: String,
 }
 
 #[derive(Default, Deserialize)]
@@ -95,6 +224,9 @@ pub fn build_app(
     let is_case_sensitive = get_app_case_sensitive(matches, config);
     let is_match_whole_word = get_app_match_whole_word(matches, config);
     let is_use_regex = get_app_use_regex(matches, config);
+    let is_showing_only_own = get_only_own_processes(config);
+    let is_showing_ppid = get_show_ppid(config);
+    let shown_proc_columns = get_shown_proc_columns(config);
 
     let mut widget_map = HashMap::new();
     let mut cpu_state_map: HashMap<u64, CpuWidgetState> = HashMap::new();
@@ -156,22 +288,23 @@ pub fn build_app(
 
                     match widget.widget_type {
                         Cpu => {
-                            cpu_state_map.insert(
-                                widget.widget_id,
-                                CpuWidgetState::init(default_time_value, autohide_timer),
-                            );
+                            let mut cpu_widget_state =
+                                CpuWidgetState::init(default_time_value, autohide_timer);
+                            cpu_widget_state.is_y_inverted = widget.is_y_inverted;
+                            cpu_state_map.insert(widget.widget_id, cpu_widget_state);
                         }
                         Mem => {
-                            mem_state_map.insert(
-                                widget.widget_id,
-                                MemWidgetState::init(default_time_value, autohide_timer),
-                            );
+                            let mut mem_widget_state =
+                                MemWidgetState::init(default_time_value, autohide_timer);
+                            mem_widget_state.is_showing_gauge = widget.mem_style == MemStyle::Gauge;
+                            mem_widget_state.is_y_inverted = widget.is_y_inverted;
+                            mem_state_map.insert(widget.widget_id, mem_widget_state);
                         }
                         Net => {
-                            net_state_map.insert(
-                                widget.widget_id,
-                                NetWidgetState::init(default_time_value, autohide_timer),
-                            );
+                            let mut net_widget_state =
+                                NetWidgetState::init(default_time_value, autohide_timer);
+                            net_widget_state.is_y_inverted = widget.is_y_inverted;
+                            net_state_map.insert(widget.widget_id, net_widget_state);
                         }
                         Proc => {
                             proc_state_map.insert(
@@ -180,7 +313,12 @@ pub fn build_app(
                                     is_case_sensitive,
                                     is_match_whole_word,
                                     is_use_regex,
-                                    is_grouped,
+                                    // The -g flag groups every process widget, regardless of the layout.
+                                    matches.is_present("GROUP_PROCESSES")
+                                        || widget.is_grouped.unwrap_or(is_grouped),
+                                    is_showing_only_own,
+                                    is_showing_ppid,
+                                    &shown_proc_columns,
                                 ),
                             );
                         }
@@ -201,6 +339,31 @@ pub fn build_app(
         }
     }
 
+    // If the default widget isn't in the layout, fall back to the first widget.
+    let mut default_widget_warning = None;
+    if let Some(default_widget_type) = &default_widget_type_option {
+        if !widget_map
+            .values()
+            .any(|widget| is_widget_of_type(&widget.widget_type, default_widget_type))
+        {
+            if let Some(first_widget) = widget_layout
+                .rows
+                .iter()
+                .flat_map(|row| row.children.iter())
+                .flat_map(|col| col.children.iter())
+                .flat_map(|col_row| col_row.children.iter())
+                .find(|widget| !matches!(widget.widget_type, Empty | CpuLegend | BasicTables))
+            {
+                initial_widget_id = first_widget.widget_id;
+                initial_widget_type = first_widget.widget_type.clone();
+            }
+            default_widget_warning = Some(format!(
+                "There is no {} widget in the current layout, so the first widget was selected.",
+                default_widget_type.get_pretty_name()
+            ));
+        }
+    }
+
     let basic_table_widget_state = if use_basic_mode {
         Some(match initial_widget_type {
             Proc | Disk | Temp => BasicTableWidgetState {
@@ -231,10 +394,17 @@ pub fn build_app(
             .context("Update 'rate' in your config file.")?,
         temperature_type: get_temperature(matches, config)
             .context("Update 'temperature_type' in your config file.")?,
+        hide_temperature_unit: get_hide_temperature_unit(matches, config),
         show_average_cpu: get_show_average_cpu(matches, config),
-        use_dot: get_use_dot(matches, config),
+        graph_marker: get_graph_marker(matches, config)
+            .context("Update 'graph_marker' in your config file.")?,
+        raw_graphs: get_raw_graphs(config),
+        colour_depth: get_colour_depth(matches, config)
+            .context("Update 'color' in your config file.")?,
         left_legend: get_use_left_legend(matches, config),
-        use_current_cpu_total: get_use_current_cpu_total(matches, config),
+        use_current_cpu_total: get_use_current_cpu_total(matches, config)
+            && !get_per_core_cpu(matches, config),
+        per_core_cpu: get_per_core_cpu(matches, config),
         use_basic_mode,
         default_time_value,
         time_interval: get_time_interval(matches, config)
@@ -248,29 +418,112 @@ pub fn build_app(
             1
         },
         disable_click: get_disable_click(matches, config),
+        mem_warn_threshold_percent: get_mem_warn_threshold_percent(config)
+            .context("Update 'warn_threshold_percent' in your config file.")?,
+        mem_warn_bell: get_mem_warn_bell(config),
+        mem_pressure_weights: get_mem_pressure_weights(config)
+            .context("Update the [mem.pressure] section in your config file.")?,
+        disk_warn_threshold_percent: get_disk_warn_threshold_percent(config)
+            .context("Update 'warn_threshold_percent' under [disk] in your config file.")?,
+        disk_mount_warn_threshold_percent: get_disk_mount_warn_threshold_percent(config)
+            .context("Update 'mount_warn_threshold_percent' in your config file.")?,
+        disk_columns: get_disk_columns(config)
+            .context("Update 'columns' under [disk] in your config file.")?,
+        command_display: get_command_display(config)
+            .context("Update 'command_display' in your config file.")?,
+        selection_style: get_selection_style(config)
+            .context("Update 'selection_style' in your config file.")?,
+        use_cpu_usage_gradient: get_use_cpu_usage_gradient(config),
+        min_terminal_width: get_min_terminal_width(config),
+        min_terminal_height: get_min_terminal_height(config),
+        quit_confirmation: get_quit_confirmation(config)
+            .context("Update 'quit_confirmation' in your config file.")?,
+        quit_from_search: get_quit_from_search(config),
+        percent_precision: get_percent_precision(config)
+            .context("Update 'percent_precision' in your config file.")?,
+        rate_precision: get_rate_precision(config)
+            .context("Update 'rate_precision' in your config file.")?,
+        rate_smoothing_alpha: get_rate_smoothing_alpha(config)
+            .context("Update 'rate_smoothing_alpha' in your config file.")?,
+        byte_format: ByteFormat {
+            unit_threshold: get_byte_unit_threshold(config)
+                .context("Update 'byte_unit_threshold' in your config file.")?,
+            significant_figures: get_byte_significant_figures(config)
+                .context("Update 'byte_significant_figures' in your config file.")?,
+        },
+        pause_idle_redraws: get_pause_idle_redraws(config),
+        oom_score_warn_threshold: get_oom_score_warn_threshold(config),
+        zombie_warn_threshold: get_zombie_warn_threshold(config),
+        process_empty_message: get_process_empty_message(config),
+        show_self_usage: get_show_self_usage(matches, config),
+        crash_report: get_crash_report(matches, config),
+        label: get_label(matches, config),
+        refresh_key: get_refresh_key(config)
+            .context("Update 'refresh_key' in your config file.")?,
+        scroll_wrap: get_scroll_wrap(config),
+        zebra_stripes: get_zebra_stripes(config),
+        scroll_lines_per_tick: get_scroll_lines_per_tick(config)
+            .context("Update 'scroll_lines_per_tick' in your config file.")?,
+        secondary_sort: get_secondary_sort(config)
+            .context("Update 'secondary_sort' in your config file.")?,
+        process_state_counts: get_process_state_counts(config)
+            .context("Update 'state_counts' in your config file.")?,
+        max_data_points: get_max_data_points(config)
+            .context("Update 'max_data_points' in your config file.")?,
+        disable_process_io: get_disable_process_io(matches, config),
+        disable_cpu_breakdown: get_disable_cpu_breakdown(matches, config),
+        show_scheduler_activity: get_show_scheduler_activity(matches, config),
+        time_format: get_time_format(config)
+            .context("Update 'time_format' in your config file.")?,
+        run_duration_in_seconds: get_run_duration_in_seconds(matches)
+            .context("Update the '--duration' flag.")?,
     };
 
     let used_widgets = UsedWidgets {
         use_cpu: used_widget_set.get(&Cpu).is_some() || used_widget_set.get(&BasicCpu).is_some(),
         use_mem: used_widget_set.get(&Mem).is_some() || used_widget_set.get(&BasicMem).is_some(),
-        use_net: used_widget_set.get(&Net).is_some() || used_widget_set.get(&BasicNet).is_some(),
-        use_proc: used_widget_set.get(&Proc).is_some(),
-        use_disk: used_widget_set.get(&Disk).is_some(),
-        use_temp: used_widget_set.get(&Temp).is_some(),
-        use_battery: used_widget_set.get(&Battery).is_some(),
+        use_net: (used_widget_set.get(&Net).is_some() || used_widget_set.get(&BasicNet).is_some())
+            && !get_disable_network(matches, config),
+        use_proc: used_widget_set.get(&Proc).is_some() || app_config_fields.show_self_usage,
+        use_disk: used_widget_set.get(&Disk).is_some() && !get_disable_disk(matches, config),
+        use_temp: used_widget_set.get(&Temp).is_some() && !get_disable_temperature(matches, config),
+        use_battery: used_widget_set.get(&Battery).is_some()
+            && !get_disable_battery(matches, config),
+        use_pressure: used_widget_set.get(&Pressure).is_some(),
+        use_system_info: used_widget_set.get(&SystemInfo).is_some(),
     };
 
+    let (pinned_names, show_pinned_not_running) = get_pinned_processes(config);
+    let mut proc_state = ProcState::init(proc_state_map);
+    proc_state.pinned_names = pinned_names;
+    proc_state.show_pinned_not_running = show_pinned_not_running;
+    proc_state.watched_names = state_file::StateFile::read().watched;
+    let (custom_headers, column_warning) = get_custom_column_headers(config);
+    for proc_widget_state in proc_state.widget_states.values_mut() {
+        proc_widget_state
+            .columns
+            .set_custom_headers(custom_headers.clone());
+    }
+
+    let mut net_state = NetState::init(net_state_map);
+    net_state.is_showing_split_axes = get_split_network_axes(config);
+
     let disk_filter =
         get_ignore_list(&config.disk_filter).context("Update 'disk_filter' in your config file")?;
     let temp_filter =
         get_ignore_list(&config.temp_filter).context("Update 'temp_filter' in your config file")?;
+    let hidden_disks =
+        get_hidden_disks(config).context("Update 'hide_mounts' in your config file.")?;
+
+    let rate_ramp = get_rate_ramp(config, app_config_fields.update_rate_in_milliseconds)
+        .context("Update the [rate_ramp] section in your config file.")?;
 
-    Ok(App::builder()
+    let mut app = App::builder()
         .app_config_fields(app_config_fields)
         .cpu_state(CpuState::init(cpu_state_map))
         .mem_state(MemState::init(mem_state_map))
-        .net_state(NetState::init(net_state_map))
-        .proc_state(ProcState::init(proc_state_map))
+        .net_state(net_state)
+        .proc_state(proc_state)
         .disk_state(DiskState::init(disk_state_map))
         .temp_state(TempState::init(temp_state_map))
         .battery_state(BatteryState::init(battery_state_map))
@@ -278,11 +531,23 @@ pub fn build_app(
         .current_widget(widget_map.get(&initial_widget_id).unwrap().clone()) // I think the unwrap is fine here
         .widget_map(widget_map)
         .used_widgets(used_widgets)
+        .rate_ramp(rate_ramp)
         .filters(DataFilters {
             disk_filter,
             temp_filter,
+            hidden_disks,
         })
-        .build())
+        .build();
+
+    let warnings = default_widget_warning
+        .into_iter()
+        .chain(column_warning)
+        .collect::<Vec<_>>();
+    if !warnings.is_empty() {
+        app.show_message(warnings.join(" "));
+    }
+
+    Ok(app)
 }
 
 pub fn get_widget_layout(
@@ -303,13 +568,9 @@ pub fn get_widget_layout(
             Some(r) => r,
             None => {
                 // This cannot (like it really shouldn't) fail!
-                ref_row = toml::from_str::<Config>(if get_use_battery(matches, config) {
-                    DEFAULT_BATTERY_LAYOUT
-                } else {
-                    DEFAULT_LAYOUT
-                })?
-                .row
-                .unwrap();
+                ref_row = toml::from_str::<Config>(get_layout_preset(matches, config)?)?
+                    .row
+                    .unwrap();
                 &ref_row
             }
         };
@@ -350,6 +611,34 @@ pub fn get_widget_layout(
     Ok((bottom_layout, default_widget_id, default_widget_type))
 }
 
+/// Returns the built-in layout to use if the config doesn't define one.
+fn get_layout_preset(
+    matches: &clap::ArgMatches<'static>, config: &Config,
+) -> error::Result<&'static str> {
+    let preset = if let Some(preset) = matches.value_of("PRESET") {
+        Some(preset)
+    } else if let Some(flags) = &config.flags {
+        flags.preset.as_ref().map(|preset| preset.as_str())
+    } else {
+        None
+    };
+
+    match preset {
+        None | Some("default") => Ok(if get_use_battery(matches, config) {
+            DEFAULT_BATTERY_LAYOUT
+        } else {
+            DEFAULT_LAYOUT
+        }),
+        Some("proc-focused") => Ok(PROC_FOCUSED_LAYOUT),
+        Some("graphs-only") => Ok(GRAPHS_ONLY_LAYOUT),
+        Some("minimal") => Ok(MINIMAL_LAYOUT),
+        Some(preset) => Err(BottomError::ConfigError(format!(
+            "\"{}\" is an invalid preset, use \"<default|proc-focused|graphs-only|minimal>\".",
+            preset
+        ))),
+    }
+}
+
 fn get_update_rate_in_milliseconds(
     matches: &clap::ArgMatches<'static>, config: &Config,
 ) -> error::Result<u64> {
@@ -387,6 +676,8 @@ fn get_temperature(
         return Ok(data_harvester::temperature::TemperatureType::Kelvin);
     } else if matches.is_present("CELSIUS") {
         return Ok(data_harvester::temperature::TemperatureType::Celsius);
+    } else if matches.is_present("RAW_TEMPERATURE") {
+        return Ok(data_harvester::temperature::TemperatureType::Raw);
     } else if let Some(flags) = &config.flags {
         if let Some(temp_type) = &flags.temperature_type {
             // Give lowest priority to config.
@@ -394,8 +685,9 @@ fn get_temperature(
                 "fahrenheit" | "f" => Ok(data_harvester::temperature::TemperatureType::Fahrenheit),
                 "kelvin" | "k" => Ok(data_harvester::temperature::TemperatureType::Kelvin),
                 "celsius" | "c" => Ok(data_harvester::temperature::TemperatureType::Celsius),
+                "raw" => Ok(data_harvester::temperature::TemperatureType::Raw),
                 _ => Err(BottomError::ConfigError(format!(
-                    "\"{}\" is an invalid temperature type, use \"<kelvin|k|celsius|c|fahrenheit|f>\".",
+                    "\"{}\" is an invalid temperature type, use \"<kelvin|k|celsius|c|fahrenheit|f|raw>\".",
                     temp_type
                 ))),
             };
@@ -404,6 +696,353 @@ fn get_temperature(
     Ok(data_harvester::temperature::TemperatureType::Celsius)
 }
 
+fn get_use_cpu_usage_gradient(config: &Config) -> bool {
+    if let Some(flags) = &config.flags {
+        if let Some(cpu_usage_gradient) = flags.cpu_usage_gradient {
+            return cpu_usage_gradient;
+        }
+    }
+    false
+}
+
+fn get_pause_idle_redraws(config: &Config) -> bool {
+    if let Some(flags) = &config.flags {
+        if let Some(pause_idle_redraws) = flags.pause_idle_redraws {
+            return pause_idle_redraws;
+        }
+    }
+    false
+}
+
+fn get_min_terminal_width(config: &Config) -> u16 {
+    if let Some(flags) = &config.flags {
+        if let Some(min_terminal_width) = flags.min_terminal_width {
+            return min_terminal_width;
+        }
+    }
+    0
+}
+
+fn get_min_terminal_height(config: &Config) -> u16 {
+    if let Some(flags) = &config.flags {
+        if let Some(min_terminal_height) = flags.min_terminal_height {
+            return min_terminal_height;
+        }
+    }
+    0
+}
+
+fn get_command_display(config: &Config) -> error::Result<CommandDisplay> {
+    if let Some(flags) = &config.flags {
+        if let Some(command_display) = &flags.command_display {
+            return match command_display.as_str() {
+                "full" => Ok(CommandDisplay::Full),
+                "basename" => Ok(CommandDisplay::Basename),
+                "first-arg" => Ok(CommandDisplay::FirstArg),
+                _ => Err(BottomError::ConfigError(format!(
+                    "\"{}\" is an invalid command display, use \"<full|basename|first-arg>\".",
+                    command_display
+                ))),
+            };
+        }
+    }
+    Ok(CommandDisplay::Full)
+}
+
+fn get_selection_style(config: &Config) -> error::Result<SelectionStyle> {
+    if let Some(flags) = &config.flags {
+        if let Some(selection_style) = &flags.selection_style {
+            return match selection_style.as_str() {
+                "full_row" => Ok(SelectionStyle::FullRow),
+                "arrow" => Ok(SelectionStyle::Arrow),
+                "bold" => Ok(SelectionStyle::Bold),
+                _ => Err(BottomError::ConfigError(format!(
+                    "\"{}\" is an invalid selection style, use \"<full_row|arrow|bold>\".",
+                    selection_style
+                ))),
+            };
+        }
+    }
+    Ok(SelectionStyle::FullRow)
+}
+
+fn get_percent_precision(config: &Config) -> error::Result<Option<usize>> {
+    if let Some(flags) = &config.flags {
+        if let Some(percent_precision) = flags.percent_precision {
+            if percent_precision > MAX_DISPLAY_PRECISION {
+                return Err(BottomError::ConfigError(format!(
+                    "set your percent precision to be between 0 and {}.",
+                    MAX_DISPLAY_PRECISION
+                )));
+            }
+            return Ok(Some(percent_precision));
+        }
+    }
+    Ok(None)
+}
+
+fn get_rate_precision(config: &Config) -> error::Result<Option<usize>> {
+    if let Some(flags) = &config.flags {
+        if let Some(rate_precision) = flags.rate_precision {
+            if rate_precision > MAX_DISPLAY_PRECISION {
+                return Err(BottomError::ConfigError(format!(
+                    "set your rate precision to be between 0 and {}.",
+                    MAX_DISPLAY_PRECISION
+                )));
+            }
+            return Ok(Some(rate_precision));
+        }
+    }
+    Ok(None)
+}
+
+fn get_rate_smoothing_alpha(config: &Config) -> error::Result<f64> {
+    if let Some(flags) = &config.flags {
+        if let Some(rate_smoothing_alpha) = flags.rate_smoothing_alpha {
+            // Written this way so that NaN is rejected as well.
+            if rate_smoothing_alpha > 0.0 && rate_smoothing_alpha <= 1.0 {
+                return Ok(rate_smoothing_alpha);
+            }
+            return Err(BottomError::ConfigError(
+                "set your rate smoothing alpha to be greater than 0 and at most 1.".to_string(),
+            ));
+        }
+    }
+    Ok(1.0)
+}
+
+fn get_byte_unit_threshold(config: &Config) -> error::Result<Option<f64>> {
+    if let Some(flags) = &config.flags {
+        if let Some(byte_unit_threshold) = flags.byte_unit_threshold {
+            // Written this way so that NaN and infinity are rejected as well.
+            if byte_unit_threshold > 0.0 && byte_unit_threshold.is_finite() {
+                return Ok(Some(byte_unit_threshold));
+            }
+            return Err(BottomError::ConfigError(
+                "set your byte unit threshold to be greater than 0.".to_string(),
+            ));
+        }
+    }
+    Ok(None)
+}
+
+fn get_byte_significant_figures(config: &Config) -> error::Result<Option<usize>> {
+    if let Some(flags) = &config.flags {
+        if let Some(byte_significant_figures) = flags.byte_significant_figures {
+            if byte_significant_figures == 0 {
+                return Err(BottomError::ConfigError(
+                    "set your byte significant figures to be at least 1.".to_string(),
+                ));
+            }
+            return Ok(Some(byte_significant_figures));
+        }
+    }
+    Ok(None)
+}
+
+fn get_max_data_points(config: &Config) -> error::Result<Option<usize>> {
+    if let Some(flags) = &config.flags {
+        if let Some(max_data_points) = flags.max_data_points {
+            if max_data_points == 0 {
+                return Err(BottomError::ConfigError(
+                    "set your max data points to be at least 1.".to_string(),
+                ));
+            }
+            return Ok(Some(max_data_points as usize));
+        }
+    }
+    Ok(None)
+}
+
+fn get_scroll_lines_per_tick(config: &Config) -> error::Result<u64> {
+    if let Some(flags) = &config.flags {
+        if let Some(scroll_lines_per_tick) = flags.scroll_lines_per_tick {
+            if scroll_lines_per_tick == 0 {
+                return Err(BottomError::ConfigError(
+                    "set your scroll lines per tick to be at least 1.".to_string(),
+                ));
+            }
+            return Ok(scroll_lines_per_tick);
+        }
+    }
+    Ok(1)
+}
+
+fn get_scroll_wrap(config: &Config) -> bool {
+    if let Some(flags) = &config.flags {
+        if let Some(scroll_wrap) = flags.scroll_wrap {
+            return scroll_wrap;
+        }
+    }
+    false
+}
+
+fn get_colour_depth(
+    matches: &clap::ArgMatches<'static>, config: &Config,
+) -> error::Result<ColourDepth> {
+    let colour_depth = if let Some(colour_depth) = matches.value_of("COLOR") {
+        Some(colour_depth)
+    } else if let Some(flags) = &config.flags {
+        flags
+            .color
+            .as_ref()
+            .map(|colour_depth| colour_depth.as_str())
+    } else {
+        None
+    };
+
+    match colour_depth {
+        None | Some("auto") => Ok(detect_colour_depth()),
+        Some("16") => Ok(ColourDepth::Basic),
+        Some("256") => Ok(ColourDepth::Indexed),
+        Some("truecolor") => Ok(ColourDepth::TrueColour),
+        Some(colour_depth) => Err(BottomError::ConfigError(format!(
+            "\"{}\" is an invalid colour depth, use \"<auto|16|256|truecolor>\".",
+            colour_depth
+        ))),
+    }
+}
+
+fn get_raw_graphs(config: &Config) -> bool {
+    if let Some(flags) = &config.flags {
+        if let Some(raw_graphs) = flags.raw_graphs {
+            return raw_graphs;
+        }
+    }
+    false
+}
+
+fn get_zebra_stripes(config: &Config) -> bool {
+    if let Some(flags) = &config.flags {
+        if let Some(zebra_stripes) = flags.zebra_stripes {
+            return zebra_stripes;
+        }
+    }
+    false
+}
+
+fn get_run_duration_in_seconds(matches: &clap::ArgMatches<'static>) -> error::Result<Option<u64>> {
+    if let Some(duration) = matches.value_of("DURATION") {
+        let duration = duration.parse::<u64>()?;
+        if duration == 0 {
+            return Err(BottomError::ConfigError(
+                "set your duration to be at least 1 second.".to_string(),
+            ));
+        }
+        return Ok(Some(duration));
+    }
+    Ok(None)
+}
+
+fn get_disable_process_io(matches: &clap::ArgMatches<'static>, config: &Config) -> bool {
+    if matches.is_present("DISABLE_PROCESS_IO") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(disable_process_io) = flags.disable_process_io {
+            return disable_process_io;
+        }
+    }
+    false
+}
+
+fn get_disable_cpu_breakdown(matches: &clap::ArgMatches<'static>, config: &Config) -> bool {
+    if matches.is_present("DISABLE_CPU_BREAKDOWN") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(disable_cpu_breakdown) = flags.disable_cpu_breakdown {
+            return disable_cpu_breakdown;
+        }
+    }
+    false
+}
+
+fn get_show_scheduler_activity(matches: &clap::ArgMatches<'static>, config: &Config) -> bool {
+    if matches.is_present("SHOW_SCHEDULER_ACTIVITY") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(show_scheduler_activity) = flags.show_scheduler_activity {
+            return show_scheduler_activity;
+        }
+    }
+    false
+}
+
+fn get_time_format(config: &Config) -> error::Result<Option<String>> {
+    if let Some(flags) = &config.flags {
+        if let Some(time_format) = &flags.time_format {
+            // Formatting with an invalid specifier fails, so it must be caught here instead.
+            if chrono::format::StrftimeItems::new(time_format)
+                .any(|item| item == chrono::format::Item::Error)
+            {
+                return Err(BottomError::ConfigError(format!(
+                    "\"{}\" is an invalid time format, use strftime specifiers such as \"%Y-%m-%d %H:%M:%S\".",
+                    time_format
+                )));
+            }
+            return Ok(Some(time_format.clone()));
+        }
+    }
+    Ok(None)
+}
+
+/// Fills in any flags that the config file leaves unset with cheaper defaults for constrained
+/// devices, so the config file (and any flags passed in) still take priority.
+pub fn apply_lite_defaults(config: &mut Config) {
+    let flags = config.flags.get_or_insert_with(ConfigFlags::default);
+
+    flags.rate.get_or_insert(LITE_REFRESH_RATE_IN_MILLISECONDS);
+    flags.max_data_points.get_or_insert(LITE_MAX_DATA_POINTS);
+    flags.disable_process_io.get_or_insert(true);
+    flags.disable_cpu_breakdown.get_or_insert(true);
+    if flags.graph_marker.is_none() && flags.dot_marker.is_none() {
+        flags.graph_marker = Some("dot".to_string());
+    }
+
+    // Keep the costlier process fields from being read, such as each process' swap usage.
+    let processes = config
+        .processes
+        .get_or_insert_with(ConfigProcesses::default);
+    processes.show_swap.get_or_insert(false);
+    processes.show_oom_score.get_or_insert(false);
+    processes.show_affinity.get_or_insert(false);
+    processes.only_own.get_or_insert(false);
+}
+
+fn get_split_network_axes(config: &Config) -> bool {
+    if let Some(flags) = &config.flags {
+        if let Some(split_network_axes) = flags.split_network_axes {
+            return split_network_axes;
+        }
+    }
+    false
+}
+
+fn get_quit_from_search(config: &Config) -> bool {
+    if let Some(flags) = &config.flags {
+        if let Some(quit_from_search) = flags.quit_from_search {
+            return quit_from_search;
+        }
+    }
+    false
+}
+
+fn get_quit_confirmation(config: &Config) -> error::Result<QuitConfirmation> {
+    if let Some(flags) = &config.flags {
+        if let Some(quit_confirmation) = &flags.quit_confirmation {
+            return match quit_confirmation.as_str() {
+                "none" => Ok(QuitConfirmation::Immediate),
+                "double" => Ok(QuitConfirmation::Double),
+                "prompt" => Ok(QuitConfirmation::Prompt),
+                _ => Err(BottomError::ConfigError(format!(
+                    "\"{}\" is an invalid quit confirmation, use \"<none|double|prompt>\".",
+                    quit_confirmation
+                ))),
+            };
+        }
+    }
+    Ok(QuitConfirmation::Immediate)
+}
+
 /// Yes, this function gets whether to show average CPU (true) or not (false)
 fn get_show_average_cpu(matches: &clap::ArgMatches<'static>, config: &Config) -> bool {
     if matches.is_present("HIDE_AVG_CPU") {
@@ -417,15 +1056,35 @@ fn get_show_average_cpu(matches: &clap::ArgMatches<'static>, config: &Config) ->
     true
 }
 
-fn get_use_dot(matches: &clap::ArgMatches<'static>, config: &Config) -> bool {
+fn get_graph_marker(
+    matches: &clap::ArgMatches<'static>, config: &Config,
+) -> error::Result<GraphMarker> {
     if matches.is_present("DOT_MARKER") {
-        return true;
+        return Ok(GraphMarker::Dot);
     } else if let Some(flags) = &config.flags {
-        if let Some(dot_marker) = flags.dot_marker {
-            return dot_marker;
+        if let Some(graph_marker) = &flags.graph_marker {
+            return match graph_marker.as_str() {
+                "braille" => Ok(GraphMarker::Braille),
+                "dot" => Ok(GraphMarker::Dot),
+                "half_block" => Ok(GraphMarker::HalfBlock),
+                // Falls back to braille if the terminal can't show images, or if bottom was
+                // built without the pixel-graphs feature.
+                #[cfg(feature = "pixel-graphs")]
+                "pixels" => Ok(crate::canvas::detect_image_protocol()
+                    .map(GraphMarker::Pixels)
+                    .unwrap_or(GraphMarker::Braille)),
+                #[cfg(not(feature = "pixel-graphs"))]
+                "pixels" => Ok(GraphMarker::Braille),
+                _ => Err(BottomError::ConfigError(format!(
+                    "\"{}\" is an invalid graph marker, use \"<braille|dot|half_block|pixels>\".",
+                    graph_marker
+                ))),
+            };
+        } else if flags.dot_marker == Some(true) {
+            return Ok(GraphMarker::Dot);
         }
     }
-    false
+    Ok(GraphMarker::Braille)
 }
 
 fn get_use_left_legend(matches: &clap::ArgMatches<'static>, config: &Config) -> bool {
@@ -452,6 +1111,18 @@ fn get_use_current_cpu_total(matches: &clap::ArgMatches<'static>, config: &Confi
     false
 }
 
+fn get_per_core_cpu(matches: &clap::ArgMatches<'static>, config: &Config) -> bool {
+    if matches.is_present("PER_CORE_CPU") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(per_core_cpu) = flags.per_core_cpu {
+            return per_core_cpu;
+        }
+    }
+
+    false
+}
+
 fn get_use_basic_mode(matches: &clap::ArgMatches<'static>, config: &Config) -> bool {
     if matches.is_present("BASIC_MODE") {
         return true;
@@ -638,7 +1309,43 @@ fn get_default_widget_and_count(
         (None, Some(_widget_count)) =>  Err(BottomError::ConfigError(
             "cannot set 'default_widget_count' by itself, it must be used with 'default_widget_type'.".to_string(),
         )),
-        (None, None) => Ok((None, 1))
+        (None, None) => Ok((get_default_widget(matches, config)?, 1))
+    }
+}
+
+/// Returns the widget type set by `default_widget`, which selects the first widget of that type.
+/// This is only used if `default_widget_type` isn't set.
+fn get_default_widget(
+    matches: &clap::ArgMatches<'static>, config: &Config,
+) -> error::Result<Option<BottomWidgetType>> {
+    let default_widget = if let Some(default_widget) = matches.value_of("DEFAULT_WIDGET") {
+        Some(default_widget)
+    } else if let Some(flags) = &config.flags {
+        flags.default_widget.as_ref().map(|widget| widget.as_str())
+    } else {
+        None
+    };
+
+    if let Some(default_widget) = default_widget {
+        let parsed_widget = default_widget.parse::<BottomWidgetType>()?;
+        if let BottomWidgetType::Empty = parsed_widget {
+            Ok(None)
+        } else {
+            Ok(Some(parsed_widget))
+        }
+    } else {
+        Ok(None)
+    }
+}
+
+/// Returns whether a widget is of the given type, treating the basic mode widgets as their
+/// full counterparts.
+fn is_widget_of_type(widget_type: &BottomWidgetType, wanted_type: &BottomWidgetType) -> bool {
+    match widget_type {
+        BottomWidgetType::BasicCpu => *wanted_type == BottomWidgetType::Cpu,
+        BottomWidgetType::BasicMem => *wanted_type == BottomWidgetType::Mem,
+        BottomWidgetType::BasicNet => *wanted_type == BottomWidgetType::Net,
+        _ => widget_type == wanted_type,
     }
 }
 
@@ -653,6 +1360,50 @@ fn get_disable_click(matches: &clap::ArgMatches<'static>, config: &Config) -> bo
     false
 }
 
+fn get_disable_battery(matches: &clap::ArgMatches<'static>, config: &Config) -> bool {
+    if matches.is_present("DISABLE_BATTERY") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(disable_battery) = flags.disable_battery {
+            return disable_battery;
+        }
+    }
+    false
+}
+
+fn get_disable_disk(matches: &clap::ArgMatches<'static>, config: &Config) -> bool {
+    if matches.is_present("DISABLE_DISK") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(disable_disk) = flags.disable_disk {
+            return disable_disk;
+        }
+    }
+    false
+}
+
+fn get_disable_network(matches: &clap::ArgMatches<'static>, config: &Config) -> bool {
+    if matches.is_present("DISABLE_NETWORK") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(disable_network) = flags.disable_network {
+            return disable_network;
+        }
+    }
+    false
+}
+
+fn get_disable_temperature(matches: &clap::ArgMatches<'static>, config: &Config) -> bool {
+    if matches.is_present("DISABLE_TEMPERATURE") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(disable_temperature) = flags.disable_temperature {
+            return disable_temperature;
+        }
+    }
+    false
+}
+
 pub fn get_use_old_network_legend(matches: &clap::ArgMatches<'static>, config: &Config) -> bool {
     if matches.is_present("USE_OLD_NETWORK_LEGEND") {
         return true;
@@ -675,6 +1426,82 @@ pub fn get_hide_table_gap(matches: &clap::ArgMatches<'static>, config: &Config)
     false
 }
 
+pub fn get_hide_temperature_unit(matches: &clap::ArgMatches<'static>, config: &Config) -> bool {
+    if matches.is_present("HIDE_TEMPERATURE_UNIT") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(hide_temperature_unit) = flags.hide_temperature_unit {
+            return hide_temperature_unit;
+        }
+    }
+    false
+}
+
+pub fn get_crash_report(matches: &clap::ArgMatches<'static>, config: &Config) -> bool {
+    if matches.is_present("CRASH_REPORT") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(crash_report) = flags.crash_report {
+            return crash_report;
+        }
+    }
+    false
+}
+
+pub fn get_show_self_usage(matches: &clap::ArgMatches<'static>, config: &Config) -> bool {
+    if matches.is_present("SHOW_SELF_USAGE") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(show_self_usage) = flags.show_self_usage {
+            return show_self_usage;
+        }
+    }
+    false
+}
+
+fn get_refresh_key(config: &Config) -> error::Result<char> {
+    if let Some(flags) = &config.flags {
+        if let Some(refresh_key) = &flags.refresh_key {
+            let mut refresh_key_chars = refresh_key.chars();
+            return match (refresh_key_chars.next(), refresh_key_chars.next()) {
+                // The key is also caught with shift, so a lowercase key would shadow the
+                // binding of its uppercase key too.
+                (Some(refresh_key_char), None)
+                    if BOUND_KEYS.contains(&refresh_key_char)
+                        || BOUND_KEYS.contains(&refresh_key_char.to_ascii_uppercase()) =>
+                {
+                    Err(BottomError::ConfigError(format!(
+                        "\"{}\" is already bound, set your refresh key to an unused character, such as \"U\".",
+                        refresh_key
+                    )))
+                }
+                (Some(refresh_key_char), None)
+                    if !refresh_key_char.is_whitespace() && !refresh_key_char.is_control() =>
+                {
+                    Ok(refresh_key_char)
+                }
+                _ => Err(BottomError::ConfigError(
+                    "set your refresh key to a single character, such as \"U\".".to_string(),
+                )),
+            };
+        }
+    }
+    Ok(DEFAULT_REFRESH_KEY)
+}
+
+/// An empty label is treated as no label.
+fn get_label(matches: &clap::ArgMatches<'static>, config: &Config) -> Option<String> {
+    let label = if let Some(label) = matches.value_of("LABEL") {
+        Some(label.to_string())
+    } else if let Some(flags) = &config.flags {
+        flags.label.clone()
+    } else {
+        None
+    };
+
+    label.filter(|label| !label.trim().is_empty())
+}
+
 pub fn get_use_battery(matches: &clap::ArgMatches<'static>, config: &Config) -> bool {
     if matches.is_present("BATTERY") {
         return true;
@@ -686,6 +1513,375 @@ pub fn get_use_battery(matches: &clap::ArgMatches<'static>, config: &Config) ->
     false
 }
 
+fn get_mem_warn_threshold_percent(config: &Config) -> error::Result<Option<f64>> {
+    if let Some(mem) = &config.mem {
+        if let Some(threshold) = mem.warn_threshold_percent {
+            if !(0.0..=100.0).contains(&threshold) {
+                return Err(BottomError::ConfigError(
+                    "set your memory warning threshold to be between 0 and 100.".to_string(),
+                ));
+            }
+            return Ok(Some(threshold));
+        }
+    }
+    Ok(None)
+}
+
+fn get_disk_warn_threshold_percent(config: &Config) -> error::Result<Option<f64>> {
+    if let Some(disk) = &config.disk {
+        if let Some(threshold) = disk.warn_threshold_percent {
+            if !(0.0..=100.0).contains(&threshold) {
+                return Err(BottomError::ConfigError(
+                    "set your disk warning threshold to be between 0 and 100.".to_string(),
+                ));
+            }
+            return Ok(Some(threshold));
+        }
+    }
+    Ok(None)
+}
+
+fn get_disk_mount_warn_threshold_percent(config: &Config) -> error::Result<HashMap<String, f64>> {
+    if let Some(disk) = &config.disk {
+        if let Some(mount_thresholds) = &disk.mount_warn_threshold_percent {
+            if let Some((mount, _)) = mount_thresholds
+                .iter()
+                .find(|(_, threshold)| !(0.0..=100.0).contains(*threshold))
+            {
+                return Err(BottomError::ConfigError(format!(
+                    "set the disk warning threshold for \"{}\" to be between 0 and 100.",
+                    mount
+                )));
+            }
+            return Ok(mount_thresholds.clone());
+        }
+    }
+    Ok(HashMap::new())
+}
+
+fn get_disk_columns(config: &Config) -> error::Result<DiskColumns> {
+    let mut disk_columns = DiskColumns::default();
+    if let Some(disk) = &config.disk {
+        if let Some(columns) = &disk.columns {
+            for column in columns {
+                match column.to_lowercase().as_str() {
+                    "type" => disk_columns.file_system = true,
+                    "options" => disk_columns.mount_options = true,
+                    _ => {
+                        return Err(BottomError::ConfigError(format!(
+                            "set your disk columns to only include \"type\" or \"options\", not \"{}\".",
+                            column
+                        )));
+                    }
+                }
+            }
+        }
+    }
+    Ok(disk_columns)
+}
+
+fn get_mem_warn_bell(config: &Config) -> bool {
+    if let Some(mem) = &config.mem {
+        if let Some(warn_bell) = mem.warn_bell {
+            return warn_bell;
+        }
+    }
+    false
+}
+
+fn get_mem_pressure_weights(config: &Config) -> error::Result<MemPressureWeights> {
+    let mut weights = MemPressureWeights::default();
+    if let Some(pressure) = config.mem.as_ref().and_then(|mem| mem.pressure.as_ref()) {
+        if let Some(memory_weight) = pressure.memory_weight {
+            weights.memory = memory_weight;
+        }
+        if let Some(swap_weight) = pressure.swap_weight {
+            weights.swap = swap_weight;
+        }
+        if let Some(swap_activity_weight) = pressure.swap_activity_weight {
+            weights.swap_activity = swap_activity_weight;
+        }
+        if let Some(swap_activity_max_pages) = pressure.swap_activity_max_pages {
+            weights.swap_activity_max_pages = swap_activity_max_pages;
+        }
+    }
+
+    if weights.memory < 0.0 || weights.swap < 0.0 || weights.swap_activity < 0.0 {
+        return Err(BottomError::ConfigError(
+            "set your memory pressure weights to be at least 0.".to_string(),
+        ));
+    } else if weights.memory + weights.swap + weights.swap_activity <= 0.0 {
+        return Err(BottomError::ConfigError(
+            "set at least one of your memory pressure weights to be above 0.".to_string(),
+        ));
+    } else if weights.swap_activity_max_pages < 1.0 {
+        return Err(BottomError::ConfigError(
+            "set your swap activity max pages to be at least 1.".to_string(),
+        ));
+    }
+
+    Ok(weights)
+}
+
+fn get_rate_ramp(
+    config: &Config, update_rate_in_milliseconds: u64,
+) -> error::Result<Option<RateRamp>> {
+    let rate_ramp = match &config.rate_ramp {
+        Some(rate_ramp) => rate_ramp,
+        None => return Ok(None),
+    };
+
+    let cpu_threshold_percent = rate_ramp
+        .cpu_threshold_percent
+        .unwrap_or(DEFAULT_RATE_RAMP_CPU_THRESHOLD_PERCENT);
+    let ticks = rate_ramp.ticks.unwrap_or(DEFAULT_RATE_RAMP_TICKS);
+    let max_rate = rate_ramp
+        .max_rate
+        .unwrap_or(update_rate_in_milliseconds * DEFAULT_RATE_RAMP_MAX_RATE_MULTIPLIER);
+
+    if !(0.0..100.0).contains(&cpu_threshold_percent) {
+        return Err(BottomError::ConfigError(
+            "set your rate ramp CPU threshold to be at least 0 and below 100.".to_string(),
+        ));
+    } else if ticks == 0 {
+        return Err(BottomError::ConfigError(
+            "set your rate ramp ticks to be at least 1.".to_string(),
+        ));
+    } else if max_rate < update_rate_in_milliseconds {
+        return Err(BottomError::ConfigError(
+            "set your rate ramp max rate to be at least your update rate.".to_string(),
+        ));
+    }
+
+    Ok(Some(RateRamp::new(
+        update_rate_in_milliseconds,
+        max_rate,
+        cpu_threshold_percent,
+        ticks,
+    )))
+}
+
+fn get_pinned_processes(config: &Config) -> (Vec<String>, bool) {
+    if let Some(processes) = &config.processes {
+        (
+            processes.pinned.clone().unwrap_or_default(),
+            processes.show_pinned_not_running.unwrap_or(false),
+        )
+    } else {
+        (Vec::new(), false)
+    }
+}
+
+fn get_only_own_processes(config: &Config) -> bool {
+    if let Some(processes) = &config.processes {
+        if let Some(only_own) = processes.only_own {
+            return only_own;
+        }
+    }
+    false
+}
+
+fn get_show_ppid(config: &Config) -> bool {
+    if let Some(processes) = &config.processes {
+        if let Some(show_ppid) = processes.show_ppid {
+            return show_ppid;
+        }
+    }
+    false
+}
+
+/// Returns the optional process columns that are turned on in the config file.
+fn get_shown_proc_columns(config: &Config) -> Vec<ProcessSorting> {
+    let mut shown_columns = Vec::new();
+    if let Some(processes) = &config.processes {
+        let optional_columns = [
+            (processes.show_swap, ProcessSorting::Swap),
+            (processes.show_threads, ProcessSorting::Threads),
+            (processes.show_oom_score, ProcessSorting::OomScore),
+            (processes.show_affinity, ProcessSorting::CpuAffinity),
+        ];
+        for (is_shown, column) in optional_columns.iter() {
+            if is_shown.unwrap_or(false) {
+                shown_columns.push(column.clone());
+            }
+        }
+    }
+    shown_columns
+}
+
+fn get_oom_score_warn_threshold(config: &Config) -> Option<u64> {
+    if let Some(processes) = &config.processes {
+        processes.oom_score_warn_threshold
+    } else {
+        None
+    }
+}
+
+fn get_zombie_warn_threshold(config: &Config) -> u64 {
+    if let Some(processes) = &config.processes {
+        if let Some(zombie_warn_threshold) = processes.zombie_warn_threshold {
+            return zombie_warn_threshold;
+        }
+    }
+    DEFAULT_ZOMBIE_WARN_THRESHOLD
+}
+
+fn get_process_empty_message(config: &Config) -> String {
+    if let Some(processes) = &config.processes {
+        if let Some(empty_message) = &processes.empty_message {
+            return empty_message.clone();
+        }
+    }
+    DEFAULT_PROCESS_EMPTY_MESSAGE.to_string()
+}
+
+/// Returns the process column that a config key like `"cpu"` refers to.
+fn get_process_column(key: &str) -> Option<ProcessSorting> {
+    match key.to_lowercase().as_str() {
+        "cpu" | "cpu%" => Some(ProcessSorting::CpuPercent),
+        "mem" => Some(ProcessSorting::Mem),
+        "mem%" => Some(ProcessSorting::MemPercent),
+        "swap" => Some(ProcessSorting::Swap),
+        "pid" => Some(ProcessSorting::Pid),
+        "ppid" => Some(ProcessSorting::ParentPid),
+        "name" => Some(ProcessSorting::ProcessName),
+        "command" => Some(ProcessSorting::Command),
+        "read" | "r/s" => Some(ProcessSorting::ReadPerSecond),
+        "write" | "w/s" => Some(ProcessSorting::WritePerSecond),
+        "tread" | "t.read" => Some(ProcessSorting::TotalRead),
+        "twrite" | "t.write" => Some(ProcessSorting::TotalWrite),
+        "state" => Some(ProcessSorting::State),
+        "count" => Some(ProcessSorting::Count),
+        "threads" => Some(ProcessSorting::Threads),
+        "oom" => Some(ProcessSorting::OomScore),
+        "affinity" => Some(ProcessSorting::CpuAffinity),
+        _ => None,
+    }
+}
+
+fn get_secondary_sort(config: &Config) -> error::Result<Option<ProcessSorting>> {
+    if let Some(processes) = &config.processes {
+        if let Some(secondary_sort) = &processes.secondary_sort {
+            return match get_process_column(secondary_sort) {
+                Some(column) => Ok(Some(column)),
+                None => Err(BottomError::ConfigError(format!(
+                    "\"{}\" is an invalid secondary sort key.",
+                    secondary_sort
+                ))),
+            };
+        }
+    }
+    Ok(None)
+}
+
+fn get_process_state_counts(config: &Config) -> error::Result<Option<ProcessStateCountScope>> {
+    if let Some(processes) = &config.processes {
+        if let Some(state_counts) = &processes.state_counts {
+            return match state_counts.as_str() {
+                "all" => Ok(Some(ProcessStateCountScope::All)),
+                "matched" => Ok(Some(ProcessStateCountScope::Matched)),
+                _ => Err(BottomError::ConfigError(format!(
+                    "\"{}\" is an invalid state count scope, use \"<all|matched>\".",
+                    state_counts
+                ))),
+            };
+        }
+    }
+    Ok(None)
+}
+
+/// Returns the custom process column headers, along with a warning listing any unknown column
+/// keys, which are skipped rather than treated as errors.
+fn get_custom_column_headers(config: &Config) -> (HashMap<ProcessSorting, String>, Option<String>) {
+    let mut custom_headers = HashMap::new();
+    let mut unknown_keys = Vec::new();
+
+    if let Some(columns) = &config.columns {
+        for (key, column) in columns {
+            match get_process_column(key) {
+                Some(process_column) => {
+                    if let Some(header) = &column.header {
+                        custom_headers.insert(process_column, header.clone());
+                    }
+                }
+                None => unknown_keys.push(format!("\"{}\"", key)),
+            }
+        }
+    }
+
+    let warning = if unknown_keys.is_empty() {
+        None
+    } else {
+        unknown_keys.sort();
+        Some(format!(
+            "Skipped unknown columns in the config file: {}.",
+            unknown_keys.join(", ")
+        ))
+    };
+
+    (custom_headers, warning)
+}
+
+fn get_hidden_disks(config: &Config) -> error::Result<HiddenDisks> {
+    if let Some(disk) = &config.disk {
+        Ok(HiddenDisks {
+            file_systems: disk.hide_filesystems.clone().unwrap_or_default(),
+            mounts: disk
+                .hide_mounts
+                .as_ref()
+                .map(|hide_mounts| {
+                    hide_mounts
+                        .iter()
+                        .map(|glob| get_glob_regex(glob))
+                        .collect::<error::Result<Vec<_>>>()
+                })
+                .transpose()?
+                .unwrap_or_default(),
+        })
+    } else {
+        Ok(HiddenDisks::default())
+    }
+}
+
+/// Converts a glob to a regex that must match the whole string.  `*` matches any run of
+/// characters, `?` matches any single character, and `[...]` matches any character in the
+/// brackets.
+fn get_glob_regex(glob: &str) -> error::Result<Regex> {
+    let mut regex_string = String::from("^");
+    let mut is_in_class = false;
+    for c in glob.chars() {
+        match c {
+            '[' if !is_in_class => {
+                is_in_class = true;
+                regex_string.push('[');
+            }
+            ']' if is_in_class => {
+                is_in_class = false;
+                regex_string.push(']');
+            }
+            '*' if !is_in_class => regex_string.push_str(".*"),
+            '?' if !is_in_class => regex_string.push('.'),
+            '[' | '\\' => {
+                regex_string.push('\\');
+                regex_string.push(c);
+            }
+            _ if is_in_class => regex_string.push(c),
+            _ => regex_string.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex_string.push('$');
+
+    if glob.is_empty() || is_in_class {
+        return Err(BottomError::ConfigError(format!(
+            "\"{}\" is an invalid glob.",
+            glob
+        )));
+    }
+
+    Regex::new(&regex_string)
+        .map_err(|_| BottomError::ConfigError(format!("\"{}\" is an invalid glob.", glob)))
+}
+
 pub fn get_ignore_list(ignore_list: &Option<IgnoreList>) -> error::Result<Option<Filter>> {
     if let Some(ignore_list) = ignore_list {
         let list: Result<Vec<_>, _> = ignore_list
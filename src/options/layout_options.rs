@@ -1,5 +1,5 @@
 use crate::app::layout_manager::*;
-use crate::error::Result;
+use crate::error::{BottomError, Result};
 use serde::Deserialize;
 
 /// Represents a row.  This has a length of some sort (optional) and a vector
@@ -19,7 +19,7 @@ impl Row {
     ) -> Result<BottomRow> {
         // TODO: In the future we want to also add percentages.
         // But for MVP, we aren't going to bother.
-        let row_ratio = self.ratio.unwrap_or(1);
+        let row_ratio = get_ratio(self.ratio)?;
         let mut children = Vec::new();
 
         *total_height_ratio += row_ratio;
@@ -30,9 +30,11 @@ impl Row {
                 match row_child {
                     RowChildren::Widget(widget) => {
                         *iter_id += 1;
-                        let width_ratio = widget.ratio.unwrap_or(1);
+                        let width_ratio = get_ratio(widget.ratio)?;
                         total_col_ratio += width_ratio;
                         let widget_type = widget.widget_type.parse::<BottomWidgetType>()?;
+                        let mem_style = get_mem_style(&widget.style)?;
+                        let is_y_inverted = widget.invert_y.unwrap_or(false);
 
                         if let Some(default_widget_type_val) = default_widget_type {
                             if *default_widget_type_val == widget_type && *default_widget_count > 0
@@ -75,6 +77,7 @@ impl Row {
                                                     .width_ratio(17)
                                                     .widget_type(BottomWidgetType::Cpu)
                                                     .widget_id(cpu_id)
+                                                    .is_y_inverted(is_y_inverted)
                                                     .flex_grow(true)
                                                     .build(),
                                             ])
@@ -87,6 +90,7 @@ impl Row {
                                                     .width_ratio(17)
                                                     .widget_type(BottomWidgetType::Cpu)
                                                     .widget_id(cpu_id)
+                                                    .is_y_inverted(is_y_inverted)
                                                     .flex_grow(true)
                                                     .build(),
                                                 BottomWidget::builder()
@@ -128,6 +132,7 @@ impl Row {
                                                     .widget_type(BottomWidgetType::Proc)
                                                     .widget_id(proc_id)
                                                     .width_ratio(2)
+                                                    .is_grouped(widget.grouped)
                                                     .build(),
                                             ])
                                             .total_widget_ratio(3)
@@ -150,13 +155,15 @@ impl Row {
                                     .children(vec![BottomWidget::builder()
                                         .widget_type(widget_type)
                                         .widget_id(*iter_id)
+                                        .mem_style(mem_style)
+                                        .is_y_inverted(is_y_inverted)
                                         .build()])
                                     .build()])
                                 .build(),
                         });
                     }
                     RowChildren::Col { ratio, child } => {
-                        let col_width_ratio = ratio.unwrap_or(1);
+                        let col_width_ratio = get_ratio(*ratio)?;
                         total_col_ratio += col_width_ratio;
                         let mut total_col_row_ratio = 0;
                         let mut contains_proc = false;
@@ -166,8 +173,10 @@ impl Row {
                         for widget in child {
                             let widget_type = widget.widget_type.parse::<BottomWidgetType>()?;
                             *iter_id += 1;
-                            let col_row_height_ratio = widget.ratio.unwrap_or(1);
+                            let col_row_height_ratio = get_ratio(widget.ratio)?;
                             total_col_row_ratio += col_row_height_ratio;
+                            let mem_style = get_mem_style(&widget.style)?;
+                            let is_y_inverted = widget.invert_y.unwrap_or(false);
 
                             if let Some(default_widget_type_val) = default_widget_type {
                                 if *default_widget_type_val == widget_type
@@ -211,6 +220,7 @@ impl Row {
                                                         .width_ratio(17)
                                                         .widget_type(BottomWidgetType::Cpu)
                                                         .widget_id(cpu_id)
+                                                        .is_y_inverted(is_y_inverted)
                                                         .flex_grow(true)
                                                         .build(),
                                                 ])
@@ -226,6 +236,7 @@ impl Row {
                                                         .width_ratio(17)
                                                         .widget_type(BottomWidgetType::Cpu)
                                                         .widget_id(cpu_id)
+                                                        .is_y_inverted(is_y_inverted)
                                                         .flex_grow(true)
                                                         .build(),
                                                     BottomWidget::builder()
@@ -265,6 +276,7 @@ impl Row {
                                                     .widget_type(BottomWidgetType::Proc)
                                                     .widget_id(proc_id)
                                                     .width_ratio(2)
+                                                    .is_grouped(widget.grouped)
                                                     .build(),
                                             ])
                                             .col_row_height_ratio(col_row_height_ratio)
@@ -289,6 +301,8 @@ impl Row {
                                         .children(vec![BottomWidget::builder()
                                             .widget_type(widget_type)
                                             .widget_id(*iter_id)
+                                            .mem_style(mem_style)
+                                            .is_y_inverted(is_y_inverted)
                                             .build()])
                                         .build(),
                                 ),
@@ -329,6 +343,26 @@ impl Row {
     }
 }
 
+/// Returns the given layout ratio, or 1 if it isn't set.  A ratio of 0 would leave the
+/// component without any space, so it is rejected.
+fn get_ratio(ratio: Option<u32>) -> Result<u32> {
+    match ratio {
+        Some(0) => Err(BottomError::ConfigError(
+            "set your layout ratios to be at least 1.".to_string(),
+        )),
+        Some(ratio) => Ok(ratio),
+        None => Ok(1),
+    }
+}
+
+/// Returns the given memory style, or a graph if it isn't set.
+fn get_mem_style(style: &Option<String>) -> Result<MemStyle> {
+    match style {
+        Some(style) => style.parse::<MemStyle>(),
+        None => Ok(MemStyle::Graph),
+    }
+}
+
 /// Represents a child of a Row - either a Col (column) or a FinalWidget.
 ///
 /// A Col can also have an optional length and children.  We only allow columns
@@ -351,4 +385,10 @@ pub struct FinalWidget {
     #[serde(rename = "type")]
     pub widget_type: String,
     pub default: Option<bool>,
+    /// Only used by process widgets.
+    pub grouped: Option<bool>,
+    /// Only used by memory widgets, either "graph" or "gauge".
+    pub style: Option<String>,
+    /// Only used by CPU, memory, and network widgets, to draw graphs filling from the top.
+    pub invert_y: Option<bool>,
 }
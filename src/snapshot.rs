@@ -0,0 +1,331 @@
+//! A point-in-time copy of the latest harvested data, independent of any
+//! widget or canvas state, that can be written out as JSON.  The retained history of a graph
+//! can also be exported as CSV.
+
+use serde::Serialize;
+
+use crate::{
+    app::{data_farmer::DataCollection, layout_manager::BottomWidgetType},
+    utils::{
+        error::{self, BottomError},
+        gen_util::{get_simple_byte_values, ByteFormat},
+    },
+};
+
+/// How many processes are listed in the text summary.
+const TEXT_TOP_PROCESS_COUNT: usize = 5;
+
+#[derive(Default, Debug, Serialize)]
+pub struct CpuSnapshot {
+    pub name: String,
+    pub usage_percent: f64,
+}
+
+#[derive(Default, Debug, Serialize)]
+pub struct MemSnapshot {
+    pub used_mb: u64,
+    pub total_mb: u64,
+}
+
+#[derive(Default, Debug, Serialize)]
+pub struct NetworkSnapshot {
+    pub rx_bytes_per_sec: u64,
+    pub tx_bytes_per_sec: u64,
+    pub total_rx_bytes: u64,
+    pub total_tx_bytes: u64,
+}
+
+#[derive(Default, Debug, Serialize)]
+pub struct DiskSnapshot {
+    pub name: String,
+    pub mount_point: String,
+    pub used_bytes: u64,
+    pub total_bytes: u64,
+}
+
+#[derive(Default, Debug, Serialize)]
+pub struct TempSnapshot {
+    pub name: String,
+    pub temperature: f32,
+}
+
+#[derive(Default, Debug, Serialize)]
+pub struct BatterySnapshot {
+    pub name: String,
+    pub state: String,
+    pub charge_percent: f64,
+}
+
+#[derive(Default, Debug, Serialize)]
+pub struct ProcessSnapshot {
+    pub pid: crate::Pid,
+    pub name: String,
+    pub cpu_usage_percent: f64,
+    pub mem_usage_percent: f64,
+}
+
+#[derive(Default, Debug, Serialize)]
+pub struct Snapshot {
+    /// The time the snapshot was taken, in RFC 3339 format unless another format is given.
+    pub timestamp: String,
+    pub cpu: Vec<CpuSnapshot>,
+    pub memory: MemSnapshot,
+    pub swap: MemSnapshot,
+    pub network: NetworkSnapshot,
+    pub disks: Vec<DiskSnapshot>,
+    pub temperatures: Vec<TempSnapshot>,
+    pub batteries: Vec<BatterySnapshot>,
+    pub processes: Vec<ProcessSnapshot>,
+}
+
+impl Snapshot {
+    /// Takes a snapshot, with its timestamp in the given strftime-style format if there is one.
+    /// The format must already be checked to be valid, as invalid formats panic.
+    pub fn from_data_collection(
+        data_collection: &DataCollection, time_format: Option<&str>,
+    ) -> Self {
+        let now = chrono::Local::now();
+
+        Snapshot {
+            timestamp: match time_format {
+                Some(time_format) => now.format(time_format).to_string(),
+                None => now.to_rfc3339(),
+            },
+            cpu: data_collection
+                .cpu_harvest
+                .iter()
+                .map(|cpu| CpuSnapshot {
+                    name: cpu.cpu_name.clone(),
+                    usage_percent: cpu.cpu_usage,
+                })
+                .collect(),
+            memory: MemSnapshot {
+                used_mb: data_collection.memory_harvest.mem_used_in_mb,
+                total_mb: data_collection.memory_harvest.mem_total_in_mb,
+            },
+            swap: MemSnapshot {
+                used_mb: data_collection.swap_harvest.mem_used_in_mb,
+                total_mb: data_collection.swap_harvest.mem_total_in_mb,
+            },
+            network: NetworkSnapshot {
+                rx_bytes_per_sec: data_collection.network_harvest.rx,
+                tx_bytes_per_sec: data_collection.network_harvest.tx,
+                total_rx_bytes: data_collection.network_harvest.total_rx,
+                total_tx_bytes: data_collection.network_harvest.total_tx,
+            },
+            disks: data_collection
+                .disk_harvest
+                .iter()
+                .map(|disk| DiskSnapshot {
+                    name: disk.name.clone(),
+                    mount_point: disk.mount_point.clone(),
+                    used_bytes: disk.used_space,
+                    total_bytes: disk.total_space,
+                })
+                .collect(),
+            temperatures: data_collection
+                .temp_harvest
+                .iter()
+                .map(|sensor| TempSnapshot {
+                    name: match (&sensor.component_name, &sensor.component_label) {
+                        (Some(name), Some(label)) => format!("{}: {}", name, label),
+                        (Some(name), None) => name.clone(),
+                        (None, Some(label)) => label.clone(),
+                        (None, None) => String::default(),
+                    },
+                    temperature: sensor.temperature,
+                })
+                .collect(),
+            batteries: data_collection
+                .battery_harvest
+                .iter()
+                .map(|battery| BatterySnapshot {
+                    name: battery.name.clone(),
+                    state: battery.state.to_string(),
+                    charge_percent: battery.charge_percent,
+                })
+                .collect(),
+            processes: data_collection
+                .process_harvest
+                .iter()
+                .map(|process| ProcessSnapshot {
+                    pid: process.pid,
+                    name: process.name.clone(),
+                    cpu_usage_percent: process.cpu_usage_percent,
+                    mem_usage_percent: process.mem_usage_percent,
+                })
+                .collect(),
+        }
+    }
+
+    /// Formats the snapshot as a compact, human-readable summary.
+    pub fn to_text(&self, byte_format: &ByteFormat) -> String {
+        let mut lines = vec![format!("System snapshot at {}", self.timestamp)];
+
+        if !self.cpu.is_empty() {
+            lines.push(format!(
+                "CPU:      {}",
+                self.cpu
+                    .iter()
+                    .map(|cpu| format!("{} {:.0}%", cpu.name, cpu.usage_percent))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+
+        lines.push(format!("Memory:   {}", mem_to_text(&self.memory)));
+        lines.push(format!("Swap:     {}", mem_to_text(&self.swap)));
+
+        let rx = get_simple_byte_values(self.network.rx_bytes_per_sec, false, byte_format);
+        let tx = get_simple_byte_values(self.network.tx_bytes_per_sec, false, byte_format);
+        lines.push(format!(
+            "Network:  RX {:.1}{}/s, TX {:.1}{}/s",
+            rx.0, rx.1, tx.0, tx.1
+        ));
+
+        for disk in &self.disks {
+            let used = get_simple_byte_values(disk.used_bytes, false, byte_format);
+            let total = get_simple_byte_values(disk.total_bytes, false, byte_format);
+            lines.push(format!(
+                "Disk:     {} ({}) {:.1}{} / {:.1}{}",
+                disk.mount_point, disk.name, used.0, used.1, total.0, total.1
+            ));
+        }
+
+        for sensor in &self.temperatures {
+            lines.push(format!(
+                "Temp:     {} {:.1}°",
+                sensor.name, sensor.temperature
+            ));
+        }
+
+        for battery in &self.batteries {
+            lines.push(format!(
+                "Battery:  {} {:.0}% ({})",
+                battery.name, battery.charge_percent, battery.state
+            ));
+        }
+
+        if !self.processes.is_empty() {
+            let mut top_processes = self.processes.iter().collect::<Vec<_>>();
+            top_processes.sort_by(|a, b| {
+                b.cpu_usage_percent
+                    .partial_cmp(&a.cpu_usage_percent)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            lines.push(format!(
+                "Top CPU:  {}",
+                top_processes
+                    .iter()
+                    .take(TEXT_TOP_PROCESS_COUNT)
+                    .map(|process| format!(
+                        "{} ({}) {:.1}%",
+                        process.name, process.pid, process.cpu_usage_percent
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+
+        lines.join("\n")
+    }
+
+    /// Serializes the snapshot as a single line of JSON.  NaN and infinite numbers, which
+    /// JSON can't represent, are written as null.
+    pub fn to_json(&self) -> error::Result<String> {
+        serde_json::to_string(self).map_err(|err| BottomError::ConversionError(err.to_string()))
+    }
+}
+
+/// Exports the retained history of a CPU, memory, or network graph as CSV, with a timestamp
+/// column and a column for each series.  Series that started partway through, such as CPUs
+/// that came online or the memory cache on some systems, have empty cells before they start.
+/// Returns [`None`] for widgets without a graph.
+pub fn export_graph_csv(
+    data_collection: &DataCollection, widget_type: &BottomWidgetType, time_format: Option<&str>,
+) -> Option<String> {
+    let series_names = match widget_type {
+        BottomWidgetType::Cpu => data_collection
+            .cpu_harvest
+            .iter()
+            .map(|cpu| format!("{} (%)", cpu.cpu_name))
+            .collect::<Vec<_>>(),
+        BottomWidgetType::Mem => ["Memory (%)", "Buffers (%)", "Cached (%)", "Swap (%)"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect(),
+        BottomWidgetType::Net => ["RX (B/s)", "TX (B/s)"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect(),
+        _ => return None,
+    };
+
+    let mut lines = vec![std::iter::once("Time")
+        .chain(series_names.iter().map(String::as_str))
+        .map(csv_field)
+        .collect::<Vec<_>>()
+        .join(",")];
+
+    // Entries only have monotonic instants, so their wall clock times are worked out from how
+    // long before the latest entry they were harvested.
+    let now = chrono::Local::now();
+    for (instant, data) in &data_collection.timed_data_vec {
+        let time = now
+            - chrono::Duration::from_std(data_collection.current_instant.duration_since(*instant))
+                .unwrap_or_else(|_| chrono::Duration::zero());
+        let values: Vec<Option<f64>> = match widget_type {
+            BottomWidgetType::Cpu => (0..series_names.len())
+                .map(|index| data.cpu_data.get(index).copied())
+                .collect(),
+            BottomWidgetType::Mem => vec![
+                Some(data.mem_data),
+                data.mem_buffers_data,
+                data.mem_cached_data,
+                Some(data.swap_data),
+            ],
+            // The rates are kept on a log scale for the graph.
+            _ => [data.rx_data, data.tx_data]
+                .iter()
+                .map(|value| {
+                    Some(if *value > 0.0 {
+                        value.exp2().round()
+                    } else {
+                        0.0
+                    })
+                })
+                .collect(),
+        };
+
+        let mut fields = vec![csv_field(&match time_format {
+            Some(time_format) => time.format(time_format).to_string(),
+            None => time.to_rfc3339(),
+        })];
+        fields.extend(values.into_iter().map(|value| {
+            value
+                .filter(|value| value.is_finite())
+                .map_or_else(String::default, |value| value.to_string())
+        }));
+        lines.push(fields.join(","));
+    }
+
+    Some(lines.join("\n"))
+}
+
+/// Quotes a CSV field if it contains anything that would otherwise split or end it.
+fn csv_field(value: &str) -> String {
+    if value.contains(|c| c == ',' || c == '"' || c == '\n' || c == '\r') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn mem_to_text(mem: &MemSnapshot) -> String {
+    let percent = if mem.total_mb == 0 {
+        0.0
+    } else {
+        mem.used_mb as f64 / mem.total_mb as f64 * 100.0
+    };
+    format!("{} / {} MiB ({:.1}%)", mem.used_mb, mem.total_mb, percent)
+}
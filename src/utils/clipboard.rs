@@ -0,0 +1,61 @@
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+use crate::utils::error::{self, BottomError};
+
+#[cfg(target_os = "macos")]
+const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[("pbcopy", &[])];
+
+#[cfg(target_os = "windows")]
+const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[("clip", &[])];
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[
+    ("wl-copy", &[]),
+    ("xclip", &["-selection", "clipboard"]),
+    ("xsel", &["--clipboard", "--input"]),
+];
+
+/// Copies the text to the system clipboard using the first clipboard tool that works.
+/// Fails if there is no usable clipboard, such as when running over SSH.
+pub fn copy_to_clipboard(text: &str) -> error::Result<()> {
+    // Any clipboard we could reach over SSH would be the remote machine's, not the user's.
+    if std::env::var_os("SSH_CONNECTION").is_some() || std::env::var_os("SSH_TTY").is_some() {
+        return Err(BottomError::GenericError(
+            "no clipboard is available over SSH".to_string(),
+        ));
+    }
+
+    for (program, args) in CLIPBOARD_COMMANDS {
+        if let Ok(mut child) = Command::new(program)
+            .args(*args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        {
+            // The stdin handle must be dropped before waiting so the tool sees the end of input.
+            if let Some(mut stdin) = child.stdin.take() {
+                if stdin.write_all(text.as_bytes()).is_err() {
+                    // The tool still has to be reaped, or it would be left as a zombie.
+                    drop(stdin);
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    continue;
+                }
+            }
+
+            if let Ok(status) = child.wait() {
+                if status.success() {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    Err(BottomError::GenericError(
+        "no clipboard tool is available".to_string(),
+    ))
+}
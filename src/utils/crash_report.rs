@@ -0,0 +1,79 @@
+//! Crash reports, which are written when bottom panics if enabled so that they can be attached
+//! to issues.  Everything but the panic itself is gathered on startup, so that the panic hook
+//! only has to write out what it already has.
+
+use std::{
+    fs::{self, File},
+    io::{self, Write},
+    panic::Location,
+    path::{Path, PathBuf},
+};
+
+use crate::constants::DEFAULT_CRASH_REPORT_PATH;
+
+pub struct CrashReport {
+    path: PathBuf,
+    /// The version, OS, arguments, and active config, which are written before the panic.
+    header: String,
+}
+
+impl CrashReport {
+    /// Returns [`None`] if there is no data directory to write the report to.
+    pub fn new(config_path: &Option<PathBuf>) -> Option<Self> {
+        let path = dirs::data_dir()?.join(DEFAULT_CRASH_REPORT_PATH);
+
+        let mut header = format!(
+            "bottom crash report\n\nVersion: {}\nOS: {} ({})\nArguments: {}\n",
+            env!("CARGO_PKG_VERSION"),
+            std::env::consts::OS,
+            std::env::consts::ARCH,
+            std::env::args().skip(1).collect::<Vec<_>>().join(" ")
+        );
+
+        // Only the lines of the config file that aren't comments are set, so only those are
+        // included.
+        match config_path {
+            Some(config_path) => {
+                header.push_str(&format!("Config file: {}\n", config_path.display()));
+                if let Ok(config) = fs::read_to_string(config_path) {
+                    for line in config.lines().map(str::trim) {
+                        if !line.is_empty() && !line.starts_with('#') {
+                            header.push_str(&format!("    {}\n", line));
+                        }
+                    }
+                }
+            }
+            None => header.push_str("Config file: none\n"),
+        }
+
+        Some(CrashReport { path, header })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Writes the report, replacing any earlier one.  This is called from the panic hook, so the
+    /// panic's details are written as they are rather than being gathered into a new string.
+    pub fn write(
+        &self, message: &str, location: Option<&Location<'_>>, stacktrace: &str,
+    ) -> io::Result<()> {
+        if let Some(parent_path) = self.path.parent() {
+            fs::create_dir_all(parent_path)?;
+        }
+
+        let mut file = File::create(&self.path)?;
+        file.write_all(self.header.as_bytes())?;
+        writeln!(
+            file,
+            "Time: {}\n",
+            chrono::Local::now().format("%Y-%m-%d %H:%M:%S %z")
+        )?;
+        match location {
+            Some(location) => writeln!(file, "Panicked at '{}', {}\n", message, location)?,
+            None => writeln!(file, "Panicked at '{}'\n", message)?,
+        }
+        file.write_all(stacktrace.as_bytes())?;
+        file.flush()
+    }
+}
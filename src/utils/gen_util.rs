@@ -29,6 +29,48 @@ pub const LOG_MEBI_LIMIT_U32: u32 = 20;
 pub const LOG_GIBI_LIMIT_U32: u32 = 30;
 pub const LOG_TEBI_LIMIT_U32: u32 = 40;
 
+/// How byte values are scaled and rounded by [`get_exact_byte_values`] and
+/// [`get_simple_byte_values`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ByteFormat {
+    /// How many of a unit a value must reach before it is shown in that unit.  If unset, a
+    /// value is shown in a unit once it reaches one of it, except that the largest unit is only
+    /// used from a trillion bytes on, even in units of 1024.
+    pub unit_threshold: Option<f64>,
+    /// How many significant figures values are rounded to, if they are rounded.
+    pub significant_figures: Option<usize>,
+}
+
+/// How many times a byte value can be divided into a larger unit, up to tera or tebibytes.
+const LARGEST_BYTE_POWER: i32 = 4;
+
+/// Divides `bytes` by `base` until the value is below the limit of the next unit, or the
+/// largest unit is reached, and returns the value with how many times it was divided.
+fn scale_bytes(bytes: u64, base: f64, byte_format: &ByteFormat) -> (f64, usize) {
+    let mut value = bytes as f64;
+    let mut power = 0;
+    while power < LARGEST_BYTE_POWER {
+        let next_unit_limit = match byte_format.unit_threshold {
+            Some(unit_threshold) => base * unit_threshold,
+            None if power == LARGEST_BYTE_POWER - 1 => TERA_LIMIT as f64 / base.powi(power),
+            None => base,
+        };
+        if value < next_unit_limit {
+            break;
+        }
+        value /= base;
+        power += 1;
+    }
+
+    match byte_format.significant_figures {
+        Some(significant_figures) if value != 0.0 => {
+            let factor = 10_f64.powi(significant_figures as i32 - 1 - value.log10().floor() as i32);
+            ((value * factor).round() / factor, power as usize)
+        }
+        _ => (value, power as usize),
+    }
+}
+
 pub fn float_min(a: f32, b: f32) -> f32 {
     match a.partial_cmp(&b) {
         Some(x) => match x {
@@ -53,40 +95,34 @@ pub fn float_max(a: f32, b: f32) -> f32 {
 
 /// Returns a tuple containing the value and the unit.  In units of 1024.
 /// This only supports up to a tebibyte.
-pub fn get_exact_byte_values(bytes: u64, spacing: bool) -> (f64, String) {
-    match bytes {
-        b if b < KIBI_LIMIT => (
-            bytes as f64,
-            if spacing {
-                "  B".to_string()
-            } else {
-                "B".to_string()
-            },
-        ),
-        b if b < MEBI_LIMIT => (bytes as f64 / 1024.0, "KiB".to_string()),
-        b if b < GIBI_LIMIT => (bytes as f64 / 1_048_576.0, "MiB".to_string()),
-        b if b < TERA_LIMIT => (bytes as f64 / 1_073_741_824.0, "GiB".to_string()),
-        _ => (bytes as f64 / 1_099_511_627_776.0, "TiB".to_string()),
-    }
+pub fn get_exact_byte_values(bytes: u64, spacing: bool, byte_format: &ByteFormat) -> (f64, String) {
+    let (value, power) = scale_bytes(bytes, 1024.0, byte_format);
+    let unit = match power {
+        0 if spacing => "  B",
+        0 => "B",
+        1 => "KiB",
+        2 => "MiB",
+        3 => "GiB",
+        _ => "TiB",
+    };
+    (value, unit.to_string())
 }
 
 /// Returns a tuple containing the value and the unit.  In units of 1000.
 /// This only supports up to a terabyte.  Note the "byte" unit will have a space appended to match the others.
-pub fn get_simple_byte_values(bytes: u64, spacing: bool) -> (f64, String) {
-    match bytes {
-        b if b < KILO_LIMIT => (
-            bytes as f64,
-            if spacing {
-                " B".to_string()
-            } else {
-                "B".to_string()
-            },
-        ),
-        b if b < MEGA_LIMIT => (bytes as f64 / 1000.0, "KB".to_string()),
-        b if b < GIGA_LIMIT => (bytes as f64 / 1_000_000.0, "MB".to_string()),
-        b if b < TERA_LIMIT => (bytes as f64 / 1_000_000_000.0, "GB".to_string()),
-        _ => (bytes as f64 / 1_000_000_000_000.0, "TB".to_string()),
-    }
+pub fn get_simple_byte_values(
+    bytes: u64, spacing: bool, byte_format: &ByteFormat,
+) -> (f64, String) {
+    let (value, power) = scale_bytes(bytes, 1000.0, byte_format);
+    let unit = match power {
+        0 if spacing => " B",
+        0 => "B",
+        1 => "KB",
+        2 => "MB",
+        3 => "GB",
+        _ => "TB",
+    };
+    (value, unit.to_string())
 }
 
 /// Gotta get partial ordering?  No problem, here's something to deal with it~
@@ -114,3 +150,30 @@ pub fn get_ordering<T: std::cmp::PartialOrd>(
         None => Ordering::Equal,
     }
 }
+
+/// Lays out the headers and rows as a plain text table, with each column padded to the width
+/// of its widest cell.
+pub fn get_plain_text_table(headers: &[String], rows: &[Vec<String>]) -> String {
+    let mut column_widths = headers
+        .iter()
+        .map(|header| header.chars().count())
+        .collect::<Vec<_>>();
+    for row in rows {
+        for (column_width, cell) in column_widths.iter_mut().zip(row) {
+            *column_width = std::cmp::max(*column_width, cell.chars().count());
+        }
+    }
+
+    std::iter::once(headers)
+        .chain(rows.iter().map(|row| &row[..]))
+        .map(|cells| {
+            let line = cells
+                .iter()
+                .zip(&column_widths)
+                .map(|(cell, column_width)| format!("{:<width$}", cell, width = column_width))
+                .collect::<Vec<_>>()
+                .join("  ");
+            format!("{}\n", line.trim_end())
+        })
+        .collect()
+}
@@ -0,0 +1,38 @@
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+use crate::utils::error::{self, BottomError};
+
+#[cfg(target_os = "windows")]
+const DEFAULT_PAGER: &str = "more";
+
+#[cfg(not(target_os = "windows"))]
+const DEFAULT_PAGER: &str = "less";
+
+/// Shows the text in the pager set by `$PAGER`, or in `less` (`more` on Windows) if it is
+/// unset, and waits for the pager to exit.  The terminal must already be out of raw mode.
+pub fn show_in_pager(text: &str) -> error::Result<()> {
+    let pager = std::env::var("PAGER")
+        .ok()
+        .filter(|pager| !pager.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_PAGER.to_string());
+
+    // `$PAGER` may include arguments, such as `less -S`.
+    let mut pager_parts = pager.split_whitespace();
+    let program = pager_parts.next().unwrap_or(DEFAULT_PAGER);
+    let mut child = Command::new(program)
+        .args(pager_parts)
+        .stdin(Stdio::piped())
+        .spawn()
+        .map_err(|err| BottomError::GenericError(format!("unable to run {}: {}", program, err)))?;
+
+    // The pager may be quit before it has read everything, which isn't an error.
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(text.as_bytes());
+    }
+
+    child.wait()?;
+    Ok(())
+}
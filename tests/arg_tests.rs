@@ -157,6 +157,20 @@ fn test_invalid_default_widget_2() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn test_zero_duration() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("--duration")
+        .arg("0")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "set your duration to be at least 1 second.",
+        ));
+
+    Ok(())
+}
+
 #[test]
 fn test_missing_default_widget_type() -> Result<(), Box<dyn std::error::Error>> {
     Command::new(get_binary_location())
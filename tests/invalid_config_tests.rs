@@ -30,6 +30,19 @@ fn test_empty_layout() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn test_invalid_layout_ratio() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_layout_ratio.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "set your layout ratios to be at least 1.",
+        ));
+    Ok(())
+}
+
 #[test]
 fn test_invalid_layout_widget_type() -> Result<(), Box<dyn std::error::Error>> {
     Command::new(get_binary_location())
@@ -169,3 +182,318 @@ fn test_invalid_default_widget_count() -> Result<(), Box<dyn std::error::Error>>
         .stderr(predicate::str::contains("invalid number"));
     Ok(())
 }
+
+#[test]
+fn test_invalid_default_widget() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_default_widget.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid widget name"));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_command_display() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_command_display.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is an invalid command display"));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_selection_style() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_selection_style.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is an invalid selection style"));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_quit_confirmation() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_quit_confirmation.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is an invalid quit confirmation"));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_disk_mount_threshold() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_disk_mount_threshold.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "disk warning threshold for \"/boot\" to be between 0 and 100",
+        ));
+    Ok(())
+}
+
+#[test]
+fn test_nan_mem_threshold() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/nan_mem_threshold.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "set your memory warning threshold to be between 0 and 100.",
+        ));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_disk_columns() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_disk_columns.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("set your disk columns to only include"));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_percent_precision() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_percent_precision.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "set your percent precision to be between 0 and 3",
+        ));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_rate_smoothing_alpha() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_rate_smoothing_alpha.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "set your rate smoothing alpha to be greater than 0 and at most 1.",
+        ));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_byte_unit_threshold() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_byte_unit_threshold.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "set your byte unit threshold to be greater than 0.",
+        ));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_byte_significant_figures() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_byte_significant_figures.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "set your byte significant figures to be at least 1.",
+        ));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_color_depth() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_color_depth.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid colour depth"));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_preset() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_preset.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is an invalid preset"));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_temperature_type() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_temperature_type.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is an invalid temperature type"));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_threshold_percent() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_threshold_percent.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("must be between 0 and 100"));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_hide_mounts() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_hide_mounts.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is an invalid glob"));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_secondary_sort() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_secondary_sort.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is an invalid secondary sort key"));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_state_counts() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_state_counts.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is an invalid state count scope"));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_max_data_points() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_max_data_points.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "set your max data points to be at least 1.",
+        ));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_mem_style() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_mem_style.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is an invalid memory style"));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_time_format() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_time_format.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is an invalid time format"));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_graph_marker() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_graph_marker.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is an invalid graph marker"));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_scroll_lines_per_tick() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_scroll_lines_per_tick.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "set your scroll lines per tick to be at least 1.",
+        ));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_mem_pressure_weight() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_mem_pressure_weight.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "set your memory pressure weights to be at least 0.",
+        ));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_rate_ramp_ticks() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_rate_ramp_ticks.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("set your rate ramp ticks to be at least 1."));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_state_color() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_state_color.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("must be a single state code"));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_refresh_key() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_refresh_key.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("set your refresh key to a single character"));
+    Ok(())
+}
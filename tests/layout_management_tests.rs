@@ -1,7 +1,10 @@
 //! Mocks layout management, so we can check if we broke anything.
 
 use bottom::app::layout_manager::{BottomLayout, BottomWidgetType};
-use bottom::constants::{DEFAULT_BATTERY_LAYOUT, DEFAULT_LAYOUT, DEFAULT_WIDGET_ID};
+use bottom::constants::{
+    DEFAULT_BATTERY_LAYOUT, DEFAULT_LAYOUT, DEFAULT_WIDGET_ID, GRAPHS_ONLY_LAYOUT, MINIMAL_LAYOUT,
+    PROC_FOCUSED_LAYOUT,
+};
 use bottom::options::{layout_options::Row, Config};
 use bottom::utils::error;
 
@@ -467,3 +470,57 @@ fn test_proc_custom_layout() {
         Some(13)
     );
 }
+
+#[test]
+/// Tests that the grouped setting is carried over to the process widget itself.
+fn test_grouped_proc_in_layout() {
+    let proc_layout = r##"
+    [[row]]
+        [[row.child]]
+            type="proc"
+            grouped=true
+        [[row.child]]
+            type="proc"
+    "##;
+    let rows = toml::from_str::<Config>(proc_layout).unwrap().row.unwrap();
+    let ret_bottom_layout = test_create_layout(&rows, DEFAULT_WIDGET_ID, None, 1, false);
+
+    assert_eq!(
+        ret_bottom_layout.rows[0].children[0].children[0].children[1].is_grouped,
+        Some(true)
+    );
+    assert_eq!(
+        ret_bottom_layout.rows[0].children[1].children[0].children[1].is_grouped,
+        None
+    );
+}
+
+#[test]
+/// Tests that the layout presets are valid and move between their widgets.
+fn test_layout_presets() {
+    let rows = toml::from_str::<Config>(PROC_FOCUSED_LAYOUT)
+        .unwrap()
+        .row
+        .unwrap();
+    let ret_bottom_layout = test_create_layout(&rows, DEFAULT_WIDGET_ID, None, 1, false);
+
+    // The CPU legend sits between the CPU and memory widgets
+    assert_eq!(
+        ret_bottom_layout.rows[0].children[0].children[0].children[1].right_neighbour,
+        Some(3)
+    );
+    assert_eq!(
+        ret_bottom_layout.rows[0].children[1].children[0].children[0].widget_type,
+        BottomWidgetType::Mem
+    );
+    assert_eq!(
+        ret_bottom_layout.rows[1].children[0].children[0].children[1].widget_type,
+        BottomWidgetType::Proc
+    );
+
+    for preset in &[GRAPHS_ONLY_LAYOUT, MINIMAL_LAYOUT] {
+        let rows = toml::from_str::<Config>(preset).unwrap().row.unwrap();
+        let ret_bottom_layout = test_create_layout(&rows, DEFAULT_WIDGET_ID, None, 1, false);
+        assert_eq!(ret_bottom_layout.rows.len(), 2);
+    }
+}
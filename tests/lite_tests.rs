@@ -0,0 +1,42 @@
+//! Checks that lite mode turns off the costlier collection, while still letting the config file
+//! turn it back on.
+
+use bottom::options::{apply_lite_defaults, Config, ConfigFlags, ConfigProcesses};
+
+#[test]
+fn test_lite_disables_costly_collection() {
+    let mut config = Config::default();
+    apply_lite_defaults(&mut config);
+
+    let flags = config.flags.as_ref().unwrap();
+    assert_eq!(flags.disable_process_io, Some(true));
+    assert_eq!(flags.disable_cpu_breakdown, Some(true));
+
+    let processes = config.processes.as_ref().unwrap();
+    assert_eq!(processes.show_swap, Some(false));
+    assert_eq!(processes.show_oom_score, Some(false));
+    assert_eq!(processes.show_affinity, Some(false));
+    assert_eq!(processes.only_own, Some(false));
+}
+
+#[test]
+fn test_lite_keeps_config_values() {
+    let mut config = Config {
+        flags: Some(ConfigFlags {
+            disable_cpu_breakdown: Some(false),
+            ..ConfigFlags::default()
+        }),
+        processes: Some(ConfigProcesses {
+            show_swap: Some(true),
+            ..ConfigProcesses::default()
+        }),
+        ..Config::default()
+    };
+    apply_lite_defaults(&mut config);
+
+    assert_eq!(
+        config.flags.as_ref().unwrap().disable_cpu_breakdown,
+        Some(false)
+    );
+    assert_eq!(config.processes.as_ref().unwrap().show_swap, Some(true));
+}
@@ -0,0 +1,115 @@
+//! Checks how processes are sorted, and in particular how ties are broken.
+
+use bottom::app::data_harvester::processes::ProcessSorting;
+use bottom::data_conversion::ConvertedProcessData;
+use bottom::{sort_process_data, Pid};
+
+fn process(
+    pid: Pid, name: &str, cpu_percent_usage: f64, mem_usage_bytes: u64,
+) -> ConvertedProcessData {
+    ConvertedProcessData {
+        pid,
+        name: name.to_string(),
+        command: name.to_string(),
+        cpu_percent_usage,
+        mem_usage_bytes,
+        ..ConvertedProcessData::default()
+    }
+}
+
+fn get_pids(processes: &[ConvertedProcessData]) -> Vec<Pid> {
+    processes.iter().map(|process| process.pid).collect()
+}
+
+#[test]
+fn test_equal_cpu_ties_broken_by_name() {
+    let mut processes = vec![
+        process(1, "zsh", 10.0, 0),
+        process(2, "Bash", 10.0, 0),
+        process(3, "cargo", 50.0, 0),
+        process(4, "alacritty", 10.0, 0),
+    ];
+    sort_process_data(
+        &mut processes,
+        &ProcessSorting::CpuPercent,
+        true,
+        false,
+        &None,
+    );
+
+    assert_eq!(get_pids(&processes), vec![3, 4, 2, 1]);
+}
+
+#[test]
+fn test_equal_cpu_and_name_ties_broken_by_pid() {
+    let mut processes = vec![
+        process(30, "worker", 10.0, 0),
+        process(10, "worker", 10.0, 0),
+        process(20, "Worker", 10.0, 0),
+    ];
+    sort_process_data(
+        &mut processes,
+        &ProcessSorting::CpuPercent,
+        true,
+        false,
+        &None,
+    );
+
+    assert_eq!(get_pids(&processes), vec![10, 20, 30]);
+
+    // Inverting the sort doesn't change the order of processes that tie.
+    sort_process_data(
+        &mut processes,
+        &ProcessSorting::CpuPercent,
+        false,
+        false,
+        &None,
+    );
+
+    assert_eq!(get_pids(&processes), vec![10, 20, 30]);
+}
+
+#[test]
+fn test_equal_cpu_ties_broken_by_secondary_sort() {
+    let mut processes = vec![
+        process(1, "a", 10.0, 100),
+        process(2, "b", 10.0, 300),
+        process(3, "c", 10.0, 200),
+        process(4, "d", 20.0, 0),
+    ];
+    sort_process_data(
+        &mut processes,
+        &ProcessSorting::CpuPercent,
+        true,
+        false,
+        &Some(ProcessSorting::Mem),
+    );
+
+    assert_eq!(get_pids(&processes), vec![4, 2, 3, 1]);
+}
+
+#[test]
+fn test_order_does_not_depend_on_input_order() {
+    let mut processes = vec![
+        process(3, "same", 10.0, 0),
+        process(1, "same", 10.0, 0),
+        process(2, "same", 10.0, 0),
+    ];
+    let mut reversed = processes.iter().rev().cloned().collect::<Vec<_>>();
+    sort_process_data(
+        &mut processes,
+        &ProcessSorting::CpuPercent,
+        true,
+        false,
+        &None,
+    );
+    sort_process_data(
+        &mut reversed,
+        &ProcessSorting::CpuPercent,
+        true,
+        false,
+        &None,
+    );
+
+    assert_eq!(get_pids(&processes), get_pids(&reversed));
+}
@@ -0,0 +1,91 @@
+//! Checks that the refresh key asks the harvester to refresh, however the terminal reports it,
+//! and that it can't be set to a key that is already bound.
+
+use std::{process::Command, sync::mpsc};
+
+use assert_cmd::prelude::*;
+use bottom::{send_refresh_if_refresh_key, ThreadControlEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use predicates::prelude::*;
+
+fn get_binary_location() -> String {
+    env!("CARGO_BIN_EXE_btm").to_string()
+}
+
+#[test]
+fn test_uppercase_refresh_key_with_shift() {
+    let (sender, receiver) = mpsc::channel();
+    let event = KeyEvent {
+        code: KeyCode::Char('U'),
+        modifiers: KeyModifiers::SHIFT,
+    };
+
+    assert_eq!(
+        send_refresh_if_refresh_key(&event, 'U', &sender),
+        Some(true)
+    );
+    assert!(matches!(
+        receiver.try_recv(),
+        Ok(ThreadControlEvent::RefreshNow)
+    ));
+}
+
+#[test]
+fn test_refresh_key_without_modifiers() {
+    let (sender, receiver) = mpsc::channel();
+    let event = KeyEvent {
+        code: KeyCode::Char('r'),
+        modifiers: KeyModifiers::empty(),
+    };
+
+    assert_eq!(
+        send_refresh_if_refresh_key(&event, 'r', &sender),
+        Some(true)
+    );
+    assert!(matches!(
+        receiver.try_recv(),
+        Ok(ThreadControlEvent::RefreshNow)
+    ));
+}
+
+#[test]
+fn test_other_keys_do_not_refresh() {
+    let (sender, receiver) = mpsc::channel();
+    let other_key = KeyEvent {
+        code: KeyCode::Char('u'),
+        modifiers: KeyModifiers::empty(),
+    };
+    let with_control = KeyEvent {
+        code: KeyCode::Char('U'),
+        modifiers: KeyModifiers::CONTROL,
+    };
+
+    assert_eq!(send_refresh_if_refresh_key(&other_key, 'U', &sender), None);
+    assert_eq!(
+        send_refresh_if_refresh_key(&with_control, 'U', &sender),
+        None
+    );
+    assert!(receiver.try_recv().is_err());
+}
+
+#[test]
+fn test_bound_refresh_key() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/bound_refresh_key.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("\"e\" is already bound"));
+    Ok(())
+}
+
+#[test]
+fn test_refresh_key_shadowing_uppercase_binding() -> Result<(), Box<dyn std::error::Error>> {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/shadowing_refresh_key.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("\"i\" is already bound"));
+    Ok(())
+}